@@ -0,0 +1,130 @@
+#ifndef RSHAPES_H
+#define RSHAPES_H
+
+/* Generated with cbindgen from src/ffi.rs, run `just header` to update. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// Rule deciding which points are inside of a self-intersecting polygon, see [`FillRule`].
+typedef enum RshapesFillRule {
+  RSHAPES_FILL_RULE_EVEN_ODD,
+  RSHAPES_FILL_RULE_NON_ZERO,
+} RshapesFillRule;
+
+// Opaque shape with `u8` coordinates and radii.
+typedef struct RshapesShape RshapesShape;
+
+typedef struct RshapesPoint {
+  uint8_t x;
+  uint8_t y;
+} RshapesPoint;
+
+// Axis-aligned rectangle, both corners are inclusive.
+typedef struct RshapesRectangle {
+  struct RshapesPoint min;
+  struct RshapesPoint max;
+} RshapesRectangle;
+
+
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct RshapesShape *rshapes_rectangle_new(struct RshapesPoint min, struct RshapesPoint max);
+
+// Creates a rectangle rotated counter-clockwise by `angle` radians around its center.
+struct RshapesShape *rshapes_rotated_rectangle_new(struct RshapesPoint center,
+                                                   uint8_t half_width,
+                                                   uint8_t half_height,
+                                                   double angle);
+
+struct RshapesShape *rshapes_circle_new(struct RshapesPoint center, uint8_t radius);
+
+struct RshapesShape *rshapes_ellipse_new(struct RshapesPoint center,
+                                         uint8_t radius_x,
+                                         uint8_t radius_y);
+
+// Creates an ellipse rotated counter-clockwise by `angle` radians around its center.
+struct RshapesShape *rshapes_rotated_ellipse_new(struct RshapesPoint center,
+                                                 uint8_t radius_x,
+                                                 uint8_t radius_y,
+                                                 double angle);
+
+struct RshapesShape *rshapes_triangle_new(struct RshapesPoint a,
+                                          struct RshapesPoint b,
+                                          struct RshapesPoint c);
+
+// Creates a polygon from `len` points, which are copied.
+//
+// # Safety
+// `points` has to point to `len` readable points, it may only be null if `len` is zero.
+struct RshapesShape *rshapes_polygon_new(const struct RshapesPoint *points,
+                                         size_t len,
+                                         enum RshapesFillRule fill_rule);
+
+// Creates a polygon with holes from `ring_count` rings, the first one is the exterior.
+//
+// The rings are stored one after another in `points`, ring `i` has `ring_lengths[i]`
+// points. Returns null if there is no exterior or [`PolygonWithHoles::new`] rejects
+// the rings.
+//
+// # Safety
+// `ring_lengths` has to point to `ring_count` readable lengths, and `points` to as many
+// readable points as the lengths add up to.
+struct RshapesShape *rshapes_polygon_with_holes_new(const struct RshapesPoint *points,
+                                                    const size_t *ring_lengths,
+                                                    size_t ring_count);
+
+// Creates a collection from copies of `len` shapes, the shapes stay owned by the caller.
+//
+// # Safety
+// `shapes` has to point to `len` valid shape pointers, it may only be null if `len` is zero.
+struct RshapesShape *rshapes_collection_new(const struct RshapesShape *const *shapes, size_t len);
+
+// Releases a shape, null is ignored.
+//
+// # Safety
+// `shape` has to be null or a pointer returned by one of the constructors, which wasn't
+// freed yet.
+void rshapes_shape_free(struct RshapesShape *shape);
+
+// # Safety
+// `shape` has to be a valid shape pointer.
+bool rshapes_shape_within(const struct RshapesShape *shape, struct RshapesPoint point);
+
+// Distance from the outline of the shape to the point, zero for points inside.
+//
+// # Safety
+// `shape` has to be a valid shape pointer.
+double rshapes_shape_distance(const struct RshapesShape *shape, struct RshapesPoint point);
+
+// # Safety
+// `shape` has to be a valid shape pointer.
+struct RshapesRectangle rshapes_shape_bbox(const struct RshapesShape *shape);
+
+// # Safety
+// `shape` has to be a valid shape pointer.
+struct RshapesPoint rshapes_shape_centroid(const struct RshapesShape *shape);
+
+// Writes the points inside the shape into `out`, in scanline order.
+//
+// At most `capacity` points are written. Returns the number of points inside the shape,
+// so a result above `capacity` means the list was cut short.
+//
+// # Safety
+// `shape` has to be a valid shape pointer, and `out` has to point to `capacity` writable
+// points. `out` may only be null if `capacity` is zero.
+size_t rshapes_shape_points_inside(const struct RshapesShape *shape,
+                                   struct RshapesPoint *out,
+                                   size_t capacity);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RSHAPES_H */
@@ -0,0 +1,323 @@
+//! Outlines of point sets and scalar grids, traced with marching squares.
+//!
+//! The outlines pass through the points where the values cross the level, interpolated
+//! linearly between neighbouring samples. Rings are oriented like the ones of
+//! [`PolygonWithHoles`]: exteriors are counter-clockwise and holes are clockwise
+//! (in a y-up coordinate system).
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::traits::Within;
+use crate::*;
+
+/// Traces the outlines of the regions of a grid where the values are at or above `level`.
+///
+/// The grid has `width` columns and is stored row by row, the value of point `(x, y)` is
+/// `values[y * width + x]`. Values outside the grid are treated as below the level, so
+/// every outline is closed, and outlines reaching the border run along the border samples.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Point2, contour::contours, traits::Within};
+///
+/// #[rustfmt::skip]
+/// let heatmap = [
+///   0.0, 0.0, 0.0, 0.0,
+///   0.0, 0.8, 1.0, 0.0,
+///   0.0, 0.6, 0.2, 0.0,
+/// ];
+/// let zones = contours(&heatmap, 4, 0.5);
+///
+/// assert_eq!(zones.len(), 1);
+/// assert!(zones[0].within(&Point2::new(1.0, 1.5)));
+/// assert!(!zones[0].within(&Point2::new(2.0, 2.0)));
+/// ```
+pub fn contours<V>(values: &[V], width: usize, level: f64) -> Vec<PolygonWithHoles<f64>>
+where
+  V: Copy + Into<f64>,
+{
+  if width == 0 {
+    return Vec::new();
+  }
+
+  let height = values.len() / width;
+  trace(width, height, level, |x, y| {
+    if (0..width as isize).contains(&x) && (0..height as isize).contains(&y) {
+      values[y as usize * width + x as usize].into()
+    } else {
+      f64::NEG_INFINITY
+    }
+  })
+}
+
+/// Traces the outlines of a `width × height` grid, `sample` has to cover the points around it.
+pub(crate) fn trace(
+  width: usize,
+  height: usize,
+  level: f64,
+  sample: impl Fn(isize, isize) -> f64,
+) -> Vec<PolygonWithHoles<f64>> {
+  // Crossings are keyed by the doubled midpoint of their edge, as `(y, x)` for scanline order.
+  let mut next = BTreeMap::new();
+  let mut points = BTreeMap::new();
+
+  for y in -1..height as isize {
+    for x in -1..width as isize {
+      let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+      let values = corners.map(|(x, y)| sample(x, y));
+      let inside = values.map(|value| value >= level);
+
+      let mut edge = |index: usize| {
+        let (a, b) = (index % 4, (index + 1) % 4);
+        let key = (corners[a].1 + corners[b].1, corners[a].0 + corners[b].0);
+        points
+          .entry(key)
+          .or_insert_with(|| crossing(corners[a], values[a], corners[b], values[b], level));
+        key
+      };
+
+      // Walking counter-clockwise around the cell, the outline leaves the shape on an exit edge
+      // and enters it on an entry edge. Segments go from an exit to an entry, so the inside is
+      // always on their left.
+      let exits = (0..4)
+        .filter(|&i| inside[i] && !inside[(i + 1) % 4])
+        .collect::<Vec<_>>();
+
+      match exits.len() {
+        0 => {}
+        1 => {
+          let entry = (0..4)
+            .find(|&i| !inside[i] && inside[(i + 1) % 4])
+            .expect("every exit has an entry");
+          next.insert(edge(exits[0]), edge(entry));
+        }
+        // Saddle, the value at the center decides if the inside corners are connected.
+        _ => {
+          let center = values.iter().sum::<f64>() / 4.0;
+          for (corner, &inside) in inside.iter().enumerate() {
+            if inside && center < level {
+              // Cut off the inside corner.
+              next.insert(edge(corner), edge(corner + 3));
+            } else if !inside && center >= level {
+              // Cut off the outside corner.
+              next.insert(edge(corner + 3), edge(corner));
+            }
+          }
+        }
+      }
+    }
+  }
+
+  let mut exteriors = Vec::new();
+  let mut holes = Vec::new();
+
+  while let Some((&start, _)) = next.first_key_value() {
+    let mut ring = Vec::new();
+    let mut key = start;
+    while let Some(to) = next.remove(&key) {
+      ring.push(points[&key]);
+      key = to;
+    }
+
+    let ring = Polygon::new(simplify(ring));
+    let area = ring.signed_area();
+    if area > 0.0 {
+      exteriors.push((area, ring));
+    } else if area < 0.0 {
+      holes.push(ring);
+    }
+  }
+
+  let mut polygons = exteriors
+    .iter()
+    .map(|(_, exterior)| PolygonWithHoles::new_unchecked(exterior.clone(), Vec::new()))
+    .collect::<Vec<_>>();
+
+  // Every hole belongs to the smallest exterior around it.
+  for hole in holes {
+    let owner = exteriors
+      .iter()
+      .enumerate()
+      .filter(|(_, (_, exterior))| exterior.within(&hole.points[0]))
+      .min_by(|(_, (a, _)), (_, (b, _))| a.total_cmp(b))
+      .map(|(index, _)| index);
+
+    if let Some(owner) = owner {
+      polygons[owner].holes.push(hole);
+    }
+  }
+
+  polygons
+}
+
+/// Returns the point where the level is crossed between two samples, one inside and one outside.
+#[inline]
+fn crossing(
+  a: (isize, isize),
+  value_a: f64,
+  b: (isize, isize),
+  value_b: f64,
+  level: f64,
+) -> Point2<f64> {
+  let point = |(x, y): (isize, isize)| Point2::new(x as f64, y as f64);
+  // Interpolating from the inside sample keeps the point there if the other one is infinite.
+  let (inner, outer, t) = if value_a >= level {
+    (a, b, (level - value_a) / (value_b - value_a))
+  } else {
+    (b, a, (level - value_b) / (value_a - value_b))
+  };
+
+  point(inner) + (point(outer) - point(inner)) * t
+}
+
+/// Drops repeated points and points in the middle of a straight run.
+fn simplify(ring: Vec<Point2<f64>>) -> Vec<Point2<f64>> {
+  let collinear = |a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>| {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x) == 0.0
+  };
+
+  let mut points: Vec<Point2<f64>> = Vec::with_capacity(ring.len());
+  for point in ring {
+    while points.len() >= 2
+      && collinear(&points[points.len() - 2], &points[points.len() - 1], &point)
+    {
+      points.pop();
+    }
+    if points.last() != Some(&point) {
+      points.push(point);
+    }
+  }
+
+  // The ring is closed, so the first and last points need the same treatment.
+  while points.len() >= 3 {
+    let n = points.len();
+    if collinear(&points[n - 2], &points[n - 1], &points[0]) {
+      points.pop();
+    } else if collinear(&points[n - 1], &points[0], &points[1]) {
+      points.remove(0);
+    } else {
+      break;
+    }
+  }
+
+  points
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+  use core::f64::consts::PI;
+
+  use float_cmp::assert_approx_eq;
+  use proptest::prelude::ProptestConfig;
+  use test_strategy::proptest;
+
+  use super::contours;
+  use crate::proptest::ShapeView;
+  use crate::traits::{Area, Within};
+  use crate::{Mask, Point2, Rectangle, Shape};
+
+  #[test]
+  fn single_point_is_diamond() {
+    let mask = Mask::from_iter([Point2::new(5, 5)]);
+    let outlines = mask.contours();
+
+    assert_eq!(outlines.len(), 1);
+    assert_eq!(
+      outlines[0].exterior().points(),
+      &vec![
+        Point2::new(5.0, 4.5),
+        Point2::new(5.5, 5.0),
+        Point2::new(5.0, 5.5),
+        Point2::new(4.5, 5.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn block_has_cut_corners() {
+    let mask = Mask::from_shape(&Rectangle::new(Point2::new(0, 0), Point2::new(2, 2)));
+    let outlines = mask.contours();
+
+    assert_eq!(outlines.len(), 1);
+    assert_eq!(outlines[0].exterior().points().len(), 8);
+    assert_eq!(outlines[0].area(), 8.5);
+  }
+
+  #[test]
+  fn ring_has_hole() {
+    let mut mask = Mask::from_shape(&Rectangle::new(Point2::new(10, 10), Point2::new(14, 14)));
+    let inner = Mask::from_shape(&Rectangle::new(Point2::new(11, 11), Point2::new(13, 13)));
+    mask = mask.difference(&inner);
+
+    let outlines = mask.contours();
+
+    assert_eq!(outlines.len(), 1);
+    assert_eq!(outlines[0].holes().len(), 1);
+    assert!(outlines[0].within(&Point2::new(10.0, 12.0)));
+    assert!(!outlines[0].within(&Point2::new(12.0, 12.0)));
+  }
+
+  #[test]
+  fn separate_regions() {
+    let mask = Mask::from_shape(&Rectangle::new(Point2::new(0, 0), Point2::new(1, 1))).union(
+      &Mask::from_shape(&Rectangle::new(Point2::new(5, 5), Point2::new(6, 6))),
+    );
+
+    assert_eq!(mask.contours().len(), 2);
+    assert_eq!(Mask::new().contours(), vec![]);
+  }
+
+  #[test]
+  fn grid_contours_circle() {
+    let (width, height) = (41, 41);
+    let values = (0..width * height)
+      .map(|i| {
+        let (x, y) = ((i % width) as f64 - 20.0, (i / width) as f64 - 20.0);
+        (x * x + y * y).sqrt()
+      })
+      .map(|distance| 15.0 - distance)
+      .collect::<Vec<_>>();
+
+    let outlines = contours(&values, width, 5.0);
+
+    assert_eq!(outlines.len(), 1);
+    assert!(outlines[0].holes().is_empty());
+    assert_approx_eq!(f64, outlines[0].area(), PI * 100.0, epsilon = 1.0);
+  }
+
+  #[test]
+  fn grid_contours_at_border() {
+    let values = [1u8, 1, 1, 1];
+    let outlines = contours(&values, 2, 0.5);
+
+    assert_eq!(outlines.len(), 1);
+    assert_eq!(outlines[0].area(), 1.0);
+  }
+
+  #[test]
+  fn grid_contours_empty() {
+    assert_eq!(contours::<f32>(&[], 0, 0.5), vec![]);
+    assert_eq!(contours(&[0.0, 0.1], 2, 0.5), vec![]);
+  }
+
+  #[proptest(ProptestConfig::with_cases(8))]
+  fn mask_contours_u8_cover_mask(shape: ShapeView<u8, u8>) {
+    let mask = Mask::from_shape(&Shape::from(shape));
+    let outlines = mask.contours();
+
+    // Only check around the points, far away everything is outside anyway.
+    for point in mask.iter().step_by(7) {
+      let point = point.map(f64::from);
+      assert!(outlines.iter().any(|outline| outline.within(&point)));
+      if point.x > 0.0 {
+        let left = Point2::new(point.x - 1.0, point.y);
+        assert_eq!(
+          outlines.iter().any(|outline| outline.within(&left)),
+          mask.contains(&left.map(|c| c as u8))
+        );
+      }
+    }
+  }
+}
@@ -20,7 +20,7 @@ where
   T: Scalar,
 {
   #[inline]
-  pub fn new_unchecked(start: Point2<T>, end: Point2<T>) -> Self {
+  pub const fn new_unchecked(start: Point2<T>, end: Point2<T>) -> Self {
     Self { start, end }
   }
 }
@@ -0,0 +1,272 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use nalgebra::Point2;
+
+use crate::traits::PointsInside;
+use crate::{contour, PolygonWithHoles};
+
+/// Number of 64-bit words in a row of the mask.
+const ROW_WORDS: usize = 256 / 64;
+
+/// Number of 64-bit words in the whole mask.
+const WORDS: usize = 256 * ROW_WORDS;
+
+/// Set of points covering the whole `u8` coordinate space, one bit per point.
+///
+/// Shapes can be rasterized into a mask once, after that checking a point with
+/// [`Within`](crate::traits::Within) is a single bit lookup.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Mask, Point2, Rectangle, traits::Within};
+///
+/// let circle = Mask::from_shape(&Circle::<u8, u8>::new(Point2::new(10, 10), 5));
+/// let left = Mask::from_shape(&Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(9, 20)));
+/// let half = circle.intersection(&left);
+///
+/// assert!(half.within(&Point2::new(6, 10)));
+/// assert!(!half.within(&Point2::new(14, 10)));
+/// assert_eq!(half.len() + circle.difference(&left).len(), circle.len());
+/// ```
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct Mask {
+  /// Rows from the smallest to the largest `y`, the bit `x % 64` of word `x / 64` is point `x`.
+  words: Box<[u64; WORDS]>,
+}
+
+impl Mask {
+  /// Creates an empty mask.
+  pub fn new() -> Self {
+    Self {
+      words: Box::new([0; WORDS]),
+    }
+  }
+
+  /// Creates a mask with all points inside the shape.
+  pub fn from_shape<S: PointsInside<u8>>(shape: &S) -> Self {
+    shape.points_inside_iter().collect()
+  }
+
+  #[inline]
+  fn position(point: &Point2<u8>) -> (usize, u64) {
+    let word = point.y as usize * ROW_WORDS + point.x as usize / 64;
+    (word, 1 << (point.x % 64))
+  }
+
+  /// Checks if the point is in the mask.
+  #[inline]
+  pub fn contains(&self, point: &Point2<u8>) -> bool {
+    let (word, bit) = Self::position(point);
+    self.words[word] & bit != 0
+  }
+
+  /// Adds the point, returns `true` if it wasn't in the mask yet.
+  #[inline]
+  pub fn insert(&mut self, point: Point2<u8>) -> bool {
+    let (word, bit) = Self::position(&point);
+    let inserted = self.words[word] & bit == 0;
+    self.words[word] |= bit;
+    inserted
+  }
+
+  /// Removes the point, returns `true` if it was in the mask.
+  #[inline]
+  pub fn remove(&mut self, point: &Point2<u8>) -> bool {
+    let (word, bit) = Self::position(point);
+    let removed = self.words[word] & bit != 0;
+    self.words[word] &= !bit;
+    removed
+  }
+
+  /// Returns the number of points in the mask.
+  pub fn len(&self) -> usize {
+    self
+      .words
+      .iter()
+      .map(|word| word.count_ones() as usize)
+      .sum()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.words.iter().all(|word| *word == 0)
+  }
+
+  #[inline]
+  fn combine(&self, other: &Self, operation: impl Fn(u64, u64) -> u64) -> Self {
+    let mut mask = self.clone();
+    for (word, other) in mask.words.iter_mut().zip(other.words.iter()) {
+      *word = operation(*word, *other);
+    }
+    mask
+  }
+
+  /// Returns the points in either mask.
+  pub fn union(&self, other: &Self) -> Self {
+    self.combine(other, |a, b| a | b)
+  }
+
+  /// Returns the points in both masks.
+  pub fn intersection(&self, other: &Self) -> Self {
+    self.combine(other, |a, b| a & b)
+  }
+
+  /// Returns the points in this mask, but not in the other one.
+  pub fn difference(&self, other: &Self) -> Self {
+    self.combine(other, |a, b| a & !b)
+  }
+
+  /// Traces the outlines of the points with [`contours`](crate::contour::contours).
+  ///
+  /// The outlines run halfway between the points inside and outside the mask, diagonal
+  /// neighbours are connected.
+  pub fn contours(&self) -> Vec<PolygonWithHoles<f64>> {
+    contour::trace(256, 256, 0.5, |x, y| {
+      let inside = (0..256).contains(&x)
+        && (0..256).contains(&y)
+        && self.contains(&Point2::new(x as u8, y as u8));
+
+      if inside {
+        1.0
+      } else {
+        0.0
+      }
+    })
+  }
+
+  /// Returns an iterator over the points in scanline order.
+  pub fn iter(&self) -> MaskIter<'_> {
+    MaskIter {
+      words: &self.words[..],
+      word: 0,
+      bits: self.words[0],
+    }
+  }
+}
+
+impl Default for Mask {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl fmt::Debug for Mask {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_set().entries(self.iter()).finish()
+  }
+}
+
+impl FromIterator<Point2<u8>> for Mask {
+  fn from_iter<I: IntoIterator<Item = Point2<u8>>>(iter: I) -> Self {
+    let mut mask = Self::new();
+    mask.extend(iter);
+    mask
+  }
+}
+
+impl Extend<Point2<u8>> for Mask {
+  fn extend<I: IntoIterator<Item = Point2<u8>>>(&mut self, iter: I) {
+    for point in iter {
+      self.insert(point);
+    }
+  }
+}
+
+impl<'a> IntoIterator for &'a Mask {
+  type Item = Point2<u8>;
+  type IntoIter = MaskIter<'a>;
+
+  fn into_iter(self) -> Self::IntoIter {
+    self.iter()
+  }
+}
+
+/// Iterator over the points of a [`Mask`] in scanline order.
+#[derive(Debug, Clone)]
+pub struct MaskIter<'a> {
+  words: &'a [u64],
+  word: usize,
+  /// Bits of the current word which weren't yielded yet.
+  bits: u64,
+}
+
+impl Iterator for MaskIter<'_> {
+  type Item = Point2<u8>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    while self.bits == 0 {
+      self.word += 1;
+      self.bits = *self.words.get(self.word)?;
+    }
+
+    let index = self.word * 64 + self.bits.trailing_zeros() as usize;
+    self.bits &= self.bits - 1;
+
+    Some(Point2::new((index % 256) as u8, (index / 256) as u8))
+  }
+}
+
+impl FusedIterator for MaskIter<'_> {}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::traits::{PointsInside, Within};
+  use crate::{Mask, Point2, Rectangle, Shape};
+
+  #[test]
+  fn mask_insert_remove() {
+    let mut mask = Mask::new();
+
+    assert!(mask.insert(Point2::new(255, 255)));
+    assert!(!mask.insert(Point2::new(255, 255)));
+    assert!(mask.insert(Point2::new(64, 0)));
+    assert_eq!(mask.len(), 2);
+    assert!(mask.contains(&Point2::new(64, 0)));
+    assert!(!mask.contains(&Point2::new(63, 0)));
+
+    assert!(mask.remove(&Point2::new(64, 0)));
+    assert!(!mask.remove(&Point2::new(64, 0)));
+    assert_eq!(mask.iter().collect::<Vec<_>>(), vec![Point2::new(255, 255)]);
+  }
+
+  #[test]
+  fn mask_empty() {
+    let mask = Mask::default();
+
+    assert!(mask.is_empty());
+    assert_eq!(mask.len(), 0);
+    assert_eq!(mask.iter().next(), None);
+  }
+
+  #[test]
+  fn mask_set_operations() {
+    let a = Mask::from_shape(&Rectangle::new(Point2::new(0, 0), Point2::new(3, 0)));
+    let b = Mask::from_shape(&Rectangle::new(Point2::new(2, 0), Point2::new(5, 0)));
+    let xs = |mask: Mask| mask.iter().map(|point| point.x).collect::<Vec<_>>();
+
+    assert_eq!(xs(a.union(&b)), vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(xs(a.intersection(&b)), vec![2, 3]);
+    assert_eq!(xs(a.difference(&b)), vec![0, 1]);
+    assert_eq!(xs(b.difference(&a)), vec![4, 5]);
+  }
+
+  #[proptest]
+  fn mask_from_shape_u8_fuzz(shape: ShapeView<u8, u8>) {
+    let shape = Shape::from(shape);
+    let mask = Mask::from_shape(&shape);
+    let points = shape.points_inside();
+
+    assert_eq!(mask.len(), points.len());
+    assert_eq!(mask.iter().collect::<Vec<_>>(), points);
+    for point in &points {
+      assert!(mask.within(point));
+    }
+  }
+}
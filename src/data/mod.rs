@@ -1,2 +1,5 @@
 mod line;
 pub use line::*;
+
+mod mask;
+pub use mask::*;
@@ -0,0 +1,1015 @@
+//! Integer-only point queries, for targets without a floating point unit.
+//!
+//! The traits in this module mirror [`Within`], [`PointsInside`](crate::traits::PointsInside)
+//! and [`Distance`](crate::traits::Distance) for shapes with integer coordinates, but never
+//! convert anything into `f64`. Containment uses exact integer arithmetic and gives the same
+//! results as the float path, distances are returned as [`Q16`] fixed-point numbers.
+//! Rotated shapes can't be checked without floats, so [`Shape`] and [`ShapeCollection`]
+//! answer with `None` when they contain one. The closest point of an ellipse has no closed
+//! form either, so [`Ellipse`] has no [`ExactDistance`], and the distance of a [`Shape`] or
+//! [`ShapeCollection`] with an ellipse is `None` as well.
+//!
+//! # Example
+//! ```rust
+//! use rshapes::exact::{ExactDistance, ExactWithin, Q16};
+//! use rshapes::{Circle, Point2};
+//!
+//! let circle = Circle::<u8, u8>::new(Point2::new(10, 10), 5);
+//!
+//! assert!(circle.within_exact(&Point2::new(13, 14)));
+//! assert_eq!(circle.distance_exact(&Point2::new(10, 20)), Q16::from_int(5));
+//! ```
+
+use alloc::vec::Vec;
+
+use num::traits::{CheckedAdd, CheckedMul, NumAssign, PrimInt, Signed, Unsigned, Zero};
+
+use crate::traits::{BoundingBox, Within};
+use crate::*;
+
+/// Integer coordinate types supported by the integer-only queries.
+///
+/// Checks widen the coordinates just enough to stay exact: `u8` to `i32`, the 16-bit types
+/// to `i64` and `i32` to `i128`.
+pub trait IntMath: FloatMath + Into<i64> {
+  /// Signed type which holds products of two coordinate differences.
+  type Wide: PrimInt + Signed + NumAssign + Scalar + From<Self>;
+
+  /// Unsigned type of the same size, which holds products of four coordinates.
+  type UnsignedWide: PrimInt + Unsigned;
+
+  /// Converts an `i64` into `Self`, saturating at the bounds of the type.
+  fn saturating_from_i64(value: i64) -> Self;
+}
+
+macro_rules! impl_int_math {
+  ($($t:ty => $wide:ty, $unsigned:ty);* $(;)?) => {$(
+    impl IntMath for $t {
+      type Wide = $wide;
+      type UnsignedWide = $unsigned;
+
+      #[inline]
+      fn saturating_from_i64(value: i64) -> Self {
+        value.clamp(<$t>::MIN as i64, <$t>::MAX as i64) as $t
+      }
+    }
+  )*};
+}
+
+impl_int_math!(
+  u8 => i32, u32;
+  u16 => i64, u64;
+  i16 => i64, u64;
+  i32 => i128, u128;
+);
+
+/// Unsigned fixed-point number with 16 integer and 16 fractional bits (Q16.16).
+///
+/// Values which don't fit saturate at [`Q16::MAX`].
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Q16(u32);
+
+impl Q16 {
+  pub const ZERO: Self = Self(0);
+  pub const MAX: Self = Self(u32::MAX);
+
+  const FRACTION_BITS: u32 = 16;
+
+  #[inline]
+  pub const fn from_bits(bits: u32) -> Self {
+    Self(bits)
+  }
+
+  #[inline]
+  pub const fn to_bits(self) -> u32 {
+    self.0
+  }
+
+  #[inline]
+  pub const fn from_int(value: u16) -> Self {
+    Self((value as u32) << Self::FRACTION_BITS)
+  }
+
+  /// Returns the integer part, rounded towards zero.
+  #[inline]
+  pub const fn to_int(self) -> u16 {
+    (self.0 >> Self::FRACTION_BITS) as u16
+  }
+
+  #[inline]
+  pub fn to_f64(self) -> f64 {
+    self.0 as f64 / (1u32 << Self::FRACTION_BITS) as f64
+  }
+
+  #[inline]
+  pub const fn saturating_sub(self, other: Self) -> Self {
+    Self(self.0.saturating_sub(other.0))
+  }
+
+  /// Returns the square root of `value / denominator`, rounded down.
+  fn sqrt_ratio(value: u128, denominator: u128) -> Self {
+    let (whole, rest) = (value / denominator, value % denominator);
+
+    // The root is past `MAX` anyway, and shifting the value could overflow.
+    if whole >> (2 * Self::FRACTION_BITS) != 0 {
+      return Self::MAX;
+    }
+
+    let scaled =
+      (whole << (2 * Self::FRACTION_BITS)) + (rest << (2 * Self::FRACTION_BITS)) / denominator;
+
+    Self(u32::try_from(scaled.isqrt()).unwrap_or(u32::MAX))
+  }
+
+  /// Returns the square root of the value, rounded down.
+  #[inline]
+  fn sqrt(value: u128) -> Self {
+    Self::sqrt_ratio(value, 1)
+  }
+}
+
+/// Checks if a point is inside a shape, using integer arithmetic only.
+///
+/// Gives the same result as [`Within`] for every point. [`Shape`] and [`ShapeCollection`]
+/// return `None` instead when they contain a rotated shape.
+pub trait ExactWithin<T: Scalar> {
+  type Result;
+
+  fn within_exact(&self, point: &Point2<T>) -> Self::Result;
+}
+
+/// Lists the integer points inside a shape, using integer arithmetic only.
+///
+/// Gives the same points in the same scanline order as
+/// [`PointsInside`](crate::traits::PointsInside). [`Shape`] and [`ShapeCollection`] return
+/// `None` instead when they contain a rotated shape.
+pub trait ExactPointsInside<T: Scalar> {
+  type Result;
+
+  fn points_inside_exact(&self) -> Self::Result;
+}
+
+/// Distance from a shape to a point, using integer arithmetic only.
+///
+/// The distance is the one of [`Distance`](crate::traits::Distance), rounded down to the
+/// next [`Q16`]. [`Shape`] and [`ShapeCollection`] return `None` instead when they contain
+/// a rotated shape or an ellipse.
+pub trait ExactDistance<T: Scalar> {
+  type Result;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Self::Result;
+}
+
+#[inline]
+fn wide<T: IntMath>(point: &Point2<T>) -> Point2<T::Wide> {
+  point.map(Into::into)
+}
+
+#[inline]
+fn distance_squared<W: PrimInt + Scalar>(a: &Point2<W>, b: &Point2<W>) -> W {
+  (a.x - b.x).pow(2) + (a.y - b.y).pow(2)
+}
+
+/// Signed doubled area of the triangle formed by the edge and the point.
+#[inline]
+fn cross<W: PrimInt + Scalar>(a: &Point2<W>, b: &Point2<W>, p: &Point2<W>) -> W {
+  (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// Converts a non-negative value for the fixed-point square roots.
+#[inline]
+fn to_u128<W: PrimInt>(value: W) -> u128 {
+  value.to_u128().unwrap_or(0)
+}
+
+/// Squares a value no larger than a radius of type `R`, in the unsigned wide type of `R`.
+#[inline]
+fn square<R: IntMath>(value: i64) -> R::UnsignedWide {
+  let value: R::UnsignedWide =
+    num::cast(value.unsigned_abs()).unwrap_or_else(R::UnsignedWide::zero);
+  value * value
+}
+
+impl<T> ExactWithin<T> for Rectangle<T>
+where
+  T: IntMath,
+{
+  type Result = bool;
+
+  fn within_exact(&self, point: &Point2<T>) -> bool {
+    self.within(point)
+  }
+}
+
+impl<T, R> ExactWithin<T> for Circle<T, R>
+where
+  T: IntMath,
+  R: IntMath,
+{
+  type Result = bool;
+
+  fn within_exact(&self, point: &Point2<T>) -> bool {
+    let radius: i64 = self.radius.into();
+
+    if radius == 0 {
+      return point == &self.center;
+    }
+
+    let distance = distance_squared(&wide(&self.center), &wide(point));
+
+    // A radius whose square doesn't fit is beyond every distance.
+    num::cast(radius)
+      .and_then(|radius: T::Wide| radius.checked_mul(&radius))
+      .is_none_or(|radius| distance <= radius)
+  }
+}
+
+impl<T, R> ExactWithin<T> for Ellipse<T, R>
+where
+  T: IntMath,
+  R: IntMath,
+{
+  type Result = bool;
+
+  fn within_exact(&self, point: &Point2<T>) -> bool {
+    let (rx, ry): (i64, i64) = (self.radius.0.into(), self.radius.1.into());
+
+    if rx == 0 || ry == 0 {
+      return point == &self.center;
+    }
+
+    if !ellipse_bounds(self).within(point) {
+      return false;
+    }
+
+    // Inside the bounds the offsets are no larger than the radii, so every product fits.
+    let p = point.map(Into::<i64>::into) - self.center.map(Into::<i64>::into);
+    let (rx2, ry2) = (square::<R>(rx), square::<R>(ry));
+
+    (square::<R>(p.x) * ry2)
+      .checked_add(&(square::<R>(p.y) * rx2))
+      .is_some_and(|sum| sum <= rx2 * ry2)
+  }
+}
+
+impl<T> ExactWithin<T> for Triangle<T>
+where
+  T: IntMath,
+{
+  type Result = bool;
+
+  fn within_exact(&self, point: &Point2<T>) -> bool {
+    if !self.bbox().within(point) {
+      return false;
+    }
+
+    let (a, b, c) = (wide(&self.0), wide(&self.1), wide(&self.2));
+    let p = wide(point);
+
+    let d1 = cross(&a, &b, &p);
+    let d2 = cross(&b, &c, &p);
+    let d3 = cross(&c, &a, &p);
+
+    let has_negative = d1.is_negative() || d2.is_negative() || d3.is_negative();
+    let has_positive = d1.is_positive() || d2.is_positive() || d3.is_positive();
+
+    !(has_negative && has_positive)
+  }
+}
+
+impl<T> ExactWithin<T> for Polygon<T>
+where
+  T: IntMath,
+{
+  type Result = bool;
+
+  fn within_exact(&self, point: &Point2<T>) -> bool {
+    if self.points.is_empty() || !self.bbox().within(point) {
+      return false;
+    }
+
+    if on_outline(self, point) {
+      return true;
+    }
+
+    let p = wide(point);
+
+    let mut crossings = 0usize;
+    let mut winding = 0isize;
+
+    for edge in self.edges() {
+      let (a, b) = (wide(&edge.start), wide(&edge.end));
+      let cross = cross(&a, &b, &p);
+
+      if a.y <= p.y {
+        if b.y > p.y && cross.is_positive() {
+          crossings += 1;
+          winding += 1;
+        }
+      } else if b.y <= p.y && cross.is_negative() {
+        crossings += 1;
+        winding -= 1;
+      }
+    }
+
+    match self.fill_rule {
+      FillRule::EvenOdd => crossings % 2 == 1,
+      FillRule::NonZero => winding != 0,
+    }
+  }
+}
+
+impl<T> ExactWithin<T> for PolygonWithHoles<T>
+where
+  T: IntMath,
+{
+  type Result = bool;
+
+  fn within_exact(&self, point: &Point2<T>) -> bool {
+    if !self.exterior.within_exact(point) {
+      return false;
+    }
+
+    !self
+      .holes
+      .iter()
+      .any(|hole| hole.within_exact(point) && !on_outline(hole, point))
+  }
+}
+
+impl<T, U> ExactWithin<T> for Shape<T, U>
+where
+  T: IntMath,
+  U: IntMath,
+{
+  type Result = Option<bool>;
+
+  fn within_exact(&self, point: &Point2<T>) -> Self::Result {
+    match self {
+      Shape::Rectangle(rectangle) => Some(rectangle.within_exact(point)),
+      Shape::Circle(circle) => Some(circle.within_exact(point)),
+      Shape::Ellipse(ellipse) => Some(ellipse.within_exact(point)),
+      Shape::Triangle(triangle) => Some(triangle.within_exact(point)),
+      Shape::Polygon(polygon) => Some(polygon.within_exact(point)),
+      Shape::PolygonWithHoles(polygon) => Some(polygon.within_exact(point)),
+      Shape::Collection(collection) => collection.within_exact(point),
+      Shape::RotatedRectangle(_) | Shape::RotatedEllipse(_) => None,
+    }
+  }
+}
+
+impl<T, U> ExactWithin<T> for ShapeCollection<T, U>
+where
+  T: IntMath,
+  U: IntMath,
+{
+  type Result = Option<bool>;
+
+  /// Returns `None` if any shape is rotated, even if another one contains the point.
+  fn within_exact(&self, point: &Point2<T>) -> Self::Result {
+    self.shapes.iter().try_fold(false, |inside, shape| {
+      Some(shape.within_exact(point)? || inside)
+    })
+  }
+}
+
+/// Box of the ellipse, the same one [`BoundingBox`] computes with floats.
+fn ellipse_bounds<T: IntMath, R: IntMath>(ellipse: &Ellipse<T, R>) -> Rectangle<T> {
+  let center = ellipse.center.map(Into::<i64>::into);
+  let radius = Vector2::new(ellipse.radius.0.into(), ellipse.radius.1.into());
+
+  Rectangle::new(
+    (center - radius).map(T::saturating_from_i64),
+    (center + radius).map(T::saturating_from_i64),
+  )
+}
+
+fn on_outline<T: IntMath>(polygon: &Polygon<T>, point: &Point2<T>) -> bool {
+  let p = wide(point);
+
+  polygon.edges().any(|edge| {
+    let (a, b) = (wide(&edge.start), wide(&edge.end));
+
+    cross(&a, &b, &p).is_zero()
+      && a.x.min(b.x) <= p.x
+      && p.x <= a.x.max(b.x)
+      && a.y.min(b.y) <= p.y
+      && p.y <= a.y.max(b.y)
+  })
+}
+
+macro_rules! impl_exact_points_inside {
+  ($($shape: ty => [$($generic: ident),*] $bounds: expr);* $(;)?) => {$(
+    impl<T, $($generic,)*> ExactPointsInside<T> for $shape
+    where
+      T: IntMath,
+      $($generic: IntMath,)*
+    {
+      type Result = Vec<Point2<T>>;
+
+      fn points_inside_exact(&self) -> Self::Result {
+        let bounds: fn(&Self) -> Option<Rectangle<T>> = $bounds;
+        points_inside_exact(self, bounds(self))
+      }
+    }
+  )*};
+}
+
+impl_exact_points_inside!(
+  Rectangle<T> => [] |rectangle| Some(*rectangle);
+  Circle<T, R> => [R] |circle| {
+    let radius: i64 = circle.radius.into();
+    let center = circle.center.map(Into::<i64>::into);
+
+    Some(Rectangle::new(
+      center.map(|c| T::saturating_from_i64(c - radius)),
+      center.map(|c| T::saturating_from_i64(c + radius)),
+    ))
+  };
+  Ellipse<T, R> => [R] |ellipse| Some(ellipse_bounds(ellipse));
+  Triangle<T> => [] |triangle| Some(triangle.bbox());
+  Polygon<T> => [] |polygon| (!polygon.points.is_empty()).then(|| polygon.bbox());
+  PolygonWithHoles<T> => [] |polygon| {
+    (!polygon.exterior.points.is_empty()).then(|| polygon.exterior.bbox())
+  };
+);
+
+impl<T, U> ExactPointsInside<T> for Shape<T, U>
+where
+  T: IntMath,
+  U: IntMath,
+{
+  type Result = Option<Vec<Point2<T>>>;
+
+  fn points_inside_exact(&self) -> Self::Result {
+    match self {
+      Shape::Rectangle(rectangle) => Some(rectangle.points_inside_exact()),
+      Shape::Circle(circle) => Some(circle.points_inside_exact()),
+      Shape::Ellipse(ellipse) => Some(ellipse.points_inside_exact()),
+      Shape::Triangle(triangle) => Some(triangle.points_inside_exact()),
+      Shape::Polygon(polygon) => Some(polygon.points_inside_exact()),
+      Shape::PolygonWithHoles(polygon) => Some(polygon.points_inside_exact()),
+      Shape::Collection(collection) => collection.points_inside_exact(),
+      Shape::RotatedRectangle(_) | Shape::RotatedEllipse(_) => None,
+    }
+  }
+}
+
+impl<T, U> ExactPointsInside<T> for ShapeCollection<T, U>
+where
+  T: IntMath,
+  U: IntMath,
+{
+  type Result = Option<Vec<Point2<T>>>;
+
+  /// Merges the points of every shape into one scanline order, without duplicates.
+  fn points_inside_exact(&self) -> Self::Result {
+    let mut points = Vec::new();
+
+    for shape in &self.shapes {
+      points.extend(shape.points_inside_exact()?);
+    }
+
+    points.sort_unstable_by_key(|point| {
+      let point = wide(point);
+      (point.y, point.x)
+    });
+    points.dedup();
+
+    Some(points)
+  }
+}
+
+/// Checks every point of the bounds, row by row.
+fn points_inside_exact<S, T>(shape: &S, bounds: Option<Rectangle<T>>) -> Vec<Point2<T>>
+where
+  S: ExactWithin<T, Result = bool>,
+  T: IntMath,
+{
+  let Some(bounds) = bounds else {
+    return Vec::new();
+  };
+  let (min, max) = (
+    bounds.min().map(Into::<i64>::into),
+    bounds.max().map(Into::<i64>::into),
+  );
+
+  (min.y..=max.y)
+    .flat_map(|y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    .map(|point| point.map(T::saturating_from_i64))
+    .filter(|point| shape.within_exact(point))
+    .collect()
+}
+
+impl<T> ExactDistance<T> for Point2<T>
+where
+  T: IntMath,
+{
+  type Result = Q16;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Q16 {
+    Q16::sqrt(to_u128(distance_squared(&wide(self), &wide(point))))
+  }
+}
+
+impl<T> ExactDistance<T> for Line<T>
+where
+  T: IntMath,
+{
+  type Result = Q16;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Q16 {
+    let (a, b, p) = (wide(&self.start), wide(&self.end), wide(point));
+
+    let dot = (p - a).dot(&(b - a));
+    let length_squared = distance_squared(&a, &b);
+
+    // Closest to one of the ends, or to a point in between.
+    if length_squared.is_zero() || dot.is_negative() {
+      Q16::sqrt(to_u128(distance_squared(&a, &p)))
+    } else if dot > length_squared {
+      Q16::sqrt(to_u128(distance_squared(&b, &p)))
+    } else {
+      // Squares which don't fit are far beyond `Q16::MAX`.
+      let cross = to_u128(cross(&a, &b, &p).abs());
+
+      cross.checked_pow(2).map_or(Q16::MAX, |cross| {
+        Q16::sqrt_ratio(cross, to_u128(length_squared))
+      })
+    }
+  }
+}
+
+impl<T> ExactDistance<T> for Rectangle<T>
+where
+  T: IntMath,
+{
+  type Result = Q16;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Q16 {
+    let (min, max, p) = (wide(self.min()), wide(self.max()), wide(point));
+
+    let dx = (min.x - p.x).max(p.x - max.x).max(T::Wide::zero());
+    let dy = (min.y - p.y).max(p.y - max.y).max(T::Wide::zero());
+
+    Q16::sqrt(to_u128(dx.pow(2) + dy.pow(2)))
+  }
+}
+
+impl<T, R> ExactDistance<T> for Circle<T, R>
+where
+  T: IntMath,
+  R: IntMath,
+{
+  type Result = Q16;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Q16 {
+    if self.within_exact(point) {
+      return Q16::ZERO;
+    }
+
+    // The radius is whole, so it can be taken off the root before rounding.
+    let radius = i128::from(Into::<i64>::into(self.radius));
+    let distance = to_u128(distance_squared(&wide(&self.center), &wide(point)));
+    let root = (distance << (2 * Q16::FRACTION_BITS)).isqrt() as i128;
+    let bits = root - (radius << Q16::FRACTION_BITS);
+
+    Q16(u32::try_from(bits.max(0)).unwrap_or(u32::MAX))
+  }
+}
+
+impl<T> ExactDistance<T> for Triangle<T>
+where
+  T: IntMath,
+{
+  type Result = Q16;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Q16 {
+    [
+      Line::new(self.0, self.1),
+      Line::new(self.1, self.2),
+      Line::new(self.2, self.0),
+    ]
+    .iter()
+    .map(|edge| edge.distance_exact(point))
+    .fold(Q16::MAX, Q16::min)
+  }
+}
+
+impl<T> ExactDistance<T> for Polygon<T>
+where
+  T: IntMath,
+{
+  type Result = Q16;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Q16 {
+    if self.within_exact(point) {
+      return Q16::ZERO;
+    }
+
+    self
+      .edges()
+      .map(|edge| edge.distance_exact(point))
+      .fold(Q16::MAX, Q16::min)
+  }
+}
+
+impl<T> ExactDistance<T> for PolygonWithHoles<T>
+where
+  T: IntMath,
+{
+  type Result = Q16;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Q16 {
+    if self.within_exact(point) {
+      return Q16::ZERO;
+    }
+
+    self
+      .rings()
+      .flat_map(|ring| ring.edges())
+      .map(|edge| edge.distance_exact(point))
+      .fold(Q16::MAX, Q16::min)
+  }
+}
+
+impl<T, U> ExactDistance<T> for Shape<T, U>
+where
+  T: IntMath,
+  U: IntMath,
+{
+  type Result = Option<Q16>;
+
+  fn distance_exact(&self, point: &Point2<T>) -> Self::Result {
+    match self {
+      Shape::Rectangle(rectangle) => Some(rectangle.distance_exact(point)),
+      Shape::Circle(circle) => Some(circle.distance_exact(point)),
+      Shape::Triangle(triangle) => Some(triangle.distance_exact(point)),
+      Shape::Polygon(polygon) => Some(polygon.distance_exact(point)),
+      Shape::PolygonWithHoles(polygon) => Some(polygon.distance_exact(point)),
+      Shape::Collection(collection) => collection.distance_exact(point),
+      Shape::Ellipse(_) | Shape::RotatedRectangle(_) | Shape::RotatedEllipse(_) => None,
+    }
+  }
+}
+
+impl<T, U> ExactDistance<T> for ShapeCollection<T, U>
+where
+  T: IntMath,
+  U: IntMath,
+{
+  type Result = Option<Q16>;
+
+  /// Returns `None` if any shape is rotated or an ellipse, even if another one is closer.
+  fn distance_exact(&self, point: &Point2<T>) -> Self::Result {
+    self.shapes.iter().try_fold(Q16::MAX, |distance, shape| {
+      Some(distance.min(shape.distance_exact(point)?))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use proptest::prelude::ProptestConfig;
+  use test_strategy::proptest;
+
+  use super::{ExactDistance, ExactPointsInside, ExactWithin, Q16};
+  use crate::traits::{Distance, PointsInside, Within};
+  use crate::{
+    Circle, Ellipse, FillRule, Line, Point2, Polygon, PolygonWithHoles, Rectangle, RotatedEllipse,
+    RotatedRectangle, Shape, ShapeCollection, Triangle,
+  };
+
+  fn all_points() -> impl Iterator<Item = Point2<u8>> {
+    (0..=255u8).flat_map(|y| (0..=255u8).map(move |x| Point2::new(x, y)))
+  }
+
+  /// Checks the integer path against the float path on every `u8` point.
+  fn assert_matches_float<S>(shape: &S)
+  where
+    S: ExactWithin<u8, Result = bool> + ExactPointsInside<u8, Result = Vec<Point2<u8>>>,
+    S: PointsInside<u8>,
+    S: for<'a> Within<&'a Point2<u8>, Result = bool>,
+  {
+    for point in all_points() {
+      assert_eq!(
+        shape.within_exact(&point),
+        shape.within(&point),
+        "{point:?}"
+      );
+    }
+    assert_eq!(shape.points_inside_exact(), shape.points_inside());
+  }
+
+  /// Checks that the distance is the float one rounded down, on every `u8` point.
+  fn assert_distance_matches_float<S>(shape: &S)
+  where
+    S: ExactDistance<u8, Result = Q16> + for<'a> Distance<&'a Point2<u8>, Result = f64>,
+  {
+    let step = Q16::from_bits(1).to_f64();
+
+    for point in all_points() {
+      let exact = shape.distance_exact(&point).to_f64();
+      let float = shape.distance(&point);
+
+      assert!(
+        exact <= float + 1e-9 && float < exact + step + 1e-9,
+        "{point:?}: {exact} vs {float}"
+      );
+    }
+  }
+
+  fn polygon(points: &[[u8; 2]]) -> Polygon<u8> {
+    Polygon::new(points.iter().copied().map(Point2::from).collect())
+  }
+
+  #[test]
+  fn q16_conversions() {
+    assert_eq!(Q16::from_int(5).to_bits(), 5 << 16);
+    assert_eq!(Q16::from_int(5).to_int(), 5);
+    assert_eq!(Q16::from_bits(0x18000).to_f64(), 1.5);
+    assert_eq!(
+      Point2::new(0u8, 0).distance_exact(&Point2::new(1, 1)),
+      // √2 = 1.41421356…, rounded down.
+      Q16::from_bits(92681)
+    );
+    assert_eq!(
+      Point2::new(0u16, 0).distance_exact(&Point2::new(65535, 65535)),
+      Q16::MAX
+    );
+  }
+
+  #[test]
+  fn rectangle_matches_float() {
+    let rectangle = Rectangle::new(Point2::new(20, 30), Point2::new(200, 90));
+
+    assert_matches_float(&rectangle);
+    assert_distance_matches_float(&rectangle);
+  }
+
+  #[test]
+  fn circle_matches_float() {
+    for circle in [
+      Circle::<u8, u8>::new(Point2::new(128, 128), 100),
+      Circle::new(Point2::new(5, 250), 30),
+      Circle::new(Point2::new(40, 40), 0),
+    ] {
+      assert_matches_float(&circle);
+      assert_distance_matches_float(&circle);
+    }
+  }
+
+  #[test]
+  fn ellipse_matches_float() {
+    for ellipse in [
+      // Points like (3, 4) lie exactly on the outline.
+      Ellipse::<u8, u8>::new(Point2::new(100, 100), (5, 5)),
+      Ellipse::new(Point2::new(128, 128), (120, 33)),
+      Ellipse::new(Point2::new(250, 10), (30, 90)),
+      Ellipse::new(Point2::new(10, 10), (0, 5)),
+    ] {
+      assert_matches_float(&ellipse);
+    }
+  }
+
+  #[test]
+  fn triangle_matches_float() {
+    for triangle in [
+      Triangle::new(
+        Point2::new(0, 0),
+        Point2::new(255, 100),
+        Point2::new(30, 255),
+      ),
+      Triangle::new(
+        Point2::new(10, 10),
+        Point2::new(100, 100),
+        Point2::new(200, 200),
+      ),
+    ] {
+      assert_matches_float(&triangle);
+      assert_distance_matches_float(&triangle);
+    }
+  }
+
+  #[test]
+  fn polygon_matches_float() {
+    let star = polygon(&[[128, 0], [203, 230], [5, 88], [251, 88], [53, 230]]);
+
+    assert_matches_float(&star);
+    assert_matches_float(&star.clone().with_fill_rule(FillRule::NonZero));
+    assert_distance_matches_float(&star);
+    assert_matches_float(&polygon(&[]));
+  }
+
+  #[test]
+  fn polygon_with_holes_matches_float() {
+    let polygon = PolygonWithHoles::new(
+      polygon(&[[10, 10], [240, 10], [240, 240], [10, 240]]),
+      vec![polygon(&[[50, 50], [100, 50], [75, 200]])],
+    )
+    .unwrap();
+
+    assert_matches_float(&polygon);
+    assert_distance_matches_float(&polygon);
+  }
+
+  #[test]
+  fn i16_within_matches_float() {
+    let circle = Circle::<i16, i16>::new(Point2::new(-1000, 2000), 3000);
+    let ellipse = Ellipse::<i16, i16>::new(Point2::new(0, 0), (i16::MAX, 20000));
+    let triangle = Triangle::<i16>::new(
+      Point2::new(i16::MIN, i16::MIN),
+      Point2::new(i16::MAX, 0),
+      Point2::new(0, i16::MAX),
+    );
+
+    let points = (i16::MIN..=i16::MAX).step_by(97).flat_map(|y| {
+      (i16::MIN..=i16::MAX)
+        .step_by(89)
+        .map(move |x| Point2::new(x, y))
+    });
+
+    for point in points {
+      assert_eq!(circle.within_exact(&point), circle.within(&point));
+      assert_eq!(ellipse.within_exact(&point), ellipse.within(&point));
+      assert_eq!(triangle.within_exact(&point), triangle.within(&point));
+    }
+  }
+
+  #[test]
+  fn u16_ellipse_extremes() {
+    let ellipse = Ellipse::<u16, u16>::new(Point2::new(0, 0), (u16::MAX, u16::MAX));
+    let flat = Ellipse::<u16, u16>::new(Point2::new(0, 1), (u16::MAX, 1));
+
+    for point in [
+      Point2::new(u16::MAX, 0),
+      Point2::new(46340, 46340),
+      Point2::new(46341, 46341),
+      Point2::new(u16::MAX, u16::MAX),
+      Point2::new(u16::MAX, 1),
+      Point2::new(u16::MAX - 1, 1),
+      Point2::new(1, 2),
+    ] {
+      assert_eq!(
+        ellipse.within_exact(&point),
+        ellipse.within(&point),
+        "{point:?}"
+      );
+      assert_eq!(flat.within_exact(&point), flat.within(&point), "{point:?}");
+    }
+  }
+
+  #[test]
+  fn i32_extremes() {
+    let (min, max) = (
+      Point2::new(i32::MIN, i32::MIN),
+      Point2::new(i32::MAX, i32::MAX),
+    );
+    let triangle = Triangle::new(min, Point2::new(i32::MAX, i32::MIN), max);
+    let circle = Circle::<i32, i32>::new(Point2::new(0, 0), i32::MAX);
+    let ellipse = Ellipse::<i32, i32>::new(Point2::new(0, 0), (i32::MAX, 1));
+
+    assert!(triangle.within_exact(&min));
+    assert!(triangle.within_exact(&Point2::new(i32::MAX, 0)));
+    assert!(!triangle.within_exact(&Point2::new(0, i32::MAX)));
+    assert!(circle.within_exact(&Point2::new(i32::MAX, 0)));
+    assert!(!circle.within_exact(&max));
+    assert!(ellipse.within_exact(&Point2::new(i32::MIN + 1, 0)));
+    assert!(!ellipse.within_exact(&Point2::new(i32::MAX, 1)));
+
+    assert_eq!(min.distance_exact(&max), Q16::MAX);
+    assert_eq!(
+      Line::new(min, max).distance_exact(&Point2::new(i32::MIN, i32::MIN + 2)),
+      // √2 = 1.41421356…, rounded down.
+      Q16::from_bits(92681)
+    );
+    assert_eq!(
+      Line::new(min, Point2::new(i32::MAX, i32::MIN)).distance_exact(&max),
+      Q16::MAX
+    );
+    assert_eq!(
+      Circle::<i32, i32>::new(Point2::new(0, 0), 100_000).distance_exact(&Point2::new(100_003, 0)),
+      Q16::from_int(3)
+    );
+  }
+
+  #[test]
+  fn shape_collection_matches_float() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![
+      Rectangle::new(Point2::new(20, 30), Point2::new(200, 90)).into(),
+      Circle::new(Point2::new(128, 128), 100).into(),
+      Shape::Collection(ShapeCollection::new(vec![Ellipse::new(
+        Point2::new(250, 10),
+        (30, 90),
+      )
+      .into()])),
+    ]);
+
+    for point in all_points() {
+      assert_eq!(
+        collection.within_exact(&point),
+        Some(collection.within(&point)),
+        "{point:?}"
+      );
+    }
+    assert_eq!(
+      collection.points_inside_exact(),
+      Some(collection.points_inside())
+    );
+  }
+
+  #[test]
+  fn shape_collection_distance_matches_float() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![
+      Rectangle::new(Point2::new(20, 30), Point2::new(60, 90)).into(),
+      Circle::new(Point2::new(180, 128), 40).into(),
+      Shape::Collection(ShapeCollection::new(vec![Triangle::new(
+        Point2::new(100, 200),
+        Point2::new(250, 250),
+        Point2::new(120, 250),
+      )
+      .into()])),
+    ]);
+    let step = Q16::from_bits(1).to_f64();
+
+    for point in all_points() {
+      let exact = collection.distance_exact(&point).unwrap().to_f64();
+      let float = collection.distance(&point);
+
+      assert!(
+        exact <= float + 1e-9 && float < exact + step + 1e-9,
+        "{point:?}: {exact} vs {float}"
+      );
+    }
+    assert_eq!(
+      ShapeCollection::<u8, u8>::new(vec![]).distance_exact(&Point2::new(0, 0)),
+      Some(Q16::MAX)
+    );
+  }
+
+  #[test]
+  fn rotated_shapes_are_not_exact() {
+    let rectangle: Shape<u8, u8> = RotatedRectangle::new(Point2::new(50, 50), (5, 2), 1.0).into();
+    let ellipse: Shape<u8, u8> = RotatedEllipse::new(Point2::new(50, 50), (5, 2), 1.0).into();
+    let circle: Shape<u8, u8> = Circle::new(Point2::new(50, 50), 10).into();
+    let collection = ShapeCollection::new(vec![circle.clone(), rectangle.clone()]);
+    let point = Point2::new(50, 50);
+
+    assert_eq!(circle.within_exact(&point), Some(true));
+    assert_eq!(rectangle.within_exact(&point), None);
+    assert_eq!(ellipse.points_inside_exact(), None);
+    assert_eq!(collection.within_exact(&point), None);
+    assert_eq!(collection.points_inside_exact(), None);
+    assert_eq!(Shape::Collection(collection).points_inside_exact(), None);
+
+    let ellipse: Shape<u8, u8> = Ellipse::new(Point2::new(50, 50), (5, 2)).into();
+    assert_eq!(circle.distance_exact(&point), Some(Q16::ZERO));
+    assert_eq!(rectangle.distance_exact(&point), None);
+    assert_eq!(ellipse.distance_exact(&point), None);
+    assert_eq!(
+      ShapeCollection::new(vec![circle, ellipse]).distance_exact(&point),
+      None
+    );
+  }
+
+  #[proptest(ProptestConfig::with_cases(4))]
+  fn circle_u8_matches_float(circle: Circle<u8, u8>) {
+    assert_matches_float(&circle);
+    assert_distance_matches_float(&circle);
+  }
+
+  #[proptest(ProptestConfig::with_cases(4))]
+  fn ellipse_u8_matches_float(ellipse: Ellipse<u8, u8>) {
+    assert_matches_float(&ellipse);
+  }
+
+  #[proptest(ProptestConfig::with_cases(4))]
+  fn triangle_u8_matches_float(triangle: Triangle<u8>) {
+    assert_matches_float(&triangle);
+    assert_distance_matches_float(&triangle);
+  }
+
+  #[proptest(ProptestConfig::with_cases(4))]
+  fn polygon_u8_matches_float(polygon: Polygon<u8>) {
+    assert_matches_float(&polygon);
+    assert_distance_matches_float(&polygon);
+  }
+
+  #[test]
+  fn points_inside_exact_order() {
+    let points: Vec<_> =
+      Rectangle::new(Point2::new(1u8, 1), Point2::new(2, 2)).points_inside_exact();
+
+    assert_eq!(
+      points,
+      vec![
+        Point2::new(1, 1),
+        Point2::new(2, 1),
+        Point2::new(1, 2),
+        Point2::new(2, 2)
+      ]
+    );
+  }
+}
@@ -0,0 +1,418 @@
+//! C interface for `u8` shapes, enabled with the `ffi` feature.
+//!
+//! Shapes are created on the heap and handed out as opaque [`RshapesShape`] pointers, which
+//! have to be released with [`rshapes_shape_free`]. Queries never allocate on the caller's
+//! behalf, results are returned by value or written into caller-provided buffers.
+//!
+//! The matching header is `include/rshapes.h`, generated with `just header`, and
+//! `tests/ffi/main.c` shows how to use it (`just ffi-test`).
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::{ptr, slice};
+
+use crate::traits::{BoundingBox, Centroid, Distance, PointsInside, Within};
+use crate::*;
+
+/// Opaque shape with `u8` coordinates and radii.
+pub struct RshapesShape(Shape<u8, u8>);
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RshapesPoint {
+  pub x: u8,
+  pub y: u8,
+}
+
+/// Axis-aligned rectangle, both corners are inclusive.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RshapesRectangle {
+  pub min: RshapesPoint,
+  pub max: RshapesPoint,
+}
+
+/// Rule deciding which points are inside of a self-intersecting polygon, see [`FillRule`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RshapesFillRule {
+  EvenOdd,
+  NonZero,
+}
+
+impl From<RshapesPoint> for Point2<u8> {
+  fn from(point: RshapesPoint) -> Self {
+    Point2::new(point.x, point.y)
+  }
+}
+
+impl From<Point2<u8>> for RshapesPoint {
+  fn from(point: Point2<u8>) -> Self {
+    Self {
+      x: point.x,
+      y: point.y,
+    }
+  }
+}
+
+impl From<RshapesFillRule> for FillRule {
+  fn from(fill_rule: RshapesFillRule) -> Self {
+    match fill_rule {
+      RshapesFillRule::EvenOdd => FillRule::EvenOdd,
+      RshapesFillRule::NonZero => FillRule::NonZero,
+    }
+  }
+}
+
+fn into_raw(shape: impl Into<Shape<u8, u8>>) -> *mut RshapesShape {
+  Box::into_raw(Box::new(RshapesShape(shape.into())))
+}
+
+/// Copies `len` points, a null pointer is only allowed for an empty list.
+///
+/// # Safety
+/// `points` has to point to `len` readable points.
+unsafe fn points(points: *const RshapesPoint, len: usize) -> Vec<Point2<u8>> {
+  if len == 0 {
+    return Vec::new();
+  }
+
+  slice::from_raw_parts(points, len)
+    .iter()
+    .copied()
+    .map(Point2::from)
+    .collect()
+}
+
+#[no_mangle]
+pub extern "C" fn rshapes_rectangle_new(min: RshapesPoint, max: RshapesPoint) -> *mut RshapesShape {
+  into_raw(Rectangle::new(min.into(), max.into()))
+}
+
+/// Creates a rectangle rotated counter-clockwise by `angle` radians around its center.
+#[no_mangle]
+pub extern "C" fn rshapes_rotated_rectangle_new(
+  center: RshapesPoint,
+  half_width: u8,
+  half_height: u8,
+  angle: f64,
+) -> *mut RshapesShape {
+  into_raw(RotatedRectangle::new(
+    center.into(),
+    (half_width, half_height),
+    angle,
+  ))
+}
+
+#[no_mangle]
+pub extern "C" fn rshapes_circle_new(center: RshapesPoint, radius: u8) -> *mut RshapesShape {
+  into_raw(Circle::new(center.into(), radius))
+}
+
+#[no_mangle]
+pub extern "C" fn rshapes_ellipse_new(
+  center: RshapesPoint,
+  radius_x: u8,
+  radius_y: u8,
+) -> *mut RshapesShape {
+  into_raw(Ellipse::new(center.into(), (radius_x, radius_y)))
+}
+
+/// Creates an ellipse rotated counter-clockwise by `angle` radians around its center.
+#[no_mangle]
+pub extern "C" fn rshapes_rotated_ellipse_new(
+  center: RshapesPoint,
+  radius_x: u8,
+  radius_y: u8,
+  angle: f64,
+) -> *mut RshapesShape {
+  into_raw(RotatedEllipse::new(
+    center.into(),
+    (radius_x, radius_y),
+    angle,
+  ))
+}
+
+#[no_mangle]
+pub extern "C" fn rshapes_triangle_new(
+  a: RshapesPoint,
+  b: RshapesPoint,
+  c: RshapesPoint,
+) -> *mut RshapesShape {
+  into_raw(Triangle::new(a.into(), b.into(), c.into()))
+}
+
+/// Creates a polygon from `len` points, which are copied.
+///
+/// # Safety
+/// `points` has to point to `len` readable points, it may only be null if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_polygon_new(
+  points: *const RshapesPoint,
+  len: usize,
+  fill_rule: RshapesFillRule,
+) -> *mut RshapesShape {
+  into_raw(Polygon::new(self::points(points, len)).with_fill_rule(fill_rule.into()))
+}
+
+/// Creates a polygon with holes from `ring_count` rings, the first one is the exterior.
+///
+/// The rings are stored one after another in `points`, ring `i` has `ring_lengths[i]`
+/// points. Returns null if there is no exterior or [`PolygonWithHoles::new`] rejects
+/// the rings.
+///
+/// # Safety
+/// `ring_lengths` has to point to `ring_count` readable lengths, and `points` to as many
+/// readable points as the lengths add up to.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_polygon_with_holes_new(
+  points: *const RshapesPoint,
+  ring_lengths: *const usize,
+  ring_count: usize,
+) -> *mut RshapesShape {
+  if ring_count == 0 {
+    return ptr::null_mut();
+  }
+
+  let mut offset = 0;
+  let mut rings = slice::from_raw_parts(ring_lengths, ring_count)
+    .iter()
+    .map(|&len| {
+      let ring = Polygon::new(self::points(points.wrapping_add(offset), len));
+      offset += len;
+      ring
+    })
+    .collect::<Vec<_>>();
+  let holes = rings.split_off(1);
+
+  match PolygonWithHoles::new(rings.remove(0), holes) {
+    Ok(polygon) => into_raw(polygon),
+    Err(_) => ptr::null_mut(),
+  }
+}
+
+/// Creates a collection from copies of `len` shapes, the shapes stay owned by the caller.
+///
+/// # Safety
+/// `shapes` has to point to `len` valid shape pointers, it may only be null if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_collection_new(
+  shapes: *const *const RshapesShape,
+  len: usize,
+) -> *mut RshapesShape {
+  let shapes = if len == 0 {
+    Vec::new()
+  } else {
+    slice::from_raw_parts(shapes, len)
+      .iter()
+      .map(|&shape| (*shape).0.clone())
+      .collect()
+  };
+
+  into_raw(ShapeCollection::new(shapes))
+}
+
+/// Releases a shape, null is ignored.
+///
+/// # Safety
+/// `shape` has to be null or a pointer returned by one of the constructors, which wasn't
+/// freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_shape_free(shape: *mut RshapesShape) {
+  if !shape.is_null() {
+    drop(Box::from_raw(shape));
+  }
+}
+
+/// # Safety
+/// `shape` has to be a valid shape pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_shape_within(
+  shape: *const RshapesShape,
+  point: RshapesPoint,
+) -> bool {
+  (*shape).0.within(&Point2::from(point))
+}
+
+/// Distance from the outline of the shape to the point, zero for points inside.
+///
+/// # Safety
+/// `shape` has to be a valid shape pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_shape_distance(
+  shape: *const RshapesShape,
+  point: RshapesPoint,
+) -> f64 {
+  (*shape).0.distance(&Point2::from(point))
+}
+
+/// # Safety
+/// `shape` has to be a valid shape pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_shape_bbox(shape: *const RshapesShape) -> RshapesRectangle {
+  let bbox = (*shape).0.bbox();
+
+  RshapesRectangle {
+    min: (*bbox.min()).into(),
+    max: (*bbox.max()).into(),
+  }
+}
+
+/// # Safety
+/// `shape` has to be a valid shape pointer.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_shape_centroid(shape: *const RshapesShape) -> RshapesPoint {
+  (*shape).0.centroid().into()
+}
+
+/// Writes the points inside the shape into `out`, in scanline order.
+///
+/// At most `capacity` points are written. Returns the number of points inside the shape,
+/// so a result above `capacity` means the list was cut short.
+///
+/// # Safety
+/// `shape` has to be a valid shape pointer, and `out` has to point to `capacity` writable
+/// points. `out` may only be null if `capacity` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn rshapes_shape_points_inside(
+  shape: *const RshapesShape,
+  out: *mut RshapesPoint,
+  capacity: usize,
+) -> usize {
+  let mut count = 0;
+  for point in (*shape).0.points_inside_iter() {
+    if count < capacity {
+      out.add(count).write(point.into());
+    }
+    count += 1;
+  }
+
+  count
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+  use core::ptr;
+
+  use super::*;
+  use crate::traits::PointsInside;
+
+  const fn point(x: u8, y: u8) -> RshapesPoint {
+    RshapesPoint { x, y }
+  }
+
+  #[test]
+  fn circle_queries() {
+    let circle = rshapes_circle_new(point(10, 10), 2);
+    let expected = Circle::<u8, u8>::new(Point2::new(10, 10), 2);
+
+    unsafe {
+      assert!(rshapes_shape_within(circle, point(11, 11)));
+      assert!(!rshapes_shape_within(circle, point(12, 12)));
+      assert_eq!(rshapes_shape_distance(circle, point(10, 20)), 8.0);
+      assert_eq!(
+        rshapes_shape_bbox(circle),
+        RshapesRectangle {
+          min: point(8, 8),
+          max: point(12, 12)
+        }
+      );
+      assert_eq!(rshapes_shape_centroid(circle), point(10, 10));
+
+      let mut out = [point(0, 0); 32];
+      let count = rshapes_shape_points_inside(circle, out.as_mut_ptr(), out.len());
+      let points = out[..count]
+        .iter()
+        .copied()
+        .map(Point2::from)
+        .collect::<Vec<_>>();
+      assert_eq!(points, expected.points_inside());
+
+      rshapes_shape_free(circle);
+    }
+  }
+
+  #[test]
+  fn points_inside_reports_full_count() {
+    let rectangle = rshapes_rectangle_new(point(0, 0), point(3, 3));
+
+    unsafe {
+      let mut out = [point(0, 0); 4];
+      assert_eq!(
+        rshapes_shape_points_inside(rectangle, out.as_mut_ptr(), out.len()),
+        16
+      );
+      assert_eq!(out, [point(0, 0), point(1, 0), point(2, 0), point(3, 0)]);
+      assert_eq!(
+        rshapes_shape_points_inside(rectangle, ptr::null_mut(), 0),
+        16
+      );
+
+      rshapes_shape_free(rectangle);
+    }
+  }
+
+  #[test]
+  fn polygon_with_holes_rings() {
+    let points = [
+      point(0, 0),
+      point(10, 0),
+      point(10, 10),
+      point(0, 10),
+      point(3, 3),
+      point(3, 7),
+      point(7, 7),
+      point(7, 3),
+    ];
+
+    unsafe {
+      let polygon = rshapes_polygon_with_holes_new(points.as_ptr(), [4, 4].as_ptr(), 2);
+      assert!(!polygon.is_null());
+      assert!(rshapes_shape_within(polygon, point(1, 1)));
+      assert!(!rshapes_shape_within(polygon, point(5, 5)));
+      rshapes_shape_free(polygon);
+
+      // Only two points in the exterior.
+      let invalid = rshapes_polygon_with_holes_new(points.as_ptr(), [2].as_ptr(), 1);
+      assert!(invalid.is_null());
+      assert!(rshapes_polygon_with_holes_new(ptr::null(), ptr::null(), 0).is_null());
+    }
+  }
+
+  #[test]
+  fn collection_copies_shapes() {
+    let rectangle = rshapes_rectangle_new(point(0, 0), point(10, 10));
+    let polygon = unsafe {
+      rshapes_polygon_new(
+        [point(20, 20), point(30, 20), point(20, 30)].as_ptr(),
+        3,
+        RshapesFillRule::NonZero,
+      )
+    };
+
+    unsafe {
+      let collection =
+        rshapes_collection_new([rectangle.cast_const(), polygon.cast_const()].as_ptr(), 2);
+      rshapes_shape_free(rectangle);
+      rshapes_shape_free(polygon);
+
+      assert!(rshapes_shape_within(collection, point(5, 5)));
+      assert!(rshapes_shape_within(collection, point(21, 21)));
+      assert!(!rshapes_shape_within(collection, point(15, 15)));
+      assert_eq!(
+        rshapes_shape_bbox(collection),
+        RshapesRectangle {
+          min: point(0, 0),
+          max: point(30, 30)
+        }
+      );
+      rshapes_shape_free(collection);
+
+      let empty = rshapes_collection_new(ptr::null(), 0);
+      assert_eq!(rshapes_shape_points_inside(empty, ptr::null_mut(), 0), 0);
+      rshapes_shape_free(empty);
+      rshapes_shape_free(ptr::null_mut());
+    }
+  }
+}
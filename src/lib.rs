@@ -1,24 +1,132 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate alloc;
+// Proptest needs the standard library, and so does the code of its derive macro.
+// Linking it also brings in the float methods, see `math`.
+#[cfg(any(test, feature = "proptest-support"))]
+extern crate std;
+
+mod math;
+// Empty while the standard library is linked.
+#[allow(unused_imports)]
+use math::*;
+
 mod data;
 pub use data::*;
 
 mod shapes;
 pub use shapes::*;
 
+mod primitive;
+
+pub mod contour;
+pub mod exact;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 pub mod traits;
 pub use traits::distance;
 pub use traits::distance_squared;
 
 use nalgebra::Scalar;
-pub use nalgebra::{Point2, Vector2};
-use num::traits::NumOps;
+pub use nalgebra::{Affine2, Isometry2, Point2, Similarity2, Translation2, Vector2};
+use num::traits::{Bounded, NumOps};
+
+/// Coordinate types supported by the geometry traits.
+///
+/// All calculations are carried out in `f64`, and the results are converted back
+/// into the coordinate type with [`FloatMath::from_f64`] or one of its rounding variants.
+pub trait FloatMath: Scalar + NumOps + PartialOrd + Copy + Bounded + Into<f64> {
+  /// Converts an `f64` into `Self`, saturating at the bounds of the type.
+  ///
+  /// Integer types truncate the fractional part.
+  fn from_f64(value: f64) -> Self;
+
+  /// Converts an `f64` into `Self`, saturating at the bounds of the type.
+  ///
+  /// Integer types round to the nearest integer.
+  fn round_from_f64(value: f64) -> Self;
+
+  /// Converts an `f64` into `Self`, saturating at the bounds of the type.
+  ///
+  /// Integer types round towards negative infinity, floating point types to the nearest
+  /// value that is not greater.
+  fn floor_from_f64(value: f64) -> Self;
+
+  /// Converts an `f64` into `Self`, saturating at the bounds of the type.
+  ///
+  /// Integer types round towards positive infinity, floating point types to the nearest
+  /// value that is not smaller.
+  fn ceil_from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_float_math_int {
+  ($($t:ty),*) => {$(
+    impl FloatMath for $t {
+      #[inline]
+      fn from_f64(value: f64) -> Self {
+        value as $t
+      }
+
+      #[inline]
+      fn round_from_f64(value: f64) -> Self {
+        value.round() as $t
+      }
+
+      #[inline]
+      fn floor_from_f64(value: f64) -> Self {
+        value.floor() as $t
+      }
+
+      #[inline]
+      fn ceil_from_f64(value: f64) -> Self {
+        value.ceil() as $t
+      }
+    }
+  )*};
+}
+
+macro_rules! impl_float_math_float {
+  ($($t:ty),*) => {$(
+    impl FloatMath for $t {
+      #[inline]
+      fn from_f64(value: f64) -> Self {
+        value as $t
+      }
+
+      #[inline]
+      fn round_from_f64(value: f64) -> Self {
+        value as $t
+      }
+
+      #[inline]
+      fn floor_from_f64(value: f64) -> Self {
+        // The cast rounds to the nearest value, step down if that went up.
+        let result = value as $t;
+        if f64::from(result) > value {
+          result.next_down()
+        } else {
+          result
+        }
+      }
 
-pub trait FloatMath: Scalar + NumOps + PartialOrd + Copy + Into<f64> {}
+      #[inline]
+      fn ceil_from_f64(value: f64) -> Self {
+        // The cast rounds to the nearest value, step up if that went down.
+        let result = value as $t;
+        if f64::from(result) < value {
+          result.next_up()
+        } else {
+          result
+        }
+      }
+    }
+  )*};
+}
 
-impl FloatMath for u8 {}
-impl FloatMath for u16 {}
-impl FloatMath for u32 {}
-impl FloatMath for f32 {}
-impl FloatMath for f64 {}
+impl_float_math_int!(u8, u16, u32, i16, i32);
+impl_float_math_float!(f32, f64);
 
 #[cfg(test)]
 pub mod testing;
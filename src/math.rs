@@ -0,0 +1,9 @@
+//! Float math with and without the standard library.
+//!
+//! Methods such as `sqrt` or `atan2` are inherent methods of `f32` and `f64` only when the
+//! standard library is linked. Otherwise [`Float`] provides them through `libm`. Importing it
+//! while the inherent methods exist would leave the import unused, so it is only brought into
+//! scope here, and every module picks it up through the crate root.
+
+#[cfg(not(any(feature = "std", test, feature = "proptest-support")))]
+pub(crate) use num::traits::Float;
@@ -0,0 +1,166 @@
+use alloc::vec::Vec;
+
+use super::{closest_point, AsPrimitives, Primitive, EPSILON};
+use crate::traits::{BoundaryPoint, Within};
+use crate::*;
+
+impl Primitive {
+  /// Returns the closest point on the outline, or `None` for an empty polygon.
+  fn boundary_point(&self, point: &Point2<f64>) -> Option<BoundaryPoint> {
+    match self {
+      Self::Polygon(polygon) => polygon_boundary_point(polygon, point),
+      Self::Ellipse(ellipse) => Some(ellipse_boundary_point(ellipse, point)),
+    }
+  }
+}
+
+/// Returns the closest point on the outline of any primitive of the shape.
+pub(crate) fn boundary_point<S: AsPrimitives>(
+  shape: &S,
+  point: &Point2<f64>,
+) -> Option<BoundaryPoint> {
+  shape
+    .primitives()
+    .iter()
+    .filter_map(|primitive| primitive.boundary_point(point))
+    .min_by(|a, b| crate::distance(point, &a.point).total_cmp(&crate::distance(point, &b.point)))
+}
+
+/// Returns the unit vector from the closest point to the point, flipped for points inside,
+/// so it always points out of the primitive.
+fn outward_direction(closest: &Point2<f64>, point: &Point2<f64>, inside: bool) -> Vector2<f64> {
+  let direction = (point - closest).normalize();
+
+  if inside {
+    -direction
+  } else {
+    direction
+  }
+}
+
+fn polygon_boundary_point(
+  polygon: &PolygonWithHoles<f64>,
+  point: &Point2<f64>,
+) -> Option<BoundaryPoint> {
+  // Every edge with its outward normal, which is on the right of counter-clockwise exteriors
+  // and clockwise holes.
+  let candidates = polygon
+    .rings()
+    .enumerate()
+    .flat_map(|(index, ring)| {
+      let sign = if (index == 0) == (ring.signed_area() >= 0.0) {
+        1.0
+      } else {
+        -1.0
+      };
+
+      ring.edges().map(move |edge| {
+        let direction = edge.end - edge.start;
+        let normal = Vector2::new(direction.y, -direction.x) * sign;
+
+        (closest_on_segment(&edge, point), normal)
+      })
+    })
+    .collect::<Vec<_>>();
+
+  let closest = candidates
+    .iter()
+    .map(|(closest, _)| *closest)
+    .min_by(|a, b| crate::distance(point, a).total_cmp(&crate::distance(point, b)))?;
+
+  if closest != *point {
+    return Some(BoundaryPoint {
+      point: closest,
+      normal: outward_direction(&closest, point, polygon.within(point)),
+    });
+  }
+
+  // On the outline the normal of the edge is used, at a vertex the normals of all edges
+  // meeting there are averaged.
+  let tolerance = EPSILON * (1.0 + closest.coords.amax());
+  let mut touching = candidates
+    .iter()
+    .filter(|(candidate, _)| crate::distance(candidate, &closest) <= tolerance)
+    .filter_map(|(_, normal)| normal.try_normalize(0.0));
+  let first = touching.next().unwrap_or_else(Vector2::zeros);
+  let at_vertex = polygon
+    .rings()
+    .flat_map(|ring| ring.points.iter())
+    .any(|vertex| crate::distance(vertex, &closest) <= tolerance);
+
+  let normal = if at_vertex {
+    (first + touching.sum::<Vector2<f64>>())
+      .try_normalize(EPSILON)
+      .unwrap_or(first)
+  } else {
+    first
+  };
+
+  Some(BoundaryPoint {
+    point: closest,
+    normal,
+  })
+}
+
+fn ellipse_boundary_point(
+  ellipse: &RotatedEllipse<f64, f64>,
+  point: &Point2<f64>,
+) -> BoundaryPoint {
+  let closest = closest_point(ellipse, point);
+
+  let normal = if closest != *point {
+    outward_direction(&closest, point, ellipse.within(point))
+  } else {
+    // Gradient of the implicit function of the ellipse, rotated back.
+    let local = ellipse.local_point(&closest);
+    let gradient = Vector2::new(
+      local.x / (ellipse.radius.0 * ellipse.radius.0),
+      local.y / (ellipse.radius.1 * ellipse.radius.1),
+    );
+    let (sin, cos) = ellipse.angle.sin_cos();
+
+    Vector2::new(
+      gradient.x * cos - gradient.y * sin,
+      gradient.x * sin + gradient.y * cos,
+    )
+    .normalize()
+  };
+
+  BoundaryPoint {
+    point: closest,
+    normal,
+  }
+}
+
+fn closest_on_segment(segment: &Line<f64>, point: &Point2<f64>) -> Point2<f64> {
+  let direction = segment.end - segment.start;
+  let length = direction.norm_squared();
+
+  if length == 0.0 {
+    return segment.start;
+  }
+
+  let t = ((point - segment.start).dot(&direction) / length).clamp(0.0, 1.0);
+  segment.start + direction * t
+}
+
+/// Returns the distance to the closest outline, negative inside the shape.
+///
+/// The primitives are combined as a union, by taking the minimum of their signed distances.
+/// Shapes without any outline are infinitely far away.
+pub(crate) fn signed_distance<S: AsPrimitives>(shape: &S, point: &Point2<f64>) -> f64 {
+  shape
+    .primitives()
+    .iter()
+    .filter_map(|primitive| {
+      let boundary = primitive.boundary_point(point)?;
+      let distance = crate::distance(point, &boundary.point);
+
+      Some(if primitive.contains(point) {
+        -distance
+      } else {
+        distance
+      })
+    })
+    .fold(f64::INFINITY, f64::min)
+}
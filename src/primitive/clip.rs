@@ -0,0 +1,529 @@
+//! Boolean operations on polygons, producing new polygons with holes.
+//!
+//! Both operands are overlaid into one planar graph: every edge is split at every vertex and
+//! crossing lying on it, and edges running along each other end up as the same piece of the
+//! graph. Each piece is classified by the operands on its left and on its right side, and the
+//! pieces separating the inside of the result from the outside are traced into rings.
+//!
+//! Coordinates closer than [`EPSILON`] (relative to their magnitude) are merged, so touching and
+//! collinear edges don't produce slivers.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::f64::consts::TAU;
+
+use num::traits::Euclid;
+
+use super::{orientation, AsPrimitives, Primitive, EPSILON};
+use crate::traits::{Distance, Within};
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Operation {
+  Union,
+  Intersection,
+  Difference,
+}
+
+impl Operation {
+  #[inline]
+  fn apply(self, a: bool, b: bool) -> bool {
+    match self {
+      Self::Union => a || b,
+      Self::Intersection => a && b,
+      Self::Difference => a && !b,
+    }
+  }
+}
+
+/// Shapes bounded by straight edges, which can be lowered into a single polygon.
+pub trait AsPolygon {
+  fn polygon(&self) -> PolygonWithHoles<f64>;
+}
+
+impl<U: AsPolygon> AsPolygon for &U {
+  fn polygon(&self) -> PolygonWithHoles<f64> {
+    U::polygon(*self)
+  }
+}
+
+macro_rules! impl_as_polygon {
+  ($($shape: ty),* $(,)?) => {$(
+    impl<T: FloatMath> AsPolygon for $shape {
+      fn polygon(&self) -> PolygonWithHoles<f64> {
+        match self.primitives().pop() {
+          Some(Primitive::Polygon(polygon)) => polygon,
+          _ => unreachable!("polygonal shapes are lowered into a single polygon"),
+        }
+      }
+    }
+  )*};
+}
+
+impl_as_polygon!(
+  Rectangle<T>,
+  RotatedRectangle<T>,
+  Triangle<T>,
+  Polygon<T>,
+  PolygonWithHoles<T>,
+);
+
+/// Ring of a polygon of one of the operands, with its edges split into pieces between shared
+/// vertices.
+struct Ring {
+  operand: usize,
+  polygon: usize,
+  hole: bool,
+  fill_rule: FillRule,
+  edges: Vec<(usize, usize)>,
+}
+
+impl Ring {
+  #[inline]
+  fn filled(&self, winding: i32) -> bool {
+    match self.fill_rule {
+      FillRule::EvenOdd => winding % 2 != 0,
+      FillRule::NonZero => winding != 0,
+    }
+  }
+}
+
+/// Applies the operation to the two sets of polygons.
+///
+/// Each set covers the union of its polygons, which may overlap each other. The result
+/// consists of non-overlapping polygons with counter-clockwise exteriors and clockwise
+/// holes. Polygons which only touch at single vertices are returned separately, and parts
+/// without any area are dropped.
+pub(crate) fn clip(
+  a: &[PolygonWithHoles<f64>],
+  b: &[PolygonWithHoles<f64>],
+  operation: Operation,
+) -> Vec<PolygonWithHoles<f64>> {
+  let operands = [a, b];
+  let scale = operands
+    .iter()
+    .flat_map(|polygons| polygons.iter())
+    .flat_map(|polygon| polygon.rings().flat_map(|ring| ring.points.iter()))
+    .fold(0.0_f64, |scale, point| scale.max(point.coords.amax()));
+  let tolerance = EPSILON * (1.0 + scale);
+
+  let vertices = overlay_vertices(&operands, tolerance);
+  let rings = split_rings(&operands, &vertices, tolerance);
+
+  // Every piece of the graph, with the rings running along it and their directions.
+  let mut pieces = BTreeMap::<(usize, usize), Vec<(usize, i32)>>::new();
+  for (index, ring) in rings.iter().enumerate() {
+    for &(start, end) in &ring.edges {
+      let (key, direction) = if start < end {
+        ((start, end), 1)
+      } else {
+        ((end, start), -1)
+      };
+      pieces.entry(key).or_default().push((index, direction));
+    }
+  }
+
+  let mut boundary = Vec::new();
+  for (&(start, end), members) in &pieces {
+    let windings = rings
+      .iter()
+      .enumerate()
+      .map(|(index, ring)| {
+        let left = left_winding(ring, &vertices, (start, end));
+        let crossing = members
+          .iter()
+          .filter(|(member, _)| *member == index)
+          .map(|(_, direction)| direction)
+          .sum::<i32>();
+
+        (left, left - crossing)
+      })
+      .collect::<Vec<_>>();
+
+    let inside = |side: fn(&(i32, i32)) -> i32| {
+      // Whether the exterior and any of the holes are filled, for every polygon.
+      let mut polygons = BTreeMap::<(usize, usize), (bool, bool)>::new();
+      for (ring, winding) in rings.iter().zip(&windings) {
+        let filled = polygons.entry((ring.operand, ring.polygon)).or_default();
+        if ring.filled(side(winding)) {
+          if ring.hole {
+            filled.1 = true;
+          } else {
+            filled.0 = true;
+          }
+        }
+      }
+
+      let operand = |operand: usize| {
+        polygons
+          .iter()
+          .any(|(&(index, _), &(exterior, hole))| index == operand && exterior && !hole)
+      };
+
+      operation.apply(operand(0), operand(1))
+    };
+
+    match (inside(|winding| winding.0), inside(|winding| winding.1)) {
+      (true, false) => boundary.push((start, end)),
+      (false, true) => boundary.push((end, start)),
+      _ => {}
+    }
+  }
+
+  assemble(trace(&boundary, &vertices), &vertices, tolerance)
+}
+
+/// Collects the vertices of both operands and all crossings of their edges, merging points
+/// closer than the tolerance.
+fn overlay_vertices(operands: &[&[PolygonWithHoles<f64>]; 2], tolerance: f64) -> Vec<Point2<f64>> {
+  let mut vertices = Vec::new();
+  let mut edges = Vec::new();
+
+  for ring in operands
+    .iter()
+    .flat_map(|polygons| polygons.iter())
+    .flat_map(|polygon| polygon.rings())
+  {
+    for point in &ring.points {
+      snap(&mut vertices, point, tolerance);
+    }
+    edges.extend(ring.edges().filter(|edge| edge.start != edge.end));
+  }
+
+  for (index, a) in edges.iter().enumerate() {
+    for b in &edges[index + 1..] {
+      if let Some(point) = crossing(a, b) {
+        snap(&mut vertices, &point, tolerance);
+      }
+    }
+  }
+
+  vertices
+}
+
+/// Returns the index of the vertex close to the point, adding the point if there is none.
+fn snap(vertices: &mut Vec<Point2<f64>>, point: &Point2<f64>, tolerance: f64) -> usize {
+  match vertices
+    .iter()
+    .position(|vertex| crate::distance(vertex, point) <= tolerance)
+  {
+    Some(index) => index,
+    None => {
+      vertices.push(*point);
+      vertices.len() - 1
+    }
+  }
+}
+
+/// Returns the crossing point of two segments, if they cross in a single point.
+fn crossing(a: &Line<f64>, b: &Line<f64>) -> Option<Point2<f64>> {
+  let r = a.end - a.start;
+  let s = b.end - b.start;
+  let denominator = r.perp(&s);
+
+  if denominator == 0.0 {
+    return None;
+  }
+
+  let offset = b.start - a.start;
+  let t = offset.perp(&s) / denominator;
+  let u = offset.perp(&r) / denominator;
+
+  ((0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&u)).then(|| a.start + r * t)
+}
+
+/// Splits the edges of all rings at every vertex lying on them.
+fn split_rings(
+  operands: &[&[PolygonWithHoles<f64>]; 2],
+  vertices: &[Point2<f64>],
+  tolerance: f64,
+) -> Vec<Ring> {
+  let index = |point: &Point2<f64>| {
+    vertices
+      .iter()
+      .position(|vertex| crate::distance(vertex, point) <= tolerance)
+      .expect("all ring points are overlay vertices")
+  };
+
+  operands
+    .iter()
+    .enumerate()
+    .flat_map(|(operand, polygons)| {
+      polygons
+        .iter()
+        .enumerate()
+        .map(move |(polygon, rings)| (operand, polygon, rings))
+    })
+    .flat_map(|(operand, polygon, rings)| {
+      rings.rings().enumerate().map(move |(ring_index, ring)| {
+        let edges = ring
+          .edges()
+          .flat_map(|edge| {
+            let (start, end) = (index(&edge.start), index(&edge.end));
+            let direction = edge.end - edge.start;
+            let length = direction.norm_squared();
+
+            let mut inner = (0..vertices.len())
+              .filter(|&vertex| vertex != start && vertex != end && start != end)
+              .filter(|&vertex| {
+                let t = (vertices[vertex] - edge.start).dot(&direction) / length;
+                0.0 < t && t < 1.0 && edge.distance(&vertices[vertex]) <= tolerance
+              })
+              .map(|vertex| {
+                let t = (vertices[vertex] - edge.start).dot(&direction) / length;
+                (t, vertex)
+              })
+              .collect::<Vec<_>>();
+            inner.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+            let path = core::iter::once(start)
+              .chain(inner.into_iter().map(|(_, vertex)| vertex))
+              .chain(core::iter::once(end))
+              .collect::<Vec<_>>();
+
+            path
+              .windows(2)
+              .map(|pair| (pair[0], pair[1]))
+              .filter(|(start, end)| start != end)
+              .collect::<Vec<_>>()
+          })
+          .collect();
+
+        Ring {
+          operand,
+          polygon,
+          hole: ring_index > 0,
+          fill_rule: ring.fill_rule,
+          edges,
+        }
+      })
+    })
+    .collect()
+}
+
+/// Returns the winding number of the ring just left of the middle of the piece.
+///
+/// Pieces of the ring running along the given piece are skipped, the caller accounts for
+/// them. The ray is cast along the left normal of the piece, so it only starts on those.
+fn left_winding(ring: &Ring, vertices: &[Point2<f64>], piece: (usize, usize)) -> i32 {
+  let (start, end) = (vertices[piece.0], vertices[piece.1]);
+  let middle = Point2::from((start.coords + end.coords) / 2.0);
+  let direction = end - start;
+
+  // Right-handed frame with the ray along the x axis.
+  let local = |point: &Point2<f64>| {
+    let offset = point - middle;
+    Point2::new(
+      direction.x * offset.y - direction.y * offset.x,
+      -direction.dot(&offset),
+    )
+  };
+  let origin = Point2::origin();
+
+  ring
+    .edges
+    .iter()
+    .filter(|&&(a, b)| (a.min(b), a.max(b)) != piece)
+    .map(|&(a, b)| {
+      let (a, b) = (local(&vertices[a]), local(&vertices[b]));
+      let cross = orientation(&a, &b, &origin);
+
+      if a.y <= 0.0 {
+        i32::from(b.y > 0.0 && cross > 0.0)
+      } else {
+        -i32::from(b.y <= 0.0 && cross < 0.0)
+      }
+    })
+    .sum()
+}
+
+/// Traces the directed boundary pieces into closed rings.
+///
+/// At every vertex the ring continues with the sharpest turn to the left, so rings touching
+/// in a vertex are kept apart.
+fn trace(boundary: &[(usize, usize)], vertices: &[Point2<f64>]) -> Vec<Vec<usize>> {
+  let mut outgoing = BTreeMap::<usize, Vec<usize>>::new();
+  for (index, &(start, _)) in boundary.iter().enumerate() {
+    outgoing.entry(start).or_default().push(index);
+  }
+
+  let angle = |from: usize, to: usize| {
+    let direction = vertices[to] - vertices[from];
+    direction.y.atan2(direction.x)
+  };
+
+  let mut used = vec![false; boundary.len()];
+  let mut rings = Vec::new();
+
+  for first in 0..boundary.len() {
+    if used[first] {
+      continue;
+    }
+
+    let mut ring = Vec::new();
+    let mut current = first;
+    let closed = loop {
+      used[current] = true;
+      let (start, end) = boundary[current];
+      ring.push(start);
+
+      let back = angle(end, start);
+      let next = outgoing
+        .get(&end)
+        .into_iter()
+        .flatten()
+        .copied()
+        .filter(|&next| !used[next] || next == first)
+        .min_by(|&a, &b| {
+          let turn = |next: usize| {
+            let turn =
+              Euclid::rem_euclid(&(back - angle(boundary[next].0, boundary[next].1)), &TAU);
+            if turn > 0.0 {
+              turn
+            } else {
+              TAU
+            }
+          };
+          turn(a).total_cmp(&turn(b))
+        });
+
+      match next {
+        Some(next) if next == first => break true,
+        Some(next) => current = next,
+        None => break false,
+      }
+    };
+
+    if closed {
+      rings.push(ring);
+    }
+  }
+
+  rings
+}
+
+/// Groups the traced rings into polygons with holes.
+///
+/// Counter-clockwise rings are exteriors, clockwise rings are holes of the smallest exterior
+/// around them.
+fn assemble(
+  rings: Vec<Vec<usize>>,
+  vertices: &[Point2<f64>],
+  tolerance: f64,
+) -> Vec<PolygonWithHoles<f64>> {
+  let rings = rings
+    .into_iter()
+    .map(|ring| Polygon::new(ring.into_iter().map(|index| vertices[index]).collect()))
+    .map(|ring| {
+      let area = ring.signed_area();
+      (ring, area)
+    })
+    .filter(|(_, area)| *area != 0.0)
+    .collect::<Vec<_>>();
+
+  let (exteriors, holes): (Vec<_>, Vec<_>) = rings.into_iter().partition(|(_, area)| *area > 0.0);
+  let mut polygons = exteriors
+    .iter()
+    .map(|(exterior, _)| (exterior.clone(), Vec::new()))
+    .collect::<Vec<_>>();
+
+  for (hole, area) in holes {
+    let parent = exteriors
+      .iter()
+      .enumerate()
+      .filter(|(_, (exterior, exterior_area))| {
+        *exterior_area > -area && hole.points.iter().all(|point| exterior.within(point))
+      })
+      .min_by(|(_, (_, a)), (_, (_, b))| a.total_cmp(b))
+      .map(|(index, _)| index);
+
+    if let Some(parent) = parent {
+      polygons[parent].1.push(hole);
+    }
+  }
+
+  polygons
+    .into_iter()
+    .filter_map(|(exterior, holes)| {
+      let exterior = simplified(exterior, tolerance)?;
+      let holes = holes
+        .into_iter()
+        .filter_map(|hole| simplified(hole, tolerance))
+        .collect();
+
+      Some(PolygonWithHoles::new_unchecked(exterior, holes))
+    })
+    .collect()
+}
+
+/// Removes vertices in the middle of straight runs, and rings left without any area.
+fn simplified(mut ring: Polygon<f64>, tolerance: f64) -> Option<Polygon<f64>> {
+  while ring.points.len() >= 3 {
+    let count = ring.points.len();
+    let straight = (0..count).find(|&index| {
+      let previous = ring.points[(index + count - 1) % count];
+      let next = ring.points[(index + 1) % count];
+
+      orientation(&previous, &ring.points[index], &next).abs()
+        <= tolerance * crate::distance(&previous, &next)
+    });
+
+    match straight {
+      Some(index) => {
+        ring.points.remove(index);
+      }
+      None => break,
+    }
+  }
+
+  (ring.points.len() >= 3 && ring.signed_area() != 0.0).then_some(ring)
+}
+
+#[cfg(test)]
+mod tests {
+  use test_case::test_case;
+
+  use super::{crossing, simplified};
+  use crate::{Line, Point2, Polygon};
+
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)), Line::new_unchecked(Point2::new(0.0, 2.0), Point2::new(2.0, 0.0)) => Some(Point2::new(1.0, 1.0)); "crossing")]
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)), Line::new_unchecked(Point2::new(1.0, 0.0), Point2::new(1.0, 2.0)) => Some(Point2::new(1.0, 0.0)); "touching")]
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)), Line::new_unchecked(Point2::new(1.0, 0.0), Point2::new(3.0, 0.0)) => None; "collinear")]
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)), Line::new_unchecked(Point2::new(3.0, 1.0), Point2::new(3.0, -1.0)) => None; "apart")]
+  fn test_crossing(a: Line<f64>, b: Line<f64>) -> Option<Point2<f64>> {
+    crossing(&a, &b)
+  }
+
+  #[test]
+  fn test_simplified() {
+    let ring = Polygon::new(vec![
+      Point2::new(0.0, 0.0),
+      Point2::new(5.0, 0.0),
+      Point2::new(10.0, 0.0),
+      Point2::new(10.0, 10.0),
+      Point2::new(0.0, 10.0),
+      Point2::new(0.0, 5.0),
+    ]);
+
+    assert_eq!(
+      simplified(ring, 1e-9).unwrap().points,
+      vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(10.0, 0.0),
+        Point2::new(10.0, 10.0),
+        Point2::new(0.0, 10.0),
+      ]
+    );
+    assert_eq!(
+      simplified(
+        Polygon::new(vec![
+          Point2::new(0.0, 0.0),
+          Point2::new(5.0, 0.0),
+          Point2::new(10.0, 0.0)
+        ]),
+        1e-9
+      ),
+      None
+    );
+  }
+}
@@ -0,0 +1,395 @@
+use alloc::vec::Vec;
+use core::f64::consts::TAU;
+
+use num::traits::Euclid;
+
+use super::{polygon_edges, unit_circle_matrix, Primitive, EPSILON};
+use crate::traits::{Distance, Within};
+use crate::*;
+
+/// Number of samples used to find crossings between two ellipses.
+const ELLIPSE_SAMPLES: usize = 256;
+
+/// Number of bisection steps used to refine a crossing between two ellipses.
+const ELLIPSE_BISECTIONS: usize = 60;
+
+/// Part of the outline of a primitive, parametrized over `0..=1`.
+#[derive(Debug, Clone)]
+enum Curve {
+  Segment(Line<f64>),
+  /// Closed outline of an ellipse, starting at the end of its first axis.
+  Ellipse(RotatedEllipse<f64, f64>),
+}
+
+impl Curve {
+  fn at(&self, t: f64) -> Point2<f64> {
+    match self {
+      Self::Segment(segment) => segment.start + (segment.end - segment.start) * t,
+      Self::Ellipse(ellipse) => {
+        let (sin, cos) = ellipse.angle.sin_cos();
+        let (phi_sin, phi_cos) = (t * TAU).sin_cos();
+        let x = ellipse.radius.0 * phi_cos;
+        let y = ellipse.radius.1 * phi_sin;
+
+        Point2::new(
+          ellipse.center.x + x * cos - y * sin,
+          ellipse.center.y + x * sin + y * cos,
+        )
+      }
+    }
+  }
+
+  /// Returns the parameters where this curve may cross the other one.
+  ///
+  /// Extra parameters are harmless, they only split the curve into more pieces.
+  fn splits(&self, other: &Curve) -> Vec<f64> {
+    match (self, other) {
+      (Self::Segment(segment), Self::Segment(other)) => segment_line_splits(segment, other),
+      (Self::Segment(segment), Self::Ellipse(ellipse)) => {
+        let matrix = unit_circle_matrix(ellipse);
+        let start = matrix.transform_point(&segment.start);
+        let end = matrix.transform_point(&segment.end);
+
+        unit_circle_crossings(&start, &(end - start))
+      }
+      (Self::Ellipse(ellipse), Self::Segment(segment)) => {
+        let matrix = unit_circle_matrix(ellipse);
+        let start = matrix.transform_point(&segment.start);
+        let direction = matrix.transform_point(&segment.end) - start;
+
+        unit_circle_crossings(&start, &direction)
+          .into_iter()
+          .chain(unit_circle_crossings(&start, &-direction))
+          .map(|s| {
+            let point = start + direction * s;
+            Euclid::rem_euclid(&point.y.atan2(point.x), &TAU) / TAU
+          })
+          .collect()
+      }
+      (Self::Ellipse(_), Self::Ellipse(other)) => {
+        let matrix = unit_circle_matrix(other);
+        let level = |t: f64| matrix.transform_point(&self.at(t)).coords.norm_squared() - 1.0;
+
+        (0..ELLIPSE_SAMPLES)
+          .filter_map(|i| {
+            let mut low = i as f64 / ELLIPSE_SAMPLES as f64;
+            let mut high = (i + 1) as f64 / ELLIPSE_SAMPLES as f64;
+            let low_sign = level(low).signum();
+
+            if low_sign == level(high).signum() {
+              return None;
+            }
+
+            for _ in 0..ELLIPSE_BISECTIONS {
+              let middle = (low + high) / 2.0;
+              if level(middle).signum() == low_sign {
+                low = middle;
+              } else {
+                high = middle;
+              }
+            }
+
+            Some((low + high) / 2.0)
+          })
+          .collect()
+      }
+    }
+  }
+
+  /// Returns one point on every piece of the curve, after splitting it at all crossings
+  /// with the other curves.
+  fn piece_midpoints(&self, curves: &[Curve]) -> Vec<Point2<f64>> {
+    let mut splits = curves
+      .iter()
+      .flat_map(|other| self.splits(other))
+      .filter(|t| (0.0..=1.0).contains(t))
+      .collect::<Vec<_>>();
+    splits.sort_by(f64::total_cmp);
+
+    let midpoints = match self {
+      Self::Segment(_) => core::iter::once(0.0)
+        .chain(splits.iter().copied())
+        .zip(splits.iter().copied().chain(core::iter::once(1.0)))
+        .map(|(a, b)| (a + b) / 2.0)
+        .collect::<Vec<_>>(),
+      Self::Ellipse(_) => match (splits.first(), splits.last()) {
+        (Some(first), Some(last)) => splits
+          .windows(2)
+          .map(|pair| (pair[0] + pair[1]) / 2.0)
+          .chain(core::iter::once(((last + first + 1.0) / 2.0).fract()))
+          .collect(),
+        _ => vec![0.0],
+      },
+    };
+
+    midpoints.into_iter().map(|t| self.at(t)).collect()
+  }
+}
+
+/// Returns the parameters along the segment where it crosses the line through the other one,
+/// or where the endpoints of a collinear segment lie.
+fn segment_line_splits(segment: &Line<f64>, other: &Line<f64>) -> Vec<f64> {
+  let cross = |a: Vector2<f64>, b: Vector2<f64>| a.x * b.y - a.y * b.x;
+  let direction = segment.end - segment.start;
+  let other_direction = other.end - other.start;
+  let denominator = cross(direction, other_direction);
+
+  if denominator != 0.0 {
+    return vec![cross(other.start - segment.start, other_direction) / denominator];
+  }
+
+  let length = direction.norm_squared();
+  if length == 0.0 {
+    return vec![];
+  }
+
+  [other.start, other.end]
+    .iter()
+    .map(|point| (point - segment.start).dot(&direction) / length)
+    .collect()
+}
+
+/// Returns the parameters `s >= 0` where `start + direction * s` crosses the unit circle.
+fn unit_circle_crossings(start: &Point2<f64>, direction: &Vector2<f64>) -> Vec<f64> {
+  let a = direction.norm_squared();
+  let b = 2.0 * start.coords.dot(direction);
+  let c = start.coords.norm_squared() - 1.0;
+  let discriminant = b * b - 4.0 * a * c;
+
+  if a == 0.0 || discriminant < 0.0 {
+    return vec![];
+  }
+
+  let root = discriminant.sqrt();
+  [(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)]
+    .into_iter()
+    .filter(|s| *s >= 0.0)
+    .collect()
+}
+
+impl Primitive {
+  fn outline(&self) -> Vec<Curve> {
+    match self {
+      Self::Polygon(polygon) => polygon_edges(polygon)
+        .into_iter()
+        .map(Curve::Segment)
+        .collect(),
+      Self::Ellipse(ellipse) => vec![Curve::Ellipse(*ellipse)],
+    }
+  }
+
+  /// Checks if the point is within a small tolerance of the outline of the primitive.
+  ///
+  /// Points computed at crossings are rounded, so exact outline tests would miss them.
+  fn near_outline(&self, point: &Point2<f64>) -> bool {
+    let tolerance = EPSILON * (1.0 + point.coords.amax());
+
+    match self {
+      Self::Polygon(_) => self
+        .edges()
+        .iter()
+        .any(|edge| edge.distance(point) <= tolerance),
+      Self::Ellipse(ellipse) => {
+        crate::distance(point, &super::closest_point(ellipse, point)) <= tolerance
+      }
+    }
+  }
+
+  /// Checks if the point is inside the primitive or on its outline, within a tolerance.
+  fn covers(&self, point: &Point2<f64>) -> bool {
+    self.contains(point) || self.near_outline(point)
+  }
+
+  /// Checks if the point is inside the primitive, and not on its outline.
+  fn contains_strictly(&self, point: &Point2<f64>) -> bool {
+    self.contains(point) && !self.near_outline(point)
+  }
+
+  /// Returns the vertices of a convex polygon in counter-clockwise order,
+  /// or `None` if the polygon is not convex or has holes.
+  ///
+  /// Segments and single points are convex as well.
+  fn convex_vertices(polygon: &PolygonWithHoles<f64>) -> Option<Vec<Point2<f64>>> {
+    if !polygon.holes.is_empty() {
+      return None;
+    }
+
+    let mut points = polygon.exterior.points.clone();
+    if polygon.exterior.signed_area() < 0.0 {
+      points.reverse();
+    }
+
+    let count = points.len();
+    let mut turning = 0.0;
+    for i in 0..count {
+      let a = points[i];
+      let b = points[(i + 1) % count];
+      let c = points[(i + 2) % count];
+      let (first, second) = (b - a, c - b);
+      let cross = first.x * second.y - first.y * second.x;
+
+      if cross < 0.0 {
+        return None;
+      }
+      turning += cross.atan2(first.dot(&second));
+    }
+
+    // Star-shaped outlines turn left all the time, but more than once around.
+    (count < 3 || turning <= TAU + EPSILON).then_some(points)
+  }
+
+  /// Checks if the other primitive lies inside this one, if this primitive is convex.
+  ///
+  /// Returns `None` for primitives which are not convex.
+  fn contains_convex(&self, other: &Self) -> Option<bool> {
+    match (self, other) {
+      (Self::Polygon(polygon), Self::Polygon(other)) => {
+        Self::convex_vertices(polygon)?;
+        Some(
+          other
+            .rings()
+            .flat_map(|ring| ring.points.iter())
+            .all(|point| polygon.within(point)),
+        )
+      }
+      (Self::Polygon(polygon), Self::Ellipse(ellipse)) => {
+        let points = Self::convex_vertices(polygon)?;
+        if points.len() < 3 || polygon.exterior.signed_area() == 0.0 {
+          return Some(false);
+        }
+
+        // The ellipse is inside, if it is behind the supporting line of every edge.
+        let (sin, cos) = ellipse.angle.sin_cos();
+        let inside = points
+          .iter()
+          .zip(points.iter().cycle().skip(1))
+          .all(|(a, b)| {
+            let edge = b - a;
+            let normal = Vector2::new(edge.y, -edge.x);
+            let extent = (ellipse.radius.0 * (normal.x * cos + normal.y * sin))
+              .hypot(ellipse.radius.1 * (normal.y * cos - normal.x * sin));
+
+            normal.dot(&ellipse.center.coords) + extent
+              <= normal.dot(&a.coords) + EPSILON * normal.norm() * extent.max(1.0)
+          });
+
+        Some(inside)
+      }
+      (Self::Ellipse(ellipse), Self::Polygon(polygon)) => Some(
+        polygon
+          .rings()
+          .flat_map(|ring| ring.points.iter())
+          .all(|point| ellipse.within(point)),
+      ),
+      (Self::Ellipse(ellipse), Self::Ellipse(other)) => {
+        // In the coordinate system where the other ellipse is the unit circle,
+        // this ellipse must contain the origin and be at least 1 away from it.
+        let mapped = super::transform_ellipse(
+          &unit_circle_matrix(other),
+          &ellipse.center,
+          &ellipse.radius,
+          ellipse.angle,
+        );
+
+        let inside = match Primitive::ellipse(mapped.center, mapped.radius, mapped.angle) {
+          Primitive::Ellipse(mapped) => {
+            let origin = Point2::origin();
+            mapped.within(&origin)
+              && crate::distance(&origin, &super::closest_point(&mapped, &origin)) >= 1.0 - EPSILON
+          }
+          Primitive::Polygon(_) => false,
+        };
+
+        Some(inside)
+      }
+    }
+  }
+}
+
+/// Checks if the shape lies inside the union of the container primitives.
+///
+/// The shape is covered, if its outline is covered and no outline of a container
+/// enters the shape without being covered by another container. Both outlines are
+/// split at all crossings, and checked at one point of every piece.
+pub(crate) fn contains(container: &[Primitive], shape: &Primitive) -> bool {
+  let convex = container
+    .iter()
+    .map(|primitive| primitive.contains_convex(shape))
+    .collect::<Vec<_>>();
+
+  if convex.contains(&Some(true)) {
+    return true;
+  }
+  if container.len() == 1 && convex[0].is_some() {
+    return false;
+  }
+
+  let outlines = container.iter().map(Primitive::outline).collect::<Vec<_>>();
+  let shape_outline = shape.outline();
+  let curves = outlines
+    .iter()
+    .flatten()
+    .chain(shape_outline.iter())
+    .cloned()
+    .collect::<Vec<_>>();
+
+  let covered = |point: &Point2<f64>, skip: Option<usize>| {
+    container
+      .iter()
+      .enumerate()
+      .any(|(index, primitive)| Some(index) != skip && primitive.covers(point))
+  };
+
+  let outline_covered = shape_outline
+    .iter()
+    .flat_map(|curve| curve.piece_midpoints(&curves))
+    .all(|point| covered(&point, None));
+
+  outline_covered
+    && outlines.iter().enumerate().all(|(index, outline)| {
+      outline
+        .iter()
+        .flat_map(|curve| curve.piece_midpoints(&curves))
+        .all(|point| !shape.contains_strictly(&point) || covered(&point, Some(index)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use test_case::test_case;
+
+  use super::{contains, Primitive};
+  use crate::RotatedEllipse;
+
+  fn polygon(points: &[[f64; 2]]) -> Primitive {
+    Primitive::polygon(points.iter().map(|&point| point.into()).collect())
+  }
+
+  fn circle(center: [f64; 2], radius: f64) -> Primitive {
+    Primitive::Ellipse(RotatedEllipse::new(center.into(), (radius, radius), 0.0))
+  }
+
+  #[test_case(vec![circle([0.0, 0.0], 2.0), circle([2.0, 0.0], 2.0)], polygon(&[[0.0, -1.0], [2.0, -1.0], [2.0, 1.0], [0.0, 1.0]]) => true; "rectangle across two circles")]
+  #[test_case(vec![circle([0.0, 0.0], 1.0), circle([3.0, 0.0], 1.0)], polygon(&[[0.0, 0.0], [3.0, 0.0]]) => false; "segment across a gap")]
+  #[test_case(vec![polygon(&[[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]]), polygon(&[[2.0, 0.0], [4.0, 0.0], [4.0, 2.0], [2.0, 2.0]])], polygon(&[[1.0, 1.0], [3.0, 1.0], [3.0, 2.0]]) => true; "triangle across two squares")]
+  #[test_case(vec![polygon(&[[0.0, 0.0], [4.0, 0.0], [4.0, 1.0], [0.0, 1.0]]), polygon(&[[0.0, 3.0], [4.0, 3.0], [4.0, 4.0], [0.0, 4.0]]), polygon(&[[0.0, 0.0], [1.0, 0.0], [1.0, 4.0], [0.0, 4.0]]), polygon(&[[3.0, 0.0], [4.0, 0.0], [4.0, 4.0], [3.0, 4.0]])], polygon(&[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0]]) => false; "frame with a hole")]
+  #[test_case(vec![circle([0.0, 0.0], 2.0), circle([1.0, 0.0], 2.0)], circle([0.5, 0.0], 2.0) => false; "circle sticking out of the union")]
+  #[test_case(vec![circle([0.0, 0.0], 2.0), circle([1.0, 0.0], 2.0)], circle([0.5, 0.0], 1.0) => true; "circle inside the union")]
+  fn test_contains_union(container: Vec<Primitive>, shape: Primitive) -> bool {
+    contains(&container, &shape)
+  }
+
+  #[test_case(&[[0.0, 10.0], [6.0, -8.0], [-9.0, 3.0], [9.0, 3.0], [-6.0, -8.0]] => false; "star")]
+  #[test_case(&[[0.0, 0.0], [4.0, 0.0], [2.0, 1.0], [4.0, 4.0], [0.0, 4.0]] => false; "concave")]
+  #[test_case(&[[0.0, 0.0], [0.0, 4.0], [4.0, 4.0], [4.0, 0.0]] => true; "clockwise square")]
+  #[test_case(&[[0.0, 0.0], [4.0, 4.0]] => true; "segment")]
+  fn test_convex_vertices(points: &[[f64; 2]]) -> bool {
+    let Primitive::Polygon(polygon) = polygon(points) else {
+      unreachable!()
+    };
+
+    Primitive::convex_vertices(&polygon).is_some()
+  }
+}
@@ -0,0 +1,150 @@
+use super::{closest_point, point_ellipse_distance, AsPrimitives, Primitive};
+use crate::traits::Distance;
+use crate::*;
+
+/// Number of steps of the golden-section search, enough to exhaust the precision of `f64`.
+const GOLDEN_SECTION_STEPS: usize = 100;
+
+/// Maximum number of alternating projections between two ellipses.
+const MAX_PROJECTIONS: usize = 1000;
+
+impl Primitive {
+  /// Returns the minimum distance between the primitives, 0 if they intersect.
+  fn distance(&self, other: &Self) -> f64 {
+    if self.intersects(other) {
+      return 0.0;
+    }
+
+    match (self, other) {
+      (Self::Polygon(_), Self::Polygon(_)) => {
+        let edges = other.edges();
+
+        self
+          .edges()
+          .iter()
+          .flat_map(|a| edges.iter().map(move |b| segments_distance(a, b)))
+          .fold(f64::INFINITY, f64::min)
+      }
+      (Self::Polygon(_), Self::Ellipse(ellipse)) => self
+        .edges()
+        .iter()
+        .map(|edge| segment_ellipse_distance(edge, ellipse))
+        .fold(f64::INFINITY, f64::min),
+      (Self::Ellipse(_), Self::Polygon(_)) => other.distance(self),
+      (Self::Ellipse(a), Self::Ellipse(b)) => ellipses_distance(a, b),
+    }
+  }
+}
+
+/// Returns the minimum distance between the primitives of two shapes, 0 if they intersect.
+///
+/// Shapes without any primitives, such as empty collections, are infinitely far away.
+pub(crate) fn distance<A: AsPrimitives, B: AsPrimitives>(a: &A, b: &B) -> f64 {
+  let others = b.primitives();
+
+  a.primitives()
+    .iter()
+    .flat_map(|primitive| others.iter().map(move |other| primitive.distance(other)))
+    .fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the distance between two segments which do not intersect.
+fn segments_distance(a: &Line<f64>, b: &Line<f64>) -> f64 {
+  [
+    a.distance(&b.start),
+    a.distance(&b.end),
+    b.distance(&a.start),
+    b.distance(&a.end),
+  ]
+  .into_iter()
+  .fold(f64::INFINITY, f64::min)
+}
+
+/// Returns the distance between a segment and an ellipse which do not intersect.
+///
+/// The distance to a convex shape is a convex function along the segment,
+/// so its minimum is found with a golden-section search.
+fn segment_ellipse_distance(segment: &Line<f64>, ellipse: &RotatedEllipse<f64, f64>) -> f64 {
+  let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+  let at = |t: f64| segment.start + (segment.end - segment.start) * t;
+  let distance_at = |t: f64| point_ellipse_distance(&at(t), ellipse);
+
+  let (mut low, mut high) = (0.0, 1.0);
+  for _ in 0..GOLDEN_SECTION_STEPS {
+    let first = high - (high - low) * ratio;
+    let second = low + (high - low) * ratio;
+
+    if distance_at(first) < distance_at(second) {
+      high = second;
+    } else {
+      low = first;
+    }
+  }
+
+  distance_at((low + high) / 2.0)
+    .min(distance_at(0.0))
+    .min(distance_at(1.0))
+}
+
+/// Returns the distance between two ellipses which do not intersect.
+///
+/// Circles are handled exactly. Otherwise alternating projections between two disjoint
+/// convex shapes converge to their closest points.
+fn ellipses_distance(a: &RotatedEllipse<f64, f64>, b: &RotatedEllipse<f64, f64>) -> f64 {
+  if a.radius.0 == a.radius.1 && b.radius.0 == b.radius.1 {
+    return (crate::distance(&a.center, &b.center) - a.radius.0 - b.radius.0).max(0.0);
+  }
+
+  let mut on_a = closest_point(a, &b.center);
+  let mut on_b = closest_point(b, &on_a);
+
+  for _ in 0..MAX_PROJECTIONS {
+    let next = closest_point(a, &on_b);
+    let moved = crate::distance(&next, &on_a);
+
+    on_a = next;
+    on_b = closest_point(b, &on_a);
+
+    if moved <= f64::EPSILON * on_a.coords.norm().max(1.0) {
+      break;
+    }
+  }
+
+  crate::distance(&on_a, &on_b)
+}
+
+#[cfg(test)]
+mod tests {
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+
+  use super::Primitive;
+  use crate::{Point2, RotatedEllipse};
+
+  #[test_case((2.0, 1.0), 0.0, Point2::new(5.0, 0.0), (1.0, 1.0) => 2.0; "circle on the major axis")]
+  #[test_case((2.0, 1.0), 0.0, Point2::new(0.0, 3.0), (1.0, 1.0) => 1.0; "circle on the minor axis")]
+  #[test_case((2.0, 1.0), core::f64::consts::FRAC_PI_2, Point2::new(0.0, 5.0), (2.0, 1.0) => 1.0; "rotated, tip to tip")]
+  #[test_case((1.0, 1.0), 0.0, Point2::new(3.0, 4.0), (1.0, 1.0) => 3.0; "circles")]
+  fn test_ellipses_distance(
+    radius: (f64, f64),
+    angle: f64,
+    center: Point2<f64>,
+    other: (f64, f64),
+  ) -> f64 {
+    let a = Primitive::Ellipse(RotatedEllipse::new(Point2::origin(), radius, angle));
+    let b = Primitive::Ellipse(RotatedEllipse::new(center, other, angle));
+    let distance = a.distance(&b);
+
+    assert_approx_eq!(f64, distance, b.distance(&a), epsilon = 1e-9);
+    (distance * 1e9).round() / 1e9
+  }
+
+  #[test]
+  fn test_segment_ellipse_distance() {
+    let segment = Primitive::polygon(vec![Point2::new(-5.0, 3.0), Point2::new(5.0, 3.0)]);
+    let ellipse = Primitive::Ellipse(RotatedEllipse::new(Point2::origin(), (4.0, 1.0), 0.0));
+
+    assert_approx_eq!(f64, segment.distance(&ellipse), 2.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, ellipse.distance(&segment), 2.0, epsilon = 1e-9);
+  }
+}
@@ -0,0 +1,155 @@
+use crate::*;
+
+/// Maximum number of bisection steps, enough to exhaust the precision of `f64`.
+const MAX_BISECTIONS: usize = 1100;
+
+/// Returns the point on the outline of the axis-aligned ellipse centered at the origin,
+/// which is closest to the given point.
+///
+/// Uses the robust bisection method by David Eberly, "Distance from a Point to an Ellipse,
+/// an Ellipsoid, or a Hyperellipsoid". Points inside the ellipse are projected onto the
+/// outline as well. Ellipses with a zero radius are treated as segments.
+pub(crate) fn closest_point_axis_aligned(radius: (f64, f64), point: Point2<f64>) -> Point2<f64> {
+  let (a, b) = (radius.0.abs(), radius.1.abs());
+
+  if a == 0.0 || b == 0.0 {
+    return Point2::new(point.x.clamp(-a, a), point.y.clamp(-b, b));
+  }
+
+  // The algorithm requires the first radius to be the larger one.
+  let swap = a < b;
+  let (e0, e1, y0, y1) = if swap {
+    (b, a, point.y.abs(), point.x.abs())
+  } else {
+    (a, b, point.x.abs(), point.y.abs())
+  };
+
+  let (x0, x1) = closest_point_first_quadrant(e0, e1, y0, y1);
+  let (x, y) = if swap { (x1, x0) } else { (x0, x1) };
+
+  Point2::new(x.copysign(point.x), y.copysign(point.y))
+}
+
+/// Solves the problem for `e0 >= e1 > 0` and a point in the first quadrant.
+fn closest_point_first_quadrant(e0: f64, e1: f64, y0: f64, y1: f64) -> (f64, f64) {
+  if y1 > 0.0 {
+    if y0 > 0.0 {
+      let z0 = y0 / e0;
+      let z1 = y1 / e1;
+      let g = z0 * z0 + z1 * z1 - 1.0;
+
+      if g == 0.0 {
+        return (y0, y1);
+      }
+
+      let r0 = (e0 / e1) * (e0 / e1);
+      let s = root(r0, z0, z1, g);
+
+      (r0 * y0 / (s + r0), y1 / (s + 1.0))
+    } else {
+      (0.0, e1)
+    }
+  } else {
+    let numerator = e0 * y0;
+    let denominator = e0 * e0 - e1 * e1;
+
+    if numerator < denominator {
+      let ratio = numerator / denominator;
+      (e0 * ratio, e1 * (1.0 - ratio * ratio).sqrt())
+    } else {
+      (e0, 0.0)
+    }
+  }
+}
+
+/// Finds the root of `(r0 * z0 / (s + r0))² + (z1 / (s + 1))² - 1` by bisection.
+fn root(r0: f64, z0: f64, z1: f64, g: f64) -> f64 {
+  let n0 = r0 * z0;
+  let mut s0 = z1 - 1.0;
+  let mut s1 = if g < 0.0 { 0.0 } else { n0.hypot(z1) - 1.0 };
+  let mut s = 0.0;
+
+  for _ in 0..MAX_BISECTIONS {
+    s = (s0 + s1) / 2.0;
+    if s == s0 || s == s1 {
+      break;
+    }
+
+    let ratio0 = n0 / (s + r0);
+    let ratio1 = z1 / (s + 1.0);
+    let g = ratio0 * ratio0 + ratio1 * ratio1 - 1.0;
+
+    if g > 0.0 {
+      s0 = s;
+    } else if g < 0.0 {
+      s1 = s;
+    } else {
+      break;
+    }
+  }
+
+  s
+}
+
+/// Returns the point on the outline of the ellipse closest to the given point.
+pub(crate) fn closest_point(
+  ellipse: &RotatedEllipse<f64, f64>,
+  point: &Point2<f64>,
+) -> Point2<f64> {
+  let local = closest_point_axis_aligned(ellipse.radius, ellipse.local_point(point));
+  let (sin, cos) = ellipse.angle.sin_cos();
+
+  Point2::new(
+    ellipse.center.x + local.x * cos - local.y * sin,
+    ellipse.center.y + local.x * sin + local.y * cos,
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+
+  use super::closest_point_axis_aligned;
+  use crate::Point2;
+
+  #[test_case((2.0, 1.0), Point2::new(5.0, 0.0), Point2::new(2.0, 0.0); "major axis, outside")]
+  #[test_case((2.0, 1.0), Point2::new(0.0, -3.0), Point2::new(0.0, -1.0); "minor axis, outside")]
+  #[test_case((2.0, 1.0), Point2::new(0.5, 0.0), Point2::new(2.0 / 3.0, 0.942_809_041_582_063_4); "major axis, inside")]
+  #[test_case((1.0, 2.0), Point2::new(0.0, 0.5), Point2::new(0.942_809_041_582_063_4, 2.0 / 3.0); "tall, inside")]
+  #[test_case((1.0, 1.0), Point2::new(-3.0, 4.0), Point2::new(-0.6, 0.8); "circle")]
+  #[test_case((2.0, 0.0), Point2::new(3.0, 1.0), Point2::new(2.0, 0.0); "degenerate")]
+  fn test_closest_point_axis_aligned(
+    radius: (f64, f64),
+    point: Point2<f64>,
+    expected: Point2<f64>,
+  ) {
+    let closest = closest_point_axis_aligned(radius, point);
+
+    assert_approx_eq!(f64, closest.x.abs(), expected.x.abs(), epsilon = 1e-9);
+    assert_approx_eq!(f64, closest.y, expected.y, epsilon = 1e-9);
+  }
+
+  #[test]
+  fn test_closest_point_is_normal() {
+    let (a, b) = (5.0, 2.0);
+    let point = Point2::new(4.0, 3.0);
+    let closest = closest_point_axis_aligned((a, b), point);
+
+    // On the outline, and the offset is parallel to the gradient of the implicit function.
+    assert_approx_eq!(
+      f64,
+      (closest.x / a).powi(2) + (closest.y / b).powi(2),
+      1.0,
+      epsilon = 1e-12
+    );
+    let gradient = (closest.x / (a * a), closest.y / (b * b));
+    let offset = (point.x - closest.x, point.y - closest.y);
+    assert_approx_eq!(
+      f64,
+      gradient.0 * offset.1 - gradient.1 * offset.0,
+      0.0,
+      epsilon = 1e-12
+    );
+  }
+}
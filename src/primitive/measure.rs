@@ -0,0 +1,181 @@
+//! Area and perimeter of the union of the primitives of a shape.
+//!
+//! Primitives which don't touch any other primitive are measured exactly. Groups of touching
+//! or overlapping primitives are merged with the polygon clipping first, with ellipses
+//! standing in as polygons of the same area.
+
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use core::f64::consts::{PI, TAU};
+
+use super::{clip, AsPrimitives, Operation, Primitive};
+use crate::*;
+
+/// Number of vertices of the polygons standing in for ellipses.
+const ELLIPSE_SEGMENTS: usize = 256;
+
+impl Primitive {
+  fn area(&self) -> f64 {
+    match self {
+      // The union of a single polygon resolves its fill rule and self-intersections.
+      Self::Polygon(polygon) => {
+        polygons_area(&clip(core::slice::from_ref(polygon), &[], Operation::Union))
+      }
+      Self::Ellipse(ellipse) => PI * ellipse.radius.0 * ellipse.radius.1,
+    }
+  }
+
+  fn perimeter(&self) -> f64 {
+    match self {
+      Self::Polygon(polygon) => polygons_perimeter(core::slice::from_ref(polygon)),
+      Self::Ellipse(ellipse) => ellipse_perimeter(ellipse.radius),
+    }
+  }
+
+  /// Returns the primitive as a polygon, ellipses are replaced by polygons with the same area.
+  fn polygonized(&self) -> PolygonWithHoles<f64> {
+    match self {
+      Self::Polygon(polygon) => polygon.clone(),
+      Self::Ellipse(ellipse) => {
+        let step = TAU / ELLIPSE_SEGMENTS as f64;
+        let scale = (TAU / (ELLIPSE_SEGMENTS as f64 * step.sin())).sqrt();
+        let (sin, cos) = ellipse.angle.sin_cos();
+
+        let points = (0..ELLIPSE_SEGMENTS)
+          .map(|index| {
+            let (y, x) = (index as f64 * step).sin_cos();
+            let (x, y) = (x * ellipse.radius.0 * scale, y * ellipse.radius.1 * scale);
+
+            ellipse.center + Vector2::new(x * cos - y * sin, x * sin + y * cos)
+          })
+          .collect();
+
+        PolygonWithHoles::new_unchecked(Polygon::new(points), vec![])
+      }
+    }
+  }
+}
+
+/// Returns the area covered by the shape, counting overlapping primitives once.
+pub(crate) fn area<S: AsPrimitives>(shape: &S) -> f64 {
+  measure(shape, Primitive::area, polygons_area)
+}
+
+/// Returns the length of the outline of the shape, leaving out the parts inside other
+/// primitives.
+pub(crate) fn perimeter<S: AsPrimitives>(shape: &S) -> f64 {
+  measure(shape, Primitive::perimeter, polygons_perimeter)
+}
+
+fn measure<S: AsPrimitives>(
+  shape: &S,
+  single: fn(&Primitive) -> f64,
+  merged: fn(&[PolygonWithHoles<f64>]) -> f64,
+) -> f64 {
+  let primitives = shape.primitives();
+
+  touching_groups(&primitives)
+    .into_iter()
+    .map(|group| match group.as_slice() {
+      [index] => single(&primitives[*index]),
+      group => {
+        let polygons = group
+          .iter()
+          .map(|&index| primitives[index].polygonized())
+          .collect::<Vec<_>>();
+
+        merged(&clip(&polygons, &[], Operation::Union))
+      }
+    })
+    .sum()
+}
+
+/// Groups the indices of primitives which are connected by touching or overlapping.
+fn touching_groups(primitives: &[Primitive]) -> Vec<Vec<usize>> {
+  let mut labels = (0..primitives.len()).collect::<Vec<_>>();
+
+  for (i, a) in primitives.iter().enumerate() {
+    for (j, b) in primitives.iter().enumerate().skip(i + 1) {
+      if labels[i] != labels[j] && a.intersects(b) {
+        let (keep, replace) = (labels[i], labels[j]);
+        labels
+          .iter_mut()
+          .filter(|label| **label == replace)
+          .for_each(|label| *label = keep);
+      }
+    }
+  }
+
+  let mut groups = BTreeMap::<usize, Vec<usize>>::new();
+  for (index, label) in labels.into_iter().enumerate() {
+    groups.entry(label).or_default().push(index);
+  }
+  groups.into_values().collect()
+}
+
+fn polygons_area(polygons: &[PolygonWithHoles<f64>]) -> f64 {
+  polygons
+    .iter()
+    .flat_map(|polygon| polygon.rings())
+    .map(|ring| ring.signed_area())
+    .sum::<f64>()
+    .abs()
+}
+
+fn polygons_perimeter(polygons: &[PolygonWithHoles<f64>]) -> f64 {
+  polygons
+    .iter()
+    .flat_map(|polygon| polygon.rings())
+    .flat_map(|ring| ring.edges())
+    .map(|edge| crate::distance(&edge.start, &edge.end))
+    .sum()
+}
+
+/// Returns the circumference of an ellipse, with the arithmetic-geometric mean of its radii.
+///
+/// The series converges quadratically, and is exact up to rounding for any eccentricity.
+fn ellipse_perimeter(radius: (f64, f64)) -> f64 {
+  let (mut a, mut b) = (radius.0.max(radius.1), radius.0.min(radius.1));
+  if b == 0.0 {
+    return 4.0 * a;
+  }
+
+  let squared = a * a;
+  let mut sum = (a * a - b * b) / 2.0;
+  let mut power = 1.0;
+
+  loop {
+    let c = (a - b) / 2.0;
+    (a, b) = ((a + b) / 2.0, (a * b).sqrt());
+    sum += power * c * c;
+    power *= 2.0;
+
+    if c <= f64::EPSILON * a {
+      break;
+    }
+  }
+
+  TAU * (squared - sum) / a
+}
+
+#[cfg(test)]
+mod tests {
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+
+  use super::ellipse_perimeter;
+
+  #[test_case((2.0, 2.0) => 12.566370614; "circle")]
+  #[test_case((2.0, 1.0) => 9.688448221; "ellipse")]
+  #[test_case((1.0, 2.0) => 9.688448221; "ellipse, swapped radii")]
+  #[test_case((100.0, 3.0) => 400.790944961; "flat ellipse")]
+  #[test_case((5.0, 0.0) => 20.0; "segment")]
+  fn test_ellipse_perimeter(radius: (f64, f64)) -> f64 {
+    (ellipse_perimeter(radius) * 1e9).round() / 1e9
+  }
+
+  #[test]
+  fn test_ellipse_perimeter_converges_to_segment() {
+    assert_approx_eq!(f64, ellipse_perimeter((1.0, 1e-9)), 4.0, epsilon = 1e-6);
+  }
+}
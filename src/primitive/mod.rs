@@ -0,0 +1,401 @@
+//! Shapes lowered into a few primitives in `f64`, used by the shape-to-shape queries.
+//!
+//! Every shape is either bounded by straight edges, which includes segments and single
+//! points, or is an ellipse. Collections are lowered into the primitives of their members.
+
+use alloc::vec::Vec;
+
+use nalgebra::{Matrix2, Matrix3};
+
+use crate::traits::{Distance, Within};
+use crate::*;
+
+mod boundary;
+mod clip;
+mod contains;
+mod distance;
+mod ellipse;
+mod measure;
+
+pub(crate) use boundary::{boundary_point, signed_distance};
+pub(crate) use clip::{clip, AsPolygon, Operation};
+pub(crate) use contains::contains;
+pub(crate) use distance::distance;
+pub(crate) use ellipse::closest_point;
+pub(crate) use measure::{area, perimeter};
+
+/// Tolerance for results that went through trigonometric functions or square roots.
+const EPSILON: f64 = 1e-9;
+
+// `Primitive`, `AsPrimitives` and `AsPolygon` are public in this private module, so they can
+// seal the public `Geometry` and `Polygonal` traits without being nameable outside the crate.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Primitive {
+  /// Area bounded by straight edges.
+  ///
+  /// Segments and single points are stored as polygons with two and one vertices.
+  Polygon(PolygonWithHoles<f64>),
+  /// Ellipse with non-zero radii.
+  Ellipse(RotatedEllipse<f64, f64>),
+}
+
+/// Shapes which can be lowered into primitives.
+pub trait AsPrimitives {
+  fn primitives(&self) -> Vec<Primitive>;
+}
+
+impl<U: AsPrimitives> AsPrimitives for &U {
+  fn primitives(&self) -> Vec<Primitive> {
+    U::primitives(*self)
+  }
+}
+
+impl Primitive {
+  fn polygon(points: Vec<Point2<f64>>) -> Self {
+    Self::Polygon(PolygonWithHoles::new_unchecked(
+      Polygon::new(points),
+      vec![],
+    ))
+  }
+
+  /// Creates an ellipse, falling back to a segment or a point for zero radii.
+  fn ellipse(center: Point2<f64>, radius: (f64, f64), angle: f64) -> Self {
+    if radius.0 > 0.0 && radius.1 > 0.0 {
+      return Self::Ellipse(RotatedEllipse::new(center, radius, angle));
+    }
+
+    let (sin, cos) = angle.sin_cos();
+    let axis = Vector2::new(cos, sin) * radius.0 + Vector2::new(-sin, cos) * radius.1;
+
+    Self::polygon(vec![center - axis, center + axis])
+  }
+
+  pub(crate) fn contains(&self, point: &Point2<f64>) -> bool {
+    match self {
+      Self::Polygon(polygon) => polygon.within(point),
+      Self::Ellipse(ellipse) => ellipse.within(point),
+    }
+  }
+
+  /// Returns the edges of all rings, or nothing for an ellipse.
+  pub(crate) fn edges(&self) -> Vec<Line<f64>> {
+    match self {
+      Self::Polygon(polygon) => polygon_edges(polygon),
+      Self::Ellipse(_) => Vec::new(),
+    }
+  }
+
+  fn vertices(&self) -> impl Iterator<Item = &Point2<f64>> {
+    let polygon = match self {
+      Self::Polygon(polygon) => Some(polygon),
+      Self::Ellipse(_) => None,
+    };
+
+    polygon
+      .into_iter()
+      .flat_map(|polygon| polygon.rings().flat_map(|ring| ring.points.iter()))
+  }
+
+  /// Checks if the primitives share at least one point, touching counts as intersecting.
+  pub(crate) fn intersects(&self, other: &Self) -> bool {
+    match (self, other) {
+      (Self::Polygon(_), Self::Polygon(_)) => {
+        let edges = other.edges();
+
+        self
+          .edges()
+          .iter()
+          .any(|a| edges.iter().any(|b| segments_intersect(a, b)))
+          || other.vertices().any(|vertex| self.contains(vertex))
+          || self.vertices().any(|vertex| other.contains(vertex))
+      }
+      (Self::Polygon(polygon), Self::Ellipse(ellipse))
+      | (Self::Ellipse(ellipse), Self::Polygon(polygon)) => {
+        polygon_edges(polygon)
+          .iter()
+          .any(|edge| segment_intersects_ellipse(edge, ellipse))
+          || polygon.within(&ellipse.center)
+      }
+      (Self::Ellipse(a), Self::Ellipse(b)) => ellipses_intersect(a, b),
+    }
+  }
+}
+
+/// Checks if any primitive of the first shape intersects any primitive of the second one.
+pub(crate) fn intersects<A: AsPrimitives, B: AsPrimitives>(a: &A, b: &B) -> bool {
+  let others = b.primitives();
+
+  a.primitives()
+    .iter()
+    .any(|primitive| others.iter().any(|other| primitive.intersects(other)))
+}
+
+#[inline]
+fn polygon_edges(polygon: &PolygonWithHoles<f64>) -> Vec<Line<f64>> {
+  polygon.rings().flat_map(|ring| ring.edges()).collect()
+}
+
+#[inline]
+fn orientation(a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>) -> f64 {
+  (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Checks if a point collinear with the segment lies within its bounds.
+#[inline]
+fn within_bounds(segment: &Line<f64>, point: &Point2<f64>) -> bool {
+  segment.start.x.min(segment.end.x) <= point.x
+    && point.x <= segment.start.x.max(segment.end.x)
+    && segment.start.y.min(segment.end.y) <= point.y
+    && point.y <= segment.start.y.max(segment.end.y)
+}
+
+/// Checks if two closed segments share at least one point.
+fn segments_intersect(a: &Line<f64>, b: &Line<f64>) -> bool {
+  let d1 = orientation(&b.start, &b.end, &a.start);
+  let d2 = orientation(&b.start, &b.end, &a.end);
+  let d3 = orientation(&a.start, &a.end, &b.start);
+  let d4 = orientation(&a.start, &a.end, &b.end);
+
+  if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+    return true;
+  }
+
+  (d1 == 0.0 && within_bounds(b, &a.start))
+    || (d2 == 0.0 && within_bounds(b, &a.end))
+    || (d3 == 0.0 && within_bounds(a, &b.start))
+    || (d4 == 0.0 && within_bounds(a, &b.end))
+}
+
+/// Checks if the segment touches the filled ellipse.
+///
+/// The check is done in the coordinate system where the ellipse is the unit circle.
+fn segment_intersects_ellipse(segment: &Line<f64>, ellipse: &RotatedEllipse<f64, f64>) -> bool {
+  let scale =
+    |point: Point2<f64>| Point2::new(point.x / ellipse.radius.0, point.y / ellipse.radius.1);
+  let start = scale(ellipse.local_point(&segment.start));
+  let end = scale(ellipse.local_point(&segment.end));
+
+  Line::new_unchecked(start, end).distance(&Point2::origin()) <= 1.0 + EPSILON
+}
+
+/// Returns the distance from the point to the filled ellipse.
+fn point_ellipse_distance(point: &Point2<f64>, ellipse: &RotatedEllipse<f64, f64>) -> f64 {
+  if ellipse.within(point) {
+    return 0.0;
+  }
+
+  crate::distance(point, &closest_point(ellipse, point))
+}
+
+/// Returns the matrix which maps the ellipse onto the unit circle.
+fn unit_circle_matrix(ellipse: &RotatedEllipse<f64, f64>) -> Matrix3<f64> {
+  let (sin, cos) = ellipse.angle.sin_cos();
+  let linear = Matrix2::new(1.0 / ellipse.radius.0, 0.0, 0.0, 1.0 / ellipse.radius.1)
+    * Matrix2::new(cos, sin, -sin, cos);
+  let translation = -(linear * ellipse.center.coords);
+
+  let mut matrix = linear.to_homogeneous();
+  matrix[(0, 2)] = translation.x;
+  matrix[(1, 2)] = translation.y;
+  matrix
+}
+
+/// Checks if two filled ellipses share at least one point.
+///
+/// The second ellipse is mapped into the coordinate system where the first one is
+/// the unit circle, so they intersect if the mapped ellipse is close enough to the origin.
+fn ellipses_intersect(a: &RotatedEllipse<f64, f64>, b: &RotatedEllipse<f64, f64>) -> bool {
+  let mapped = transform_ellipse(&unit_circle_matrix(a), &b.center, &b.radius, b.angle);
+
+  match Primitive::ellipse(mapped.center, mapped.radius, mapped.angle) {
+    Primitive::Ellipse(mapped) => {
+      point_ellipse_distance(&Point2::origin(), &mapped) <= 1.0 + EPSILON
+    }
+    // Radii can collapse to zero through rounding, if one ellipse is a lot larger than the other.
+    segment => segment
+      .edges()
+      .iter()
+      .any(|edge| edge.distance(&Point2::origin()) <= 1.0 + EPSILON),
+  }
+}
+
+/// Maps the ellipse through the linear part of the matrix.
+///
+/// The image of an ellipse under an affine transformation is another ellipse,
+/// its radii and orientation are given by the singular value decomposition of the
+/// combined linear map.
+pub(crate) fn transform_ellipse<T, R>(
+  matrix: &Matrix3<f64>,
+  center: &Point2<T>,
+  radius: &(R, R),
+  angle: f64,
+) -> RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  let (sin, cos) = angle.sin_cos();
+  let linear = matrix.fixed_view::<2, 2>(0, 0)
+    * Matrix2::new(cos, -sin, sin, cos)
+    * Matrix2::new(radius.0.into(), 0.0, 0.0, radius.1.into());
+
+  let svd = linear.svd(true, false);
+  let u = svd.u.expect("left singular vectors were requested");
+
+  RotatedEllipse::new(
+    matrix
+      .transform_point(&center.map(Into::<f64>::into))
+      .map(T::round_from_f64),
+    (
+      R::round_from_f64(svd.singular_values[0]),
+      R::round_from_f64(svd.singular_values[1]),
+    ),
+    u[(1, 0)].atan2(u[(0, 0)]),
+  )
+}
+
+#[inline]
+fn point<T: FloatMath>(point: &Point2<T>) -> Point2<f64> {
+  point.map(Into::<f64>::into)
+}
+
+impl<T: FloatMath> AsPrimitives for Line<T> {
+  fn primitives(&self) -> Vec<Primitive> {
+    vec![Primitive::polygon(vec![
+      point(&self.start),
+      point(&self.end),
+    ])]
+  }
+}
+
+impl<T: FloatMath> AsPrimitives for Rectangle<T> {
+  fn primitives(&self) -> Vec<Primitive> {
+    let (min, max) = (point(self.min()), point(self.max()));
+
+    vec![Primitive::polygon(vec![
+      min,
+      Point2::new(max.x, min.y),
+      max,
+      Point2::new(min.x, max.y),
+    ])]
+  }
+}
+
+impl<T: FloatMath> AsPrimitives for RotatedRectangle<T> {
+  fn primitives(&self) -> Vec<Primitive> {
+    vec![Primitive::polygon(self.corners().to_vec())]
+  }
+}
+
+impl<T: FloatMath, R: FloatMath> AsPrimitives for Circle<T, R> {
+  fn primitives(&self) -> Vec<Primitive> {
+    let radius: f64 = self.radius.into();
+    vec![Primitive::ellipse(
+      point(&self.center),
+      (radius, radius),
+      0.0,
+    )]
+  }
+}
+
+impl<T: FloatMath, R: FloatMath> AsPrimitives for Ellipse<T, R> {
+  fn primitives(&self) -> Vec<Primitive> {
+    let radius = (self.radius.0.into(), self.radius.1.into());
+    vec![Primitive::ellipse(point(&self.center), radius, 0.0)]
+  }
+}
+
+impl<T: FloatMath, R: FloatMath> AsPrimitives for RotatedEllipse<T, R> {
+  fn primitives(&self) -> Vec<Primitive> {
+    let radius = (self.radius.0.into(), self.radius.1.into());
+    vec![Primitive::ellipse(point(&self.center), radius, self.angle)]
+  }
+}
+
+impl<T: FloatMath> AsPrimitives for Triangle<T> {
+  fn primitives(&self) -> Vec<Primitive> {
+    vec![Primitive::polygon(vec![
+      point(&self.0),
+      point(&self.1),
+      point(&self.2),
+    ])]
+  }
+}
+
+#[inline]
+fn ring<T: FloatMath>(polygon: &Polygon<T>) -> Polygon<f64> {
+  Polygon {
+    points: polygon.points.iter().map(point).collect(),
+    fill_rule: polygon.fill_rule,
+  }
+}
+
+impl<T: FloatMath> AsPrimitives for Polygon<T> {
+  fn primitives(&self) -> Vec<Primitive> {
+    vec![Primitive::Polygon(PolygonWithHoles::new_unchecked(
+      ring(self),
+      vec![],
+    ))]
+  }
+}
+
+impl<T: FloatMath> AsPrimitives for PolygonWithHoles<T> {
+  fn primitives(&self) -> Vec<Primitive> {
+    vec![Primitive::Polygon(PolygonWithHoles::new_unchecked(
+      ring(&self.exterior),
+      self.holes.iter().map(ring).collect(),
+    ))]
+  }
+}
+
+impl<T: FloatMath, R: FloatMath> AsPrimitives for Shape<T, R> {
+  fn primitives(&self) -> Vec<Primitive> {
+    match self {
+      Self::Rectangle(rectangle) => rectangle.primitives(),
+      Self::RotatedRectangle(rectangle) => rectangle.primitives(),
+      Self::Circle(circle) => circle.primitives(),
+      Self::Ellipse(ellipse) => ellipse.primitives(),
+      Self::RotatedEllipse(ellipse) => ellipse.primitives(),
+      Self::Triangle(triangle) => triangle.primitives(),
+      Self::Polygon(polygon) => polygon.primitives(),
+      Self::PolygonWithHoles(polygon) => polygon.primitives(),
+      Self::Collection(collection) => collection.primitives(),
+    }
+  }
+}
+
+impl<T: FloatMath, R: FloatMath> AsPrimitives for ShapeCollection<T, R> {
+  fn primitives(&self) -> Vec<Primitive> {
+    self
+      .shapes
+      .iter()
+      .flat_map(|shape| shape.primitives())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_case::test_case;
+
+  use super::{segments_intersect, Primitive};
+  use crate::{Line, Point2};
+
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)), Line::new_unchecked(Point2::new(0.0, 2.0), Point2::new(2.0, 0.0)) => true; "crossing")]
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)), Line::new_unchecked(Point2::new(2.0, 2.0), Point2::new(3.0, 0.0)) => true; "shared end")]
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 0.0)), Line::new_unchecked(Point2::new(1.0, 0.0), Point2::new(3.0, 0.0)) => true; "collinear overlap")]
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)), Line::new_unchecked(Point2::new(2.0, 0.0), Point2::new(3.0, 0.0)) => false; "collinear apart")]
+  #[test_case(Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)), Line::new_unchecked(Point2::new(1.0, 0.0), Point2::new(3.0, 2.0)) => false; "parallel")]
+  #[test_case(Line::new_unchecked(Point2::new(1.0, 1.0), Point2::new(1.0, 1.0)), Line::new_unchecked(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)) => true; "point on segment")]
+  fn test_segments_intersect(a: Line<f64>, b: Line<f64>) -> bool {
+    segments_intersect(&a, &b) && segments_intersect(&b, &a)
+  }
+
+  #[test]
+  fn test_degenerate_ellipse_is_segment() {
+    assert_eq!(
+      Primitive::ellipse(Point2::new(1.0, 1.0), (2.0, 0.0), 0.0),
+      Primitive::polygon(vec![Point2::new(-1.0, 1.0), Point2::new(3.0, 1.0)])
+    );
+  }
+}
@@ -1,13 +1,17 @@
 #![allow(dead_code)]
+use alloc::vec::Vec;
 #[cfg_attr(test, mutants::skip)]
 use array_init::{array_init, try_array_init};
+use core::f64::consts::PI;
+use core::fmt::Debug;
+use core::ops::Range;
 use nalgebra::{Point, Scalar};
-use num::Unsigned;
-use std::fmt::Debug;
 
-use crate::{Circle, Ellipse, Line, Rectangle, Shape, Triangle};
+use crate::{
+  Circle, Ellipse, Line, Polygon, Rectangle, RotatedEllipse, RotatedRectangle, Shape, Triangle,
+};
 use proptest::arbitrary::{Arbitrary, StrategyFor};
-use proptest::collection::vec;
+use proptest::collection::{vec, VecStrategy};
 use proptest::prelude::any_with;
 use proptest::strategy::{Map, NewTree, Strategy, ValueTree};
 use proptest::test_runner::TestRunner;
@@ -75,7 +79,7 @@ impl<T, const N: usize> Debug for PointView<T, N>
 where
   T: Scalar + Debug + Clone + PartialEq,
 {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
     f.debug_tuple("PointView").field(&self.0).finish()
   }
 }
@@ -143,7 +147,7 @@ where
   T: Arbitrary + Scalar + Clone,
   <R as Arbitrary>::Strategy: Clone,
   <R as Arbitrary>::Parameters: Clone,
-  R: Arbitrary + Scalar + Unsigned + Clone,
+  R: Arbitrary + Scalar + Clone,
 {
   type Parameters = <(T, R) as Arbitrary>::Parameters;
   fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
@@ -167,7 +171,7 @@ where
   T: Arbitrary + Scalar + Clone,
   <R as Arbitrary>::Strategy: Clone,
   <R as Arbitrary>::Parameters: Clone,
-  R: Arbitrary + Scalar + Unsigned + Clone,
+  R: Arbitrary + Scalar + Clone,
 {
   type Parameters = <(T, R) as Arbitrary>::Parameters;
   fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
@@ -180,6 +184,41 @@ where
   type Strategy = Mapped<(PointView<T, 2>, (R, R)), Ellipse<T, R>>;
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Arbitrary RotatedEllipse
+
+impl<T, R> Arbitrary for RotatedEllipse<T, R>
+where
+  <T as Arbitrary>::Strategy: Clone,
+  <T as Arbitrary>::Parameters: Clone,
+  T: Arbitrary + Scalar + Clone,
+  <R as Arbitrary>::Strategy: Clone,
+  <R as Arbitrary>::Parameters: Clone,
+  R: Arbitrary + Scalar + Clone,
+{
+  type Parameters = <(T, R) as Arbitrary>::Parameters;
+  fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+    (
+      any_with::<PointView<T, 2>>(params.0),
+      any_with::<(R, R)>((params.1.clone(), params.1)),
+      -PI..PI,
+    )
+      .prop_map(|(center, radius, angle)| RotatedEllipse {
+        center: center.into(),
+        radius,
+        angle,
+      })
+  }
+  type Strategy = Map<
+    (
+      StrategyFor<PointView<T, 2>>,
+      StrategyFor<(R, R)>,
+      Range<f64>,
+    ),
+    fn(_: (PointView<T, 2>, (R, R), f64)) -> RotatedEllipse<T, R>,
+  >;
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Arbitrary Line
 
@@ -204,7 +243,7 @@ impl<T> Arbitrary for Rectangle<T>
 where
   <T as Arbitrary>::Strategy: Clone,
   <T as Arbitrary>::Parameters: Clone,
-  T: Arbitrary + Scalar + Copy + Ord,
+  T: Arbitrary + Scalar + Copy + PartialOrd,
 {
   type Parameters = <(PointView<T, 2>, PointView<T, 2>) as Arbitrary>::Parameters;
   fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
@@ -214,6 +253,34 @@ where
   type Strategy = Mapped<(PointView<T, 2>, PointView<T, 2>), Rectangle<T>>;
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Arbitrary RotatedRectangle
+
+impl<T> Arbitrary for RotatedRectangle<T>
+where
+  <T as Arbitrary>::Strategy: Clone,
+  <T as Arbitrary>::Parameters: Clone,
+  T: Arbitrary + Scalar + Copy + PartialOrd,
+{
+  type Parameters = <(PointView<T, 2>, (T, T)) as Arbitrary>::Parameters;
+  fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+    (
+      any_with::<PointView<T, 2>>(params.0),
+      any_with::<(T, T)>(params.1),
+      -PI..PI,
+    )
+      .prop_map(|(center, half_size, angle)| RotatedRectangle::new(center.into(), half_size, angle))
+  }
+  type Strategy = Map<
+    (
+      StrategyFor<PointView<T, 2>>,
+      StrategyFor<(T, T)>,
+      Range<f64>,
+    ),
+    fn(_: (PointView<T, 2>, (T, T), f64)) -> RotatedRectangle<T>,
+  >;
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Arbitrary Triangle
 
@@ -221,7 +288,7 @@ impl<T> Arbitrary for Triangle<T>
 where
   <T as Arbitrary>::Strategy: Clone,
   <T as Arbitrary>::Parameters: Clone,
-  T: Arbitrary + Scalar + Copy + Ord,
+  T: Arbitrary + Scalar + Copy + PartialOrd,
 {
   type Parameters = <(PointView<T, 2>, PointView<T, 2>, PointView<T, 2>) as Arbitrary>::Parameters;
   fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
@@ -231,6 +298,24 @@ where
   type Strategy = Mapped<(PointView<T, 2>, PointView<T, 2>, PointView<T, 2>), Triangle<T>>;
 }
 
+///////////////////////////////////////////////////////////////////////////////
+// Arbitrary Polygon
+
+impl<T> Arbitrary for Polygon<T>
+where
+  <T as Arbitrary>::Strategy: Clone,
+  <T as Arbitrary>::Parameters: Clone,
+  T: Arbitrary + Scalar + Copy + PartialOrd,
+{
+  type Parameters = <PointView<T, 2> as Arbitrary>::Parameters;
+  fn arbitrary_with(params: Self::Parameters) -> Self::Strategy {
+    vec(any_with::<PointView<T, 2>>(params), 3..16)
+      .prop_map(|points| Polygon::new(points.into_iter().map(Into::into).collect()))
+  }
+  type Strategy =
+    Map<VecStrategy<StrategyFor<PointView<T, 2>>>, fn(_: Vec<PointView<T, 2>>) -> Polygon<T>>;
+}
+
 ///////////////////////////////////////////////////////////////////////////////
 // Arbitrary Shape
 
@@ -239,32 +324,38 @@ pub enum ShapeView<T, R>
 where
   <T as Arbitrary>::Strategy: Clone,
   <T as Arbitrary>::Parameters: Clone,
-  T: Arbitrary + Scalar + Copy + Ord,
+  T: Arbitrary + Scalar + Copy + PartialOrd,
   <R as Arbitrary>::Strategy: Clone,
   <R as Arbitrary>::Parameters: Clone,
-  R: Arbitrary + Scalar + Unsigned + Copy + Ord,
+  R: Arbitrary + Scalar + Copy + PartialOrd,
 {
   Rectangle(Rectangle<T>),
+  RotatedRectangle(RotatedRectangle<T>),
   Circle(Circle<T, R>),
   Ellipse(Ellipse<T, R>),
+  RotatedEllipse(RotatedEllipse<T, R>),
   Triangle(Triangle<T>),
+  Polygon(Polygon<T>),
 }
 
 impl<T, R> From<ShapeView<T, R>> for Shape<T, R>
 where
   <T as Arbitrary>::Strategy: Clone,
   <T as Arbitrary>::Parameters: Clone,
-  T: Arbitrary + Scalar + Copy + Ord,
+  T: Arbitrary + Scalar + Copy + PartialOrd,
   <R as Arbitrary>::Strategy: Clone,
   <R as Arbitrary>::Parameters: Clone,
-  R: Arbitrary + Scalar + Unsigned + Copy + Ord,
+  R: Arbitrary + Scalar + Copy + PartialOrd,
 {
   fn from(val: ShapeView<T, R>) -> Self {
     match val {
       ShapeView::Rectangle(rectangle) => Shape::Rectangle(rectangle),
       ShapeView::Circle(circle) => Shape::Circle(circle),
       ShapeView::Ellipse(ellipse) => Shape::Ellipse(ellipse),
+      ShapeView::RotatedRectangle(rectangle) => Shape::RotatedRectangle(rectangle),
+      ShapeView::RotatedEllipse(ellipse) => Shape::RotatedEllipse(ellipse),
       ShapeView::Triangle(triangle) => Shape::Triangle(triangle),
+      ShapeView::Polygon(polygon) => Shape::Polygon(polygon),
     }
   }
 }
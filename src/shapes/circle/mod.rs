@@ -1,7 +1,5 @@
 use crate::*;
 
-use num::Unsigned;
-
 use derivative::Derivative;
 use getset::Getters;
 
@@ -11,7 +9,7 @@ use getset::Getters;
 )]
 #[derive(Getters, Derivative)]
 #[derivative(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct Circle<T: Scalar, R: Scalar + Unsigned> {
+pub struct Circle<T: Scalar, R: Scalar> {
   #[getset(get = "pub")]
   pub(crate) center: Point2<T>,
   #[getset(get = "pub")]
@@ -21,10 +19,10 @@ pub struct Circle<T: Scalar, R: Scalar + Unsigned> {
 impl<T, R> Circle<T, R>
 where
   T: Scalar,
-  R: Scalar + Unsigned,
+  R: Scalar,
 {
   #[inline]
-  pub fn new(center: Point2<T>, radius: R) -> Self {
+  pub const fn new(center: Point2<T>, radius: R) -> Self {
     Self { center, radius }
   }
 }
@@ -0,0 +1,134 @@
+use alloc::boxed::Box;
+
+use derivative::Derivative;
+use nalgebra::Scalar;
+
+use super::*;
+
+/// Shape combined from other shapes with boolean set operations.
+///
+/// The expression is evaluated lazily, per query point. Like all other shapes it is closed:
+/// subtracting a shape removes its inside, but keeps its outline where it touches the rest.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Csg, Point2, Rectangle, traits::Within};
+///
+/// let effect = Csg::<u8, u8>::from(Circle::new(Point2::new(10, 10), 8))
+///   .difference(Rectangle::new(Point2::new(10, 0), Point2::new(20, 20)));
+///
+/// assert!(effect.within(&Point2::new(5, 10)));
+/// assert!(effect.within(&Point2::new(10, 10)));
+/// assert!(!effect.within(&Point2::new(15, 10)));
+/// ```
+#[cfg_attr(
+  feature = "serde-serialize",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(bound(
+    deserialize = "T: crate::FloatMath + serde::Deserialize<'de>, U: serde::Deserialize<'de>"
+  ))
+)]
+#[derive(Derivative)]
+#[derivative(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum Csg<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  Shape(Shape<T, U>),
+  /// Points inside either operand.
+  Union(Box<Csg<T, U>>, Box<Csg<T, U>>),
+  /// Points inside both operands.
+  Intersection(Box<Csg<T, U>>, Box<Csg<T, U>>),
+  /// Points inside the first operand, but not inside the second one.
+  Difference(Box<Csg<T, U>>, Box<Csg<T, U>>),
+  /// Points inside exactly one of the operands.
+  SymmetricDifference(Box<Csg<T, U>>, Box<Csg<T, U>>),
+}
+
+impl<T, U> Csg<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  #[inline]
+  pub fn union(self, other: impl Into<Self>) -> Self {
+    Self::Union(Box::new(self), Box::new(other.into()))
+  }
+
+  #[inline]
+  pub fn intersection(self, other: impl Into<Self>) -> Self {
+    Self::Intersection(Box::new(self), Box::new(other.into()))
+  }
+
+  #[inline]
+  pub fn difference(self, other: impl Into<Self>) -> Self {
+    Self::Difference(Box::new(self), Box::new(other.into()))
+  }
+
+  #[inline]
+  pub fn symmetric_difference(self, other: impl Into<Self>) -> Self {
+    Self::SymmetricDifference(Box::new(self), Box::new(other.into()))
+  }
+}
+
+impl<T, U> From<Shape<T, U>> for Csg<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn from(shape: Shape<T, U>) -> Self {
+    Self::Shape(shape)
+  }
+}
+
+macro_rules! impl_from_shape {
+  ($($shape: ty),* $(,)?) => {$(
+    impl<T, U> From<$shape> for Csg<T, U>
+    where
+      T: Scalar,
+      U: Scalar,
+    {
+      fn from(shape: $shape) -> Self {
+        Self::Shape(shape.into())
+      }
+    }
+  )*};
+}
+
+impl_from_shape!(
+  Rectangle<T>,
+  RotatedRectangle<T>,
+  Circle<T, U>,
+  Ellipse<T, U>,
+  RotatedEllipse<T, U>,
+  Triangle<T>,
+  Polygon<T>,
+  PolygonWithHoles<T>,
+  ShapeCollection<T, U>,
+);
+
+#[cfg(test)]
+mod tests {
+  use alloc::boxed::Box;
+
+  use crate::{Circle, Csg, Point2, Rectangle, Shape};
+
+  #[test]
+  fn test_builder() {
+    let circle = Circle::<u8, u8>::new(Point2::new(10, 10), 5);
+    let rectangle = Rectangle::new(Point2::new(0, 0), Point2::new(10, 10));
+
+    assert_eq!(
+      Csg::from(circle).difference(rectangle),
+      Csg::Difference(
+        Box::new(Csg::Shape(Shape::Circle(circle))),
+        Box::new(Csg::Shape(Shape::Rectangle(rectangle)))
+      )
+    );
+    assert!(matches!(
+      Csg::from(circle).union(circle).intersection(rectangle),
+      Csg::Intersection(left, _) if matches!(*left, Csg::Union(_, _))
+    ));
+  }
+}
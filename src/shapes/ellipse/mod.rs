@@ -22,30 +22,37 @@ where
   R: Scalar,
 {
   #[inline]
-  pub fn new(center: Point2<T>, radius: (R, R)) -> Self {
+  pub const fn new(center: Point2<T>, radius: (R, R)) -> Self {
     Self { center, radius }
   }
 
   #[inline]
-  pub fn width(&self) -> &R {
+  pub const fn width(&self) -> &R {
     &self.radius.0
   }
 
   #[inline]
-  pub fn height(&self) -> &R {
+  pub const fn height(&self) -> &R {
     &self.radius.1
   }
 }
 
-impl Ellipse<u8, u8> {
-  pub fn point_intersection(&self, point: &Point2<u8>, max_iterations: usize) -> Point2<f64> {
-    let a = self.radius.0 as f64;
-    let b = self.radius.1 as f64;
+impl<T, R> Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  pub fn point_intersection(&self, point: &Point2<T>, max_iterations: usize) -> Point2<f64> {
+    let a: f64 = self.radius.0.into();
+    let b: f64 = self.radius.1.into();
 
-    let epsilon = 0.1 / a.max(b);
+    // The tolerance is on the angle, so it must not depend on the size of the ellipse.
+    let epsilon = 1e-12;
 
-    let dx = point.x as f64 - self.center.x as f64;
-    let dy = point.y as f64 - self.center.y as f64;
+    let center = self.center.map(Into::<f64>::into);
+
+    let dx = Into::<f64>::into(point.x) - center.x;
+    let dy = Into::<f64>::into(point.y) - center.y;
     let p1 = Point2::new(dx, dy);
 
     // Intersection of straight line from origin to p with ellipse as the first approximation:
@@ -69,8 +76,8 @@ impl Ellipse<u8, u8> {
       }
     }
 
-    let x = a * phi.cos() + self.center.x as f64;
-    let y = b * phi.sin() + self.center.y as f64;
+    let x = a * phi.cos() + center.x;
+    let y = b * phi.sin() + center.y;
 
     Point2::new(x, y)
   }
@@ -78,19 +85,21 @@ impl Ellipse<u8, u8> {
 
 #[cfg(test)]
 mod tests {
+  use std::println;
+
   use super::*;
   use float_cmp::assert_approx_eq;
   use nalgebra::Point2;
   use test_case::test_case;
 
   #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(130, 120), Point2::new(130.0, 120.0); "point_at_0_deg")]
-  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(127, 127), Point2::new(125.91230017765763, 124.03251492896399); "point_at_45_deg")]
+  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(127, 127), Point2::new(125.91230020279505, 124.03251491975013); "point_at_45_deg")]
   #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(120, 125), Point2::new(120.0, 125.0); "point_at_90_deg")]
-  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(113, 127), Point2::new(114.08769982234237, 124.03251492896399); "point_at_135_deg")]
+  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(113, 127), Point2::new(114.08769979720495, 124.03251491975013); "point_at_135_deg")]
   #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(110, 120), Point2::new(110.0, 120.0); "point_at_180_deg")]
-  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(113, 113), Point2::new(114.08769982234237, 115.96748507103601); "point_at_225_deg")]
+  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(113, 113), Point2::new(114.08769979720495, 115.96748508024987); "point_at_225_deg")]
   #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(120, 115), Point2::new(120.0, 115.0); "point_at_270_deg")]
-  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(127, 113), Point2::new(125.91230017765763, 115.96748507103601); "point_at_315_deg")]
+  #[test_case(Ellipse::new(Point2::new(120, 120), (10, 5)), Point2::new(127, 113), Point2::new(125.91230020279505, 115.96748508024987); "point_at_315_deg")]
   fn test_ellipse_point_intersection_u8(
     ellipse: Ellipse<u8, u8>,
     point: Point2<u8>,
@@ -103,4 +112,20 @@ mod tests {
     assert_approx_eq!(f64, intersection.x, expected.x, ulps = 2);
     assert_approx_eq!(f64, intersection.y, expected.y, ulps = 2);
   }
+
+  #[test_case(Ellipse::new(Point2::new(0.5, 0.5), (0.2, 0.1)), Point2::new(0.9, 0.5), Point2::new(0.7, 0.5); "point_at_0_deg")]
+  #[test_case(Ellipse::new(Point2::new(0.5, 0.5), (0.2, 0.1)), Point2::new(0.5, 0.9), Point2::new(0.5, 0.6); "point_at_90_deg")]
+  #[test_case(Ellipse::new(Point2::new(0.5, 0.5), (0.2, 0.1)), Point2::new(0.1, 0.5), Point2::new(0.3, 0.5); "point_at_180_deg")]
+  #[test_case(Ellipse::new(Point2::new(0.5, 0.5), (0.2, 0.1)), Point2::new(0.9, 0.8), Point2::new(0.6746769041000609, 0.5487031291962287); "point_off_axis")]
+  #[test_case(Ellipse::new(Point2::new(0.5, 0.5), (0.2, 0.1)), Point2::new(0.2, 0.3), Point2::new(0.3274588745144016, 0.4494293563018944); "point_off_axis_opposite")]
+  fn test_ellipse_point_intersection_f64(
+    ellipse: Ellipse<f64, f64>,
+    point: Point2<f64>,
+    expected: Point2<f64>,
+  ) {
+    let intersection = ellipse.point_intersection(&point, 1000);
+
+    assert_approx_eq!(f64, intersection.x, expected.x, epsilon = 1e-9);
+    assert_approx_eq!(f64, intersection.y, expected.y, epsilon = 1e-9);
+  }
 }
@@ -0,0 +1,182 @@
+use core::fmt;
+
+use derivative::Derivative;
+use nalgebra::Scalar;
+
+use super::{Shape, ShapeCollection};
+
+/// Error returned when adding a shape to a full [`FixedShapeCollection`], holds the shape.
+#[derive(Derivative)]
+#[derivative(Debug, Clone, PartialEq, Eq)]
+pub struct CapacityError<T, U>(pub Shape<T, U>)
+where
+  T: Scalar,
+  U: Scalar;
+
+impl<T, U> fmt::Display for CapacityError<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "collection is full")
+  }
+}
+
+impl<T, U> core::error::Error for CapacityError<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+}
+
+/// Collection of up to `N` shapes, stored inline instead of on the heap.
+///
+/// Behaves like a [`ShapeCollection`] and is serialized the same way, for targets which
+/// can't allocate the collection itself. Polygons still keep their points in a `Vec`.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, FixedShapeCollection, Point2, Rectangle, traits::Within};
+///
+/// let mut zones = FixedShapeCollection::<u8, u8, 2>::new();
+/// zones.push(Rectangle::new(Point2::new(0, 0), Point2::new(10, 10)).into()).unwrap();
+/// zones.push(Circle::new(Point2::new(20, 20), 5).into()).unwrap();
+///
+/// assert!(zones.within(&Point2::new(22, 22)));
+/// assert!(zones.push(Circle::new(Point2::new(40, 40), 5).into()).is_err());
+/// ```
+#[cfg_attr(
+  feature = "serde-serialize",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(
+    transparent,
+    bound(
+      deserialize = "T: crate::FloatMath + serde::Deserialize<'de>, U: serde::Deserialize<'de>"
+    )
+  )
+)]
+#[derive(Derivative)]
+#[derivative(Debug, Default, Hash, Clone, PartialEq, Eq)]
+pub struct FixedShapeCollection<T, U, const N: usize>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  shapes: heapless::Vec<Shape<T, U>, N>,
+}
+
+impl<T, U, const N: usize> FixedShapeCollection<T, U, N>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  pub const fn new() -> Self {
+    Self {
+      shapes: heapless::Vec::new(),
+    }
+  }
+
+  /// Adds a shape at the end, or gives it back if the collection is full.
+  pub fn push(&mut self, shape: Shape<T, U>) -> Result<(), CapacityError<T, U>> {
+    self.shapes.push(shape).map_err(CapacityError)
+  }
+
+  /// Removes the last shape.
+  pub fn pop(&mut self) -> Option<Shape<T, U>> {
+    self.shapes.pop()
+  }
+
+  pub fn clear(&mut self) {
+    self.shapes.clear();
+  }
+
+  pub fn shapes(&self) -> &[Shape<T, U>] {
+    &self.shapes
+  }
+
+  pub fn is_full(&self) -> bool {
+    self.shapes.is_full()
+  }
+
+  pub const fn capacity(&self) -> usize {
+    N
+  }
+}
+
+impl<T, U, const N: usize> TryFrom<ShapeCollection<T, U>> for FixedShapeCollection<T, U, N>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  type Error = CapacityError<T, U>;
+
+  /// Moves the shapes over, fails with the first shape which doesn't fit.
+  fn try_from(collection: ShapeCollection<T, U>) -> Result<Self, Self::Error> {
+    let mut fixed = Self::new();
+    for shape in collection.shapes {
+      fixed.push(shape)?;
+    }
+
+    Ok(fixed)
+  }
+}
+
+impl<T, U, const N: usize> From<FixedShapeCollection<T, U, N>> for ShapeCollection<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn from(collection: FixedShapeCollection<T, U, N>) -> Self {
+    Self::new(collection.shapes.into_iter().collect())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use super::CapacityError;
+  use crate::{Circle, FixedShapeCollection, Point2, Rectangle, Shape, ShapeCollection};
+
+  #[test]
+  fn fixed_shape_collection_push_until_full() {
+    let mut collection = FixedShapeCollection::<u8, u8, 2>::new();
+    let circle: Shape<u8, u8> = Circle::new(Point2::new(10, 10), 5).into();
+    let rectangle: Shape<u8, u8> = Rectangle::new(Point2::new(0, 0), Point2::new(5, 5)).into();
+
+    assert_eq!(collection.push(circle.clone()), Ok(()));
+    assert!(!collection.is_full());
+    assert_eq!(collection.push(rectangle.clone()), Ok(()));
+    assert!(collection.is_full());
+    assert_eq!(
+      collection.push(circle.clone()),
+      Err(CapacityError(circle.clone()))
+    );
+    assert_eq!(collection.shapes(), &[circle.clone(), rectangle.clone()]);
+    assert_eq!(collection.capacity(), 2);
+
+    assert_eq!(collection.pop(), Some(rectangle));
+    assert_eq!(collection.shapes(), &[circle]);
+    collection.clear();
+    assert!(collection.shapes().is_empty());
+  }
+
+  #[test]
+  fn fixed_shape_collection_try_from() {
+    let shapes: Vec<Shape<u8, u8>> = vec![
+      Circle::new(Point2::new(10, 10), 5).into(),
+      Rectangle::new(Point2::new(0, 0), Point2::new(5, 5)).into(),
+    ];
+    let collection = ShapeCollection::new(shapes.clone());
+
+    let fixed = FixedShapeCollection::<u8, u8, 2>::try_from(collection.clone()).unwrap();
+    assert_eq!(fixed.shapes(), shapes.as_slice());
+    assert_eq!(ShapeCollection::from(fixed), collection);
+
+    assert_eq!(
+      FixedShapeCollection::<u8, u8, 1>::try_from(collection),
+      Err(CapacityError(shapes[1].clone()))
+    );
+  }
+}
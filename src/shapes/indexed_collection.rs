@@ -0,0 +1,421 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use nalgebra::Scalar;
+use ordered_float::OrderedFloat;
+
+use crate::traits::{BoundingBox, Distance, Intersects, Within};
+use crate::*;
+
+/// Maximum number of shapes in a leaf of the hierarchy.
+const LEAF_SIZE: usize = 4;
+
+/// Collection of shapes with a bounding volume hierarchy for fast lookups.
+///
+/// The hierarchy is built once from the bounding boxes of the shapes, queries only check
+/// the shapes whose boxes are close enough to matter. All queries give the same results as
+/// checking every member of a [`ShapeCollection`] in turn.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, IndexedCollection, Point2, Rectangle, traits::Within};
+///
+/// let zones = IndexedCollection::<u8, u8>::new(vec![
+///   Rectangle::new(Point2::new(0, 0), Point2::new(10, 10)).into(),
+///   Circle::new(Point2::new(50, 50), 5).into(),
+///   Circle::new(Point2::new(55, 50), 5).into(),
+/// ]);
+///
+/// assert!(zones.within(&Point2::new(5, 5)));
+/// assert_eq!(zones.point_query(&Point2::new(52, 50)), vec![1, 2]);
+/// assert_eq!(
+///   zones.range_query(&Rectangle::new(Point2::new(0, 0), Point2::new(46, 50))),
+///   vec![0, 1]
+/// );
+/// assert_eq!(zones.nearest(&Point2::new(20, 5)), Some((0, 10.0)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedCollection<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  shapes: Vec<Shape<T, U>>,
+  /// Nodes of the hierarchy, the root comes first.
+  nodes: Vec<Node>,
+  /// Shape indices, every leaf owns a range of them.
+  order: Vec<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+  min: Point2<f64>,
+  max: Point2<f64>,
+  children: Children,
+}
+
+#[derive(Debug, Clone)]
+enum Children {
+  /// Range of `order` with the shapes of the leaf.
+  Leaf(usize, usize),
+  /// Indices of the two child nodes.
+  Inner(usize, usize),
+}
+
+impl Node {
+  /// Returns the distance from the point to the box, 0 inside.
+  #[inline]
+  fn distance(&self, point: &Point2<f64>) -> f64 {
+    let dx = (self.min.x - point.x).max(point.x - self.max.x).max(0.0);
+    let dy = (self.min.y - point.y).max(point.y - self.max.y).max(0.0);
+
+    (dx * dx + dy * dy).sqrt()
+  }
+
+  #[inline]
+  fn overlaps(&self, min: &Point2<f64>, max: &Point2<f64>) -> bool {
+    self.min.x <= max.x && min.x <= self.max.x && self.min.y <= max.y && min.y <= self.max.y
+  }
+}
+
+impl<T, U> IndexedCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  /// Creates the collection and builds the hierarchy.
+  pub fn new(shapes: Vec<Shape<T, U>>) -> Self {
+    let boxes = shapes
+      .iter()
+      .map(|shape| {
+        let bbox = shape.bbox();
+        (
+          bbox.min().map(Into::<f64>::into),
+          bbox.max().map(Into::<f64>::into),
+        )
+      })
+      .collect::<Vec<_>>();
+
+    let mut collection = Self {
+      shapes,
+      nodes: Vec::new(),
+      order: (0..boxes.len()).collect(),
+    };
+    if !boxes.is_empty() {
+      collection.build(&boxes, 0, boxes.len());
+    }
+
+    collection
+  }
+
+  /// Builds the node for `order[start..end]` and its children, returns its index.
+  ///
+  /// Shapes are split at the median of their box centers along the longer side.
+  fn build(&mut self, boxes: &[(Point2<f64>, Point2<f64>)], start: usize, end: usize) -> usize {
+    let members = &mut self.order[start..end];
+
+    let mut min = Point2::new(f64::INFINITY, f64::INFINITY);
+    let mut max = Point2::new(f64::NEG_INFINITY, f64::NEG_INFINITY);
+    for &index in members.iter() {
+      let (a, b) = &boxes[index];
+      min = Point2::new(min.x.min(a.x), min.y.min(a.y));
+      max = Point2::new(max.x.max(b.x), max.y.max(b.y));
+    }
+
+    let index = self.nodes.len();
+    self.nodes.push(Node {
+      min,
+      max,
+      children: Children::Leaf(start, end),
+    });
+
+    if members.len() > LEAF_SIZE {
+      let center = |index: &usize| {
+        let (a, b) = &boxes[*index];
+        if max.x - min.x >= max.y - min.y {
+          OrderedFloat(a.x + b.x)
+        } else {
+          OrderedFloat(a.y + b.y)
+        }
+      };
+      let middle = members.len() / 2;
+      members.select_nth_unstable_by_key(middle, center);
+
+      let left = self.build(boxes, start, start + middle);
+      let right = self.build(boxes, start + middle, end);
+      self.nodes[index].children = Children::Inner(left, right);
+    }
+
+    index
+  }
+
+  /// Visits the shapes in every leaf whose box passes the filter, skipping whole subtrees
+  /// whose box doesn't pass it.
+  fn visit(&self, filter: impl Fn(&Node) -> bool, mut visit: impl FnMut(usize)) {
+    let mut stack = Vec::new();
+    if !self.nodes.is_empty() {
+      stack.push(0);
+    }
+
+    while let Some(node) = stack.pop() {
+      let node = &self.nodes[node];
+      if !filter(node) {
+        continue;
+      }
+
+      match node.children {
+        Children::Leaf(start, end) => self.order[start..end].iter().for_each(|&i| visit(i)),
+        Children::Inner(left, right) => stack.extend([right, left]),
+      }
+    }
+  }
+
+  /// Returns the indices of the shapes the point is within, in ascending order.
+  pub fn point_query(&self, point: &Point2<T>) -> Vec<usize> {
+    let p = point.map(Into::<f64>::into);
+    let mut indices = Vec::new();
+
+    self.visit(
+      |node| node.overlaps(&p, &p),
+      |index| {
+        if self.shapes[index].within(point) {
+          indices.push(index);
+        }
+      },
+    );
+
+    indices.sort_unstable();
+    indices
+  }
+
+  /// Returns the indices of the shapes intersecting the rectangle, in ascending order.
+  pub fn range_query(&self, range: &Rectangle<T>) -> Vec<usize> {
+    let min = range.min().map(Into::<f64>::into);
+    let max = range.max().map(Into::<f64>::into);
+    let mut indices = Vec::new();
+
+    self.visit(
+      |node| node.overlaps(&min, &max),
+      |index| {
+        if self.shapes[index].intersects(range) {
+          indices.push(index);
+        }
+      },
+    );
+
+    indices.sort_unstable();
+    indices
+  }
+
+  /// Returns the index of the shape closest to the point and its distance.
+  ///
+  /// Of several shapes at the same distance, the one with the lowest index is returned.
+  pub fn nearest(&self, point: &Point2<T>) -> Option<(usize, f64)> {
+    let p = point.map(Into::<f64>::into);
+    let mut best: Option<(usize, f64)> = None;
+
+    // Nodes are visited closest first, until the closest remaining one is farther away
+    // than the best shape so far.
+    let mut queue = BinaryHeap::new();
+    if let Some(root) = self.nodes.first() {
+      queue.push(Reverse((OrderedFloat(root.distance(&p)), 0)));
+    }
+
+    while let Some(Reverse((OrderedFloat(bound), node))) = queue.pop() {
+      if best.is_some_and(|(_, distance)| bound > distance) {
+        break;
+      }
+
+      match self.nodes[node].children {
+        Children::Leaf(start, end) => {
+          for &index in &self.order[start..end] {
+            let distance = self.shapes[index].distance(point);
+            let closer = best.is_none_or(|(best_index, best_distance)| {
+              distance < best_distance || (distance == best_distance && index < best_index)
+            });
+            if closer {
+              best = Some((index, distance));
+            }
+          }
+        }
+        Children::Inner(left, right) => {
+          for child in [left, right] {
+            queue.push(Reverse((
+              OrderedFloat(self.nodes[child].distance(&p)),
+              child,
+            )));
+          }
+        }
+      }
+    }
+
+    best
+  }
+}
+
+impl<T, U> IndexedCollection<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  pub fn shapes(&self) -> &[Shape<T, U>] {
+    &self.shapes
+  }
+}
+
+impl<T, U> From<ShapeCollection<T, U>> for IndexedCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn from(collection: ShapeCollection<T, U>) -> Self {
+    Self::new(collection.shapes)
+  }
+}
+
+impl<T, U> From<IndexedCollection<T, U>> for ShapeCollection<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn from(collection: IndexedCollection<T, U>) -> Self {
+    Self::new(collection.shapes)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::testing::PointView2;
+  use crate::traits::{Distance, Intersects, Within};
+  use crate::{Circle, Ellipse, IndexedCollection, Point2, Rectangle, Shape, ShapeCollection};
+
+  fn grid() -> IndexedCollection<u8, u8> {
+    let shapes = (0..10u8)
+      .flat_map(|x| (0..10u8).map(move |y| (x, y)))
+      .map(|(x, y)| Circle::new(Point2::new(x * 20 + 10, y * 20 + 10), 8).into())
+      .collect();
+
+    IndexedCollection::new(shapes)
+  }
+
+  #[test]
+  fn indexed_collection_point_query() {
+    let zones = grid();
+
+    assert_eq!(zones.point_query(&Point2::new(30, 50)), vec![12]);
+    assert_eq!(zones.point_query(&Point2::new(20, 20)), vec![]);
+    assert!(zones.within(&Point2::new(190, 190)));
+    assert!(!zones.within(&Point2::new(255, 255)));
+  }
+
+  #[test]
+  fn indexed_collection_range_query() {
+    let zones = grid();
+    let range = Rectangle::new(Point2::new(15, 15), Point2::new(25, 25));
+
+    assert_eq!(zones.range_query(&range), vec![0, 1, 10, 11]);
+  }
+
+  #[test]
+  fn indexed_collection_nearest() {
+    let zones = grid();
+
+    assert_eq!(zones.nearest(&Point2::new(250, 10)), Some((90, 52.0)));
+    assert_eq!(zones.distance(&Point2::new(250, 10)), 52.0);
+    // Equally far from four circles.
+    assert_eq!(zones.nearest(&Point2::new(20, 20)).unwrap().0, 0);
+  }
+
+  #[test]
+  fn indexed_collection_empty() {
+    let zones = IndexedCollection::<u8, u8>::new(vec![]);
+
+    assert_eq!(zones.point_query(&Point2::new(0, 0)), vec![]);
+    assert_eq!(
+      zones.range_query(&Rectangle::new(Point2::new(0, 0), Point2::new(255, 255))),
+      vec![]
+    );
+    assert_eq!(zones.nearest(&Point2::new(0, 0)), None);
+    assert_eq!(
+      zones.distance(&Point2::new(0, 0)),
+      ShapeCollection::<u8, u8>::new(vec![]).distance(&Point2::new(0, 0))
+    );
+  }
+
+  #[test]
+  fn indexed_collection_fractional_radius() {
+    let mut shapes: Vec<Shape<u8, f32>> = vec![
+      Circle::new(Point2::new(10, 10), 2.5).into(),
+      Circle::new(Point2::new(15, 10), 1.3).into(),
+    ];
+    // Fillers on both sides, so that the two circles end up in different leaves.
+    shapes.extend([1, 3, 5, 100, 150, 200].map(|x| Circle::new(Point2::new(x, 10), 1.0).into()));
+    let zones = IndexedCollection::new(shapes);
+
+    // A box truncated towards the center would hide the first circle behind the second.
+    assert_eq!(zones.nearest(&Point2::new(13, 10)), Some((0, 0.5)));
+    assert_eq!(zones.distance(&Point2::new(13, 10)), 0.5);
+  }
+
+  #[proptest(ProptestConfig::with_cases(64))]
+  fn indexed_collection_u8_matches_linear(
+    #[strategy(prop::collection::vec(any::<ShapeView<u8, u8>>(), 0..24))] shapes: Vec<
+      ShapeView<u8, u8>,
+    >,
+    point: PointView2<u8>,
+    corner: PointView2<u8>,
+  ) {
+    let shapes = shapes.into_iter().map(Shape::from).collect::<Vec<_>>();
+    let linear = ShapeCollection::new(shapes.clone());
+    let indexed = IndexedCollection::new(shapes.clone());
+    let point = Point2::from(point);
+    let range = Rectangle::new(point, Point2::from(corner));
+
+    let within = (0..shapes.len())
+      .filter(|&i| shapes[i].within(&point))
+      .collect::<Vec<_>>();
+    assert_eq!(indexed.point_query(&point), within);
+    assert_eq!(indexed.within(&point), linear.within(&point));
+
+    let intersecting = (0..shapes.len())
+      .filter(|&i| shapes[i].intersects(&range))
+      .collect::<Vec<_>>();
+    assert_eq!(indexed.range_query(&range), intersecting);
+
+    assert_eq!(indexed.distance(&point), linear.distance(&point));
+    if let Some((index, distance)) = indexed.nearest(&point) {
+      assert_eq!(shapes[index].distance(&point), distance);
+    }
+  }
+
+  #[proptest(ProptestConfig::with_cases(64))]
+  fn indexed_collection_fractional_radius_matches_linear(
+    #[strategy(prop::collection::vec((any::<PointView2<u8>>(), 0.0f32..32.0, 0.0f32..32.0), 0..24))]
+    ellipses: Vec<(PointView2<u8>, f32, f32)>,
+    point: PointView2<u8>,
+  ) {
+    let shapes = ellipses
+      .into_iter()
+      .enumerate()
+      .map(|(i, (center, rx, ry))| match i % 2 {
+        0 => Circle::new(center.into(), rx).into(),
+        _ => Ellipse::new(center.into(), (rx, ry)).into(),
+      })
+      .collect::<Vec<Shape<u8, f32>>>();
+    let linear = ShapeCollection::new(shapes.clone());
+    let indexed = IndexedCollection::new(shapes.clone());
+    let point = Point2::from(point);
+
+    assert_eq!(indexed.distance(&point), linear.distance(&point));
+    if let Some((index, distance)) = indexed.nearest(&point) {
+      assert_eq!(shapes[index].distance(&point), distance);
+    }
+  }
+}
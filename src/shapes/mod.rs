@@ -1,51 +1,85 @@
 mod circle;
+mod csg;
 mod ellipse;
+mod fixed_shape_collection;
+mod indexed_collection;
+mod polygon;
+mod polygon_with_holes;
 mod rectangle;
+mod rotated_ellipse;
+mod rotated_rectangle;
 mod shape_collection;
+mod slice_shape_collection;
 mod triangle;
 
 pub use circle::*;
+pub use csg::*;
 pub use ellipse::*;
+pub use fixed_shape_collection::*;
+pub use indexed_collection::*;
+pub use polygon::*;
+pub use polygon_with_holes::*;
 pub use rectangle::*;
+pub use rotated_ellipse::*;
+pub use rotated_rectangle::*;
 pub use shape_collection::*;
+pub use slice_shape_collection::*;
 pub use triangle::*;
 
+use core::hash::{Hash, Hasher};
+
 use derivative::Derivative;
 use nalgebra::Scalar;
-use num::Unsigned;
 
 #[cfg_attr(
   feature = "serde-serialize",
-  derive(serde::Serialize, serde::Deserialize)
+  derive(serde::Serialize, serde::Deserialize),
+  serde(bound(
+    deserialize = "T: crate::FloatMath + serde::Deserialize<'de>, U: serde::Deserialize<'de>"
+  ))
 )]
 #[derive(Derivative)]
 #[derivative(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum Shape<T, U>
 where
   T: Scalar,
-  U: Scalar + Unsigned,
+  U: Scalar,
 {
   Rectangle(Rectangle<T>),
+  RotatedRectangle(RotatedRectangle<T>),
   Circle(Circle<T, U>),
   Ellipse(Ellipse<T, U>),
+  RotatedEllipse(RotatedEllipse<T, U>),
   Triangle(Triangle<T>),
+  Polygon(Polygon<T>),
+  PolygonWithHoles(PolygonWithHoles<T>),
   Collection(ShapeCollection<T, U>),
 }
 
 impl<T, R> From<Rectangle<T>> for Shape<T, R>
 where
   T: Scalar + Clone,
-  R: Scalar + Unsigned,
+  R: Scalar,
 {
   fn from(rectangle: Rectangle<T>) -> Self {
     Self::Rectangle(rectangle)
   }
 }
 
+impl<T, R> From<RotatedRectangle<T>> for Shape<T, R>
+where
+  T: Scalar + Clone,
+  R: Scalar,
+{
+  fn from(rectangle: RotatedRectangle<T>) -> Self {
+    Self::RotatedRectangle(rectangle)
+  }
+}
+
 impl<T, R> From<Circle<T, R>> for Shape<T, R>
 where
   T: Scalar + Clone,
-  R: Scalar + Unsigned,
+  R: Scalar,
 {
   fn from(circle: Circle<T, R>) -> Self {
     Self::Circle(circle)
@@ -55,36 +89,79 @@ where
 impl<T, R> From<Ellipse<T, R>> for Shape<T, R>
 where
   T: Scalar + Clone,
-  R: Scalar + Unsigned,
+  R: Scalar,
 {
   fn from(ellipse: Ellipse<T, R>) -> Self {
     Self::Ellipse(ellipse)
   }
 }
 
+impl<T, R> From<RotatedEllipse<T, R>> for Shape<T, R>
+where
+  T: Scalar + Clone,
+  R: Scalar,
+{
+  fn from(ellipse: RotatedEllipse<T, R>) -> Self {
+    Self::RotatedEllipse(ellipse)
+  }
+}
+
 impl<T, R> From<Triangle<T>> for Shape<T, R>
 where
   T: Scalar + Clone,
-  R: Scalar + Unsigned,
+  R: Scalar,
 {
   fn from(triangle: Triangle<T>) -> Self {
     Self::Triangle(triangle)
   }
 }
 
+impl<T, R> From<Polygon<T>> for Shape<T, R>
+where
+  T: Scalar + Clone,
+  R: Scalar,
+{
+  fn from(polygon: Polygon<T>) -> Self {
+    Self::Polygon(polygon)
+  }
+}
+
+impl<T, R> From<PolygonWithHoles<T>> for Shape<T, R>
+where
+  T: Scalar + Clone,
+  R: Scalar,
+{
+  fn from(polygon: PolygonWithHoles<T>) -> Self {
+    Self::PolygonWithHoles(polygon)
+  }
+}
+
 impl<T, R> From<ShapeCollection<T, R>> for Shape<T, R>
 where
   T: Scalar + Clone,
-  R: Scalar + Unsigned,
+  R: Scalar,
 {
   fn from(collection: ShapeCollection<T, R>) -> Self {
     Self::Collection(collection)
   }
 }
 
+/// Hashes an `f64` by its bit pattern, for shapes with floating-point parameters.
+pub(crate) fn hash_f64<H: Hasher>(value: &f64, state: &mut H) {
+  value.to_bits().hash(state);
+}
+
+/// Compares two `f64` by their bit patterns, so that equality agrees with [`hash_f64`].
+pub(crate) fn eq_f64(a: &f64, b: &f64) -> bool {
+  a.to_bits() == b.to_bits()
+}
+
 #[cfg(test)]
 mod tests {
-  use crate::{Circle, Ellipse, Point2, Rectangle, Shape, ShapeCollection, Triangle};
+  use crate::{
+    Circle, Ellipse, Point2, Polygon, PolygonWithHoles, Rectangle, RotatedEllipse,
+    RotatedRectangle, Shape, ShapeCollection, Triangle,
+  };
 
   #[test]
   fn test_from() {
@@ -94,12 +171,24 @@ mod tests {
       Shape::Rectangle(_)
     ));
 
+    let rectangle = RotatedRectangle::new(Point2::from([10, 10]), (5, 5), 0.5);
+    assert!(matches!(
+      Shape::<u8, u8>::from(rectangle),
+      Shape::RotatedRectangle(_)
+    ));
+
     let circle = Circle::new(Point2::from([10, 10]), 5);
     assert!(matches!(Shape::<u8, u8>::from(circle), Shape::Circle(_)));
 
     let ellipse = Ellipse::new(Point2::from([10, 10]), (5, 5));
     assert!(matches!(Shape::<u8, u8>::from(ellipse), Shape::Ellipse(_)));
 
+    let ellipse = RotatedEllipse::new(Point2::from([10, 10]), (5, 3), 0.5);
+    assert!(matches!(
+      Shape::<u8, u8>::from(ellipse),
+      Shape::RotatedEllipse(_)
+    ));
+
     let triangle = Triangle::new(
       Point2::from([0, 0]),
       Point2::from([10, 0]),
@@ -110,6 +199,23 @@ mod tests {
       Shape::Triangle(_)
     ));
 
+    let polygon = Polygon::new(vec![
+      Point2::from([0, 0]),
+      Point2::from([10, 0]),
+      Point2::from([10, 10]),
+      Point2::from([0, 10]),
+    ]);
+    assert!(matches!(
+      Shape::<u8, u8>::from(polygon.clone()),
+      Shape::Polygon(_)
+    ));
+
+    let polygon_with_holes = PolygonWithHoles::new(polygon, vec![]).unwrap();
+    assert!(matches!(
+      Shape::<u8, u8>::from(polygon_with_holes),
+      Shape::PolygonWithHoles(_)
+    ));
+
     let collection = ShapeCollection::new(vec![
       Shape::Rectangle(Rectangle::new(Point2::from([0, 0]), Point2::from([10, 10]))),
       Shape::Circle(Circle::new(Point2::from([10, 10]), 5)),
@@ -119,4 +225,29 @@ mod tests {
       Shape::Collection(_)
     ));
   }
+
+  fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+    use core::hash::Hasher;
+
+    let mut hasher = std::hash::DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+  }
+
+  #[test]
+  fn test_angle_eq_agrees_with_hash() {
+    let positive = RotatedRectangle::<u8>::new(Point2::new(10, 10), (5, 3), 0.0);
+    let negative = RotatedRectangle::<u8>::new(Point2::new(10, 10), (5, 3), -0.0);
+    assert_eq!(positive, negative);
+    assert_eq!(hash(&positive), hash(&negative));
+
+    let positive = RotatedEllipse::<u8, u8>::new(Point2::new(10, 10), (5, 3), 0.0);
+    let negative = RotatedEllipse::<u8, u8>::new(Point2::new(10, 10), (5, 3), -0.0);
+    assert_eq!(positive, negative);
+    assert_eq!(hash(&positive), hash(&negative));
+
+    let nan = RotatedEllipse::<u8, u8>::new(Point2::new(10, 10), (5, 3), f64::NAN);
+    assert_eq!(nan, nan);
+    assert_ne!(nan, positive);
+  }
 }
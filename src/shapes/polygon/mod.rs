@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+
+use derivative::Derivative;
+use getset::Getters;
+
+use crate::*;
+
+/// Rule used to decide which regions of a polygon are inside.
+///
+/// Both rules give the same result for simple polygons and only differ for
+/// self-intersecting outlines.
+#[cfg_attr(
+  feature = "serde-serialize",
+  derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum FillRule {
+  /// A point is inside if a ray from it crosses the outline an odd number of times.
+  #[default]
+  EvenOdd,
+  /// A point is inside if the outline winds around it at least once.
+  NonZero,
+}
+
+#[cfg_attr(
+  feature = "serde-serialize",
+  derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Getters, Derivative)]
+#[derivative(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Polygon<T: Scalar> {
+  #[getset(get = "pub")]
+  pub(crate) points: Vec<Point2<T>>,
+  #[getset(get = "pub")]
+  #[cfg_attr(feature = "serde-serialize", serde(default))]
+  pub(crate) fill_rule: FillRule,
+}
+
+impl<T> Polygon<T>
+where
+  T: Scalar,
+{
+  /// Creates a new polygon from its vertices, using the even-odd fill rule.
+  ///
+  /// The outline is closed implicitly, the last point connects back to the first one.
+  #[inline]
+  pub const fn new(points: Vec<Point2<T>>) -> Self {
+    Self {
+      points,
+      fill_rule: FillRule::EvenOdd,
+    }
+  }
+
+  /// Returns the same polygon with a different fill rule.
+  #[inline]
+  pub const fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+    self.fill_rule = fill_rule;
+    self
+  }
+
+  /// Returns an iterator over the edges of the closed outline.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Line, Point2, Polygon};
+  ///
+  /// let polygon = Polygon::new(vec![Point2::new(0, 0), Point2::new(10, 0), Point2::new(0, 10)]);
+  /// let edges = polygon.edges().collect::<Vec<_>>();
+  ///
+  /// assert_eq!(edges.len(), 3);
+  /// assert_eq!(edges[2], Line::new_unchecked(Point2::new(0, 10), Point2::new(0, 0)));
+  /// ```
+  pub fn edges(&self) -> impl Iterator<Item = Line<T>> + '_ {
+    let next = self.points.iter().cycle().skip(1);
+
+    self
+      .points
+      .iter()
+      .zip(next)
+      .map(|(start, end)| Line::new_unchecked(start.clone(), end.clone()))
+  }
+}
+
+impl<T> Polygon<T>
+where
+  T: FloatMath,
+{
+  /// Returns the signed area of the polygon, computed with the shoelace formula.
+  ///
+  /// The area is positive for counter-clockwise outlines (in a y-up coordinate system)
+  /// and negative for clockwise ones.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Point2, Polygon};
+  ///
+  /// let polygon = Polygon::new(vec![Point2::new(0, 0), Point2::new(10, 0), Point2::new(10, 10)]);
+  /// assert_eq!(polygon.signed_area(), 50.0);
+  /// ```
+  pub fn signed_area(&self) -> f64 {
+    self
+      .edges()
+      .map(|edge| {
+        let start = edge.start.map(Into::<f64>::into);
+        let end = edge.end.map(Into::<f64>::into);
+        start.x * end.y - end.x * start.y
+      })
+      .sum::<f64>()
+      / 2.0
+  }
+
+  /// Checks if the point lies on the outline of the polygon.
+  pub(crate) fn on_outline(&self, point: &Point2<T>) -> bool {
+    let p = point.map(Into::<f64>::into);
+
+    self.edges().any(|edge| {
+      let a = edge.start.map(Into::<f64>::into);
+      let b = edge.end.map(Into::<f64>::into);
+
+      let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+
+      cross == 0.0
+        && a.x.min(b.x) <= p.x
+        && p.x <= a.x.max(b.x)
+        && a.y.min(b.y) <= p.y
+        && p.y <= a.y.max(b.y)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use test_case::test_case;
+
+  use crate::{FillRule, Point2, Polygon};
+
+  #[test]
+  fn test_new_defaults_to_even_odd() {
+    let polygon = Polygon::new(vec![
+      Point2::new(0, 0),
+      Point2::new(10, 0),
+      Point2::new(0, 10),
+    ]);
+
+    assert_eq!(polygon.fill_rule(), &FillRule::EvenOdd);
+    assert_eq!(
+      polygon.with_fill_rule(FillRule::NonZero).fill_rule(),
+      &FillRule::NonZero
+    );
+  }
+
+  #[test_case(vec![Point2::new(0, 0), Point2::new(10, 0), Point2::new(10, 10), Point2::new(0, 10)] => 100.0; "counter-clockwise square")]
+  #[test_case(vec![Point2::new(0, 10), Point2::new(10, 10), Point2::new(10, 0), Point2::new(0, 0)] => -100.0; "clockwise square")]
+  #[test_case(vec![Point2::new(0, 0), Point2::new(10, 0), Point2::new(5, 5), Point2::new(10, 10), Point2::new(0, 10)] => 75.0; "concave")]
+  #[test_case(vec![Point2::new(0, 0), Point2::new(10, 10)] => 0.0; "degenerate")]
+  #[test_case(vec![] => 0.0; "empty")]
+  fn test_signed_area(points: Vec<Point2<i32>>) -> f64 {
+    Polygon::new(points).signed_area()
+  }
+}
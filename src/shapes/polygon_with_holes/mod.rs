@@ -0,0 +1,331 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use derivative::Derivative;
+use getset::Getters;
+
+use crate::traits::Within;
+use crate::*;
+
+/// Error returned when the rings of a [`PolygonWithHoles`] do not describe a valid shape.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum PolygonError {
+  /// The exterior ring has less than three points or no area.
+  DegenerateExterior,
+  /// The hole with the given index has less than three points or no area.
+  DegenerateHole(usize),
+  /// The hole with the given index is not fully inside the exterior ring, or covers all of it.
+  HoleOutsideExterior(usize),
+  /// The holes with the given indices overlap each other.
+  OverlappingHoles(usize, usize),
+}
+
+impl fmt::Display for PolygonError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::DegenerateExterior => write!(f, "exterior ring is degenerate"),
+      Self::DegenerateHole(index) => write!(f, "hole {} is degenerate", index),
+      Self::HoleOutsideExterior(index) => write!(f, "hole {} is outside of the exterior", index),
+      Self::OverlappingHoles(a, b) => write!(f, "holes {} and {} overlap", a, b),
+    }
+  }
+}
+
+impl core::error::Error for PolygonError {}
+
+/// Polygon with one exterior ring and any number of interior rings (holes).
+///
+/// Points on the outline of a hole belong to the shape, points strictly inside a hole do not.
+///
+/// Deserialized values are validated with [`PolygonWithHoles::new`].
+#[cfg_attr(
+  feature = "serde-serialize",
+  derive(serde::Serialize, serde::Deserialize),
+  serde(
+    try_from = "PolygonWithHolesRings<T>",
+    bound(deserialize = "T: crate::FloatMath + serde::Deserialize<'de>")
+  )
+)]
+#[derive(Getters, Derivative)]
+#[derivative(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct PolygonWithHoles<T: Scalar> {
+  #[getset(get = "pub")]
+  pub(crate) exterior: Polygon<T>,
+  #[getset(get = "pub")]
+  pub(crate) holes: Vec<Polygon<T>>,
+}
+
+impl<T> PolygonWithHoles<T>
+where
+  T: Scalar,
+{
+  /// Creates a new polygon with holes without validating the rings.
+  #[inline]
+  pub const fn new_unchecked(exterior: Polygon<T>, holes: Vec<Polygon<T>>) -> Self {
+    Self { exterior, holes }
+  }
+}
+
+impl<T> PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  /// Creates a new polygon with holes, validating the rings.
+  ///
+  /// The rings are reoriented if necessary, so that the exterior is counter-clockwise
+  /// and the holes are clockwise (in a y-up coordinate system).
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Point2, Polygon, PolygonError, PolygonWithHoles};
+  ///
+  /// let exterior = Polygon::new(vec![[0, 0].into(), [30, 0].into(), [30, 30].into(), [0, 30].into()]);
+  /// let hole = Polygon::new(vec![[10, 10].into(), [20, 10].into(), [20, 20].into(), [10, 20].into()]);
+  /// let outside = Polygon::new(vec![[40, 40].into(), [50, 40].into(), [50, 50].into()]);
+  ///
+  /// assert!(PolygonWithHoles::new(exterior.clone(), vec![hole]).is_ok());
+  /// assert_eq!(
+  ///   PolygonWithHoles::new(exterior, vec![outside]),
+  ///   Err(PolygonError::HoleOutsideExterior(0))
+  /// );
+  /// ```
+  pub fn new(exterior: Polygon<T>, holes: Vec<Polygon<T>>) -> Result<Self, PolygonError> {
+    let exterior_area = exterior.signed_area();
+    if exterior.points.len() < 3 || exterior_area == 0.0 {
+      return Err(PolygonError::DegenerateExterior);
+    }
+    let exterior = if exterior_area < 0.0 {
+      reversed(exterior)
+    } else {
+      exterior
+    };
+    let outer = to_f64(&exterior);
+
+    let mut oriented = Vec::with_capacity(holes.len());
+    let mut rings = Vec::with_capacity(holes.len());
+    for (index, hole) in holes.into_iter().enumerate() {
+      let area = hole.signed_area();
+      if hole.points.len() < 3 || area == 0.0 {
+        return Err(PolygonError::DegenerateHole(index));
+      }
+
+      let hole = if area > 0.0 { reversed(hole) } else { hole };
+      let ring = to_f64(&hole);
+
+      // A hole as large as the exterior would leave nothing of the shape.
+      if area.abs() >= exterior_area.abs() || !contains(&outer, &ring) {
+        return Err(PolygonError::HoleOutsideExterior(index));
+      }
+
+      oriented.push(hole);
+      rings.push(ring);
+    }
+
+    for (i, a) in rings.iter().enumerate() {
+      for (j, b) in rings.iter().enumerate().skip(i + 1) {
+        if interiors_overlap(a, b) {
+          return Err(PolygonError::OverlappingHoles(i, j));
+        }
+      }
+    }
+
+    Ok(Self::new_unchecked(exterior, oriented))
+  }
+
+  /// Returns an iterator over all rings, the exterior first.
+  pub fn rings(&self) -> impl Iterator<Item = &Polygon<T>> {
+    core::iter::once(&self.exterior).chain(self.holes.iter())
+  }
+}
+
+fn reversed<T: Scalar>(mut polygon: Polygon<T>) -> Polygon<T> {
+  polygon.points.reverse();
+  polygon
+}
+
+/// Unvalidated rings of a [`PolygonWithHoles`], as they are deserialized.
+#[cfg(feature = "serde-serialize")]
+#[derive(serde::Deserialize)]
+struct PolygonWithHolesRings<T: Scalar> {
+  exterior: Polygon<T>,
+  holes: Vec<Polygon<T>>,
+}
+
+#[cfg(feature = "serde-serialize")]
+impl<T> TryFrom<PolygonWithHolesRings<T>> for PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  type Error = PolygonError;
+
+  fn try_from(rings: PolygonWithHolesRings<T>) -> Result<Self, Self::Error> {
+    Self::new(rings.exterior, rings.holes)
+  }
+}
+
+fn to_f64<T: FloatMath>(polygon: &Polygon<T>) -> Polygon<f64> {
+  Polygon::new(
+    polygon
+      .points
+      .iter()
+      .map(|point| point.map(Into::<f64>::into))
+      .collect(),
+  )
+}
+
+fn orientation(a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>) -> f64 {
+  (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+/// Returns the vertices and the edge midpoints of the ring.
+///
+/// Midpoints catch edges that pass through the other ring between two vertices on its outline.
+fn sample_points(ring: &Polygon<f64>) -> impl Iterator<Item = Point2<f64>> + '_ {
+  ring.points.iter().copied().chain(
+    ring
+      .edges()
+      .map(|edge| nalgebra::center(&edge.start, &edge.end)),
+  )
+}
+
+/// Checks if `inner` lies inside `outer`, touching its outline at most.
+fn contains(outer: &Polygon<f64>, inner: &Polygon<f64>) -> bool {
+  sample_points(inner).all(|point| outer.within(&point)) && !rings_cross(outer, inner)
+}
+
+/// Checks if the interiors of two rings with the same orientation overlap.
+fn interiors_overlap(a: &Polygon<f64>, b: &Polygon<f64>) -> bool {
+  rings_cross(a, b)
+    || strictly_contains_any(a, b)
+    || strictly_contains_any(b, a)
+    || shares_edge(a, b)
+}
+
+/// Checks if any vertex or edge midpoint of `other` is strictly inside `ring`.
+fn strictly_contains_any(ring: &Polygon<f64>, other: &Polygon<f64>) -> bool {
+  sample_points(other).any(|point| ring.within(&point) && !ring.on_outline(&point))
+}
+
+/// Checks if the rings share a piece of an edge running in the same direction.
+///
+/// With both rings oriented the same way, their interiors are then on the same side of it,
+/// which covers equal rings that no point can be strictly inside of.
+fn shares_edge(a: &Polygon<f64>, b: &Polygon<f64>) -> bool {
+  a.edges().any(|first| {
+    let direction = first.end - first.start;
+    let length = direction.dot(&direction);
+
+    b.edges().any(|second| {
+      if orientation(&first.start, &first.end, &second.start) != 0.0
+        || orientation(&first.start, &first.end, &second.end) != 0.0
+        || direction.dot(&(second.end - second.start)) <= 0.0
+      {
+        return false;
+      }
+
+      let start = direction.dot(&(second.start - first.start));
+      let end = direction.dot(&(second.end - first.start));
+      start.min(end) < length && start.max(end) > 0.0
+    })
+  })
+}
+
+/// Checks if any edges of the two rings properly cross each other.
+///
+/// Touching edges and shared vertices are not considered crossing.
+fn rings_cross(a: &Polygon<f64>, b: &Polygon<f64>) -> bool {
+  a.edges().any(|first| {
+    let (p1, p2) = (first.start, first.end);
+
+    b.edges().any(|second| {
+      let (q1, q2) = (second.start, second.end);
+
+      orientation(&p1, &p2, &q1) * orientation(&p1, &p2, &q2) < 0.0
+        && orientation(&q1, &q2, &p1) * orientation(&q1, &q2, &p2) < 0.0
+    })
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use test_case::test_case;
+
+  use crate::{Point2, Polygon, PolygonError, PolygonWithHoles};
+
+  fn square(min: u8, max: u8) -> Polygon<u8> {
+    Polygon::new(vec![
+      Point2::new(min, min),
+      Point2::new(max, min),
+      Point2::new(max, max),
+      Point2::new(min, max),
+    ])
+  }
+
+  fn rectangle(min_x: u8, min_y: u8, max_x: u8, max_y: u8) -> Polygon<u8> {
+    Polygon::new(vec![
+      Point2::new(min_x, min_y),
+      Point2::new(max_x, min_y),
+      Point2::new(max_x, max_y),
+      Point2::new(min_x, max_y),
+    ])
+  }
+
+  #[test]
+  fn test_new_orients_rings() {
+    let exterior = Polygon::new(square(0, 30).points().iter().rev().copied().collect());
+    let shape = PolygonWithHoles::new(exterior, vec![square(10, 20)]).unwrap();
+
+    assert!(shape.exterior().signed_area() > 0.0);
+    assert!(shape.holes()[0].signed_area() < 0.0);
+    assert_eq!(shape.rings().count(), 2);
+  }
+
+  #[test_case(square(0, 0), vec![] => Err(PolygonError::DegenerateExterior); "degenerate exterior")]
+  #[test_case(square(0, 30), vec![Polygon::new(vec![[1, 1].into(), [2, 2].into()])] => Err(PolygonError::DegenerateHole(0)); "degenerate hole")]
+  #[test_case(square(0, 30), vec![square(20, 40)] => Err(PolygonError::HoleOutsideExterior(0)); "hole crossing exterior")]
+  #[test_case(square(0, 30), vec![square(40, 50)] => Err(PolygonError::HoleOutsideExterior(0)); "hole outside exterior")]
+  #[test_case(square(10, 20), vec![square(0, 30)] => Err(PolygonError::HoleOutsideExterior(0)); "hole around exterior")]
+  #[test_case(square(0, 30), vec![square(5, 15), square(10, 20)] => Err(PolygonError::OverlappingHoles(0, 1)); "crossing holes")]
+  #[test_case(square(0, 30), vec![square(5, 25), square(10, 20)] => Err(PolygonError::OverlappingHoles(0, 1)); "nested holes")]
+  #[test_case(square(0, 30), vec![square(5, 10), square(10, 15)] => Ok(()); "touching holes")]
+  #[test_case(square(0, 30), vec![square(0, 10)] => Ok(()); "hole touching exterior")]
+  #[test_case(square(0, 30), vec![square(0, 30)] => Err(PolygonError::HoleOutsideExterior(0)); "hole equal to exterior")]
+  #[test_case(square(0, 30), vec![square(10, 20), square(10, 20)] => Err(PolygonError::OverlappingHoles(0, 1)); "identical holes")]
+  #[test_case(square(0, 30), vec![square(10, 20), rectangle(10, 10, 15, 20)] => Err(PolygonError::OverlappingHoles(0, 1)); "holes sharing edges")]
+  #[test_case(square(0, 30), vec![rectangle(5, 10, 15, 20), rectangle(10, 10, 20, 20)] => Err(PolygonError::OverlappingHoles(0, 1)); "holes overlapping along an edge")]
+  #[test_case(square(0, 30), vec![rectangle(5, 10, 10, 20), rectangle(10, 10, 20, 20)] => Ok(()); "holes sharing an edge")]
+  fn test_new_validates(
+    exterior: Polygon<u8>,
+    holes: Vec<Polygon<u8>>,
+  ) -> Result<(), PolygonError> {
+    PolygonWithHoles::new(exterior, holes).map(|_| ())
+  }
+
+  #[test]
+  fn test_concave_exterior() {
+    let exterior = Polygon::new(vec![
+      Point2::new(0, 0),
+      Point2::new(30, 0),
+      Point2::new(30, 30),
+      Point2::new(20, 30),
+      Point2::new(20, 10),
+      Point2::new(10, 10),
+      Point2::new(10, 30),
+      Point2::new(0, 30),
+    ]);
+    // All vertices are inside the exterior, but the hole spans the notch.
+    let hole = Polygon::new(vec![
+      Point2::new(5, 20),
+      Point2::new(25, 20),
+      Point2::new(25, 25),
+      Point2::new(5, 25),
+    ]);
+
+    assert_eq!(
+      PolygonWithHoles::new(exterior, vec![hole]),
+      Err(PolygonError::HoleOutsideExterior(0))
+    );
+  }
+}
@@ -16,17 +16,17 @@ where
   T: Scalar,
 {
   #[inline]
-  pub fn new_unchecked(min: Point2<T>, max: Point2<T>) -> Self {
+  pub const fn new_unchecked(min: Point2<T>, max: Point2<T>) -> Self {
     Self(min, max)
   }
 
   #[inline]
-  pub fn min(&self) -> &Point2<T> {
+  pub const fn min(&self) -> &Point2<T> {
     &self.0
   }
 
   #[inline]
-  pub fn max(&self) -> &Point2<T> {
+  pub const fn max(&self) -> &Point2<T> {
     &self.1
   }
 }
@@ -56,8 +56,57 @@ where
 
     Self::new_unchecked(Point2::new(x_min, y_min), Point2::new(x_max, y_max))
   }
+
+  /// Checks if the minimum corner is past the maximum corner on either axis.
+  ///
+  /// Bounding boxes use such rectangles for shapes without any points, e.g. disjoint
+  /// intersections.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Point2, Rectangle};
+  ///
+  /// assert!(!Rectangle::new(Point2::new(0, 0), Point2::new(0, 0)).is_empty());
+  /// assert!(Rectangle::new_unchecked(Point2::new(10, 0), Point2::new(0, 10)).is_empty());
+  /// ```
+  pub fn is_empty(&self) -> bool {
+    self.0.x > self.1.x || self.0.y > self.1.y
+  }
 }
 
+macro_rules! impl_rectangle_const {
+  ($($t:ty),*) => {$(
+    impl Rectangle<$t> {
+      /// Same as [`Rectangle::new`], but usable in constant expressions.
+      ///
+      /// Only available for concrete integer types, so the type has to be named explicitly.
+      ///
+      /// # Example
+      /// ```rust
+      /// use rshapes::{Point2, Rectangle};
+      ///
+      #[doc = concat!("const CHEST: Rectangle<", stringify!($t), "> = Rectangle::<", stringify!($t), ">::new_const(Point2::new(10, 0), Point2::new(0, 10));")]
+      ///
+      /// assert_eq!(CHEST, Rectangle::new(Point2::new(0, 0), Point2::new(10, 10)));
+      /// ```
+      pub const fn new_const(a: Point2<$t>, b: Point2<$t>) -> Self {
+        // Field access goes through `Deref`, which isn't available in constants.
+        let [ax, ay] = a.coords.data.0[0];
+        let [bx, by] = b.coords.data.0[0];
+
+        let x_max = if ax > bx { ax } else { bx };
+        let x_min = if ax < bx { ax } else { bx };
+        let y_max = if ay > by { ay } else { by };
+        let y_min = if ay < by { ay } else { by };
+
+        Self::new_unchecked(Point2::new(x_min, y_min), Point2::new(x_max, y_max))
+      }
+    }
+  )*};
+}
+
+impl_rectangle_const!(u8, u16, u32, i16, i32);
+
 impl<T> Rectangle<T>
 where
   T: Scalar + Num,
@@ -96,7 +145,9 @@ where
 #[cfg(test)]
 mod tests {
   use test_case::test_case;
+  use test_strategy::proptest;
 
+  use crate::testing::PointView2;
   use crate::{Point2, Rectangle};
 
   #[test_case(
@@ -123,4 +174,11 @@ mod tests {
     assert_eq!(rectangle.min(), &min);
     assert_eq!(rectangle.max(), &max);
   }
+
+  #[proptest]
+  fn new_const_matches_new_i16(a: PointView2<i16>, b: PointView2<i16>) {
+    let (a, b) = (Point2::from(a), Point2::from(b));
+
+    assert_eq!(Rectangle::<i16>::new_const(a, b), Rectangle::new(a, b));
+  }
 }
@@ -0,0 +1,117 @@
+use derivative::Derivative;
+use getset::Getters;
+
+use crate::*;
+
+/// Ellipse rotated counter-clockwise around its center.
+#[cfg_attr(
+  feature = "serde-serialize",
+  derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Getters, Derivative)]
+#[derivative(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct RotatedEllipse<T: Scalar, R: Scalar> {
+  #[getset(get = "pub")]
+  pub(crate) center: Point2<T>,
+  #[getset(get = "pub")]
+  pub(crate) radius: (R, R),
+  /// Rotation angle in radians, compared and hashed by its bit pattern.
+  #[getset(get = "pub")]
+  #[derivative(
+    Hash(hash_with = "crate::shapes::hash_f64"),
+    PartialEq(compare_with = "crate::shapes::eq_f64")
+  )]
+  pub(crate) angle: f64,
+}
+
+impl<T, R> RotatedEllipse<T, R>
+where
+  T: Scalar,
+  R: Scalar,
+{
+  #[inline]
+  pub const fn new(center: Point2<T>, radius: (R, R), angle: f64) -> Self {
+    Self {
+      center,
+      radius,
+      // Adding zero turns `-0.0` into `0.0`, which would not be equal by bits.
+      angle: angle + 0.0,
+    }
+  }
+
+  #[inline]
+  pub const fn width(&self) -> &R {
+    &self.radius.0
+  }
+
+  #[inline]
+  pub const fn height(&self) -> &R {
+    &self.radius.1
+  }
+}
+
+impl<T, R> RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  /// Converts the point into the coordinate system of the ellipse,
+  /// where the ellipse is centered at the origin and axis-aligned.
+  pub(crate) fn local_point(&self, point: &Point2<T>) -> Point2<f64> {
+    to_local(&self.center, self.angle, point)
+  }
+}
+
+impl<T, R> From<Ellipse<T, R>> for RotatedEllipse<T, R>
+where
+  T: Scalar,
+  R: Scalar,
+{
+  fn from(ellipse: Ellipse<T, R>) -> Self {
+    Self::new(ellipse.center, ellipse.radius, 0.0)
+  }
+}
+
+/// Translates the point by `-center` and rotates it by `-angle`.
+pub(crate) fn to_local<T: FloatMath>(
+  center: &Point2<T>,
+  angle: f64,
+  point: &Point2<T>,
+) -> Point2<f64> {
+  let (sin, cos) = angle.sin_cos();
+
+  let dx = Into::<f64>::into(point.x) - Into::<f64>::into(center.x);
+  let dy = Into::<f64>::into(point.y) - Into::<f64>::into(center.y);
+
+  Point2::new(dx * cos + dy * sin, dy * cos - dx * sin)
+}
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::FRAC_PI_2;
+
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+
+  use crate::{Ellipse, Point2, RotatedEllipse};
+
+  #[test_case(0.0, Point2::new(15, 10), Point2::new(5.0, 0.0); "not rotated")]
+  #[test_case(FRAC_PI_2, Point2::new(15, 10), Point2::new(0.0, -5.0); "quarter turn")]
+  #[test_case(FRAC_PI_2, Point2::new(10, 15), Point2::new(5.0, 0.0); "quarter turn, y axis")]
+  fn test_local_point(angle: f64, point: Point2<u8>, expected: Point2<f64>) {
+    let ellipse = RotatedEllipse::<u8, u8>::new(Point2::new(10, 10), (4, 2), angle);
+    let local = ellipse.local_point(&point);
+
+    assert_approx_eq!(f64, local.x, expected.x, epsilon = 1e-9);
+    assert_approx_eq!(f64, local.y, expected.y, epsilon = 1e-9);
+  }
+
+  #[test]
+  fn test_from_ellipse() {
+    let ellipse = RotatedEllipse::from(Ellipse::<u8, u8>::new(Point2::new(10, 10), (4, 2)));
+
+    assert_eq!(ellipse.center(), &Point2::new(10, 10));
+    assert_eq!(ellipse.radius(), &(4, 2));
+    assert_eq!(ellipse.angle(), &0.0);
+  }
+}
@@ -0,0 +1,103 @@
+use derivative::Derivative;
+use getset::Getters;
+
+use crate::shapes::rotated_ellipse::to_local;
+use crate::*;
+
+/// Rectangle rotated counter-clockwise around its center, also known as an oriented bounding box.
+#[cfg_attr(
+  feature = "serde-serialize",
+  derive(serde::Serialize, serde::Deserialize)
+)]
+#[derive(Getters, Derivative)]
+#[derivative(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct RotatedRectangle<T: Scalar> {
+  #[getset(get = "pub")]
+  pub(crate) center: Point2<T>,
+  /// Half of the width and height of the rectangle before rotation.
+  #[getset(get = "pub")]
+  pub(crate) half_size: (T, T),
+  /// Rotation angle in radians, compared and hashed by its bit pattern.
+  #[getset(get = "pub")]
+  #[derivative(
+    Hash(hash_with = "crate::shapes::hash_f64"),
+    PartialEq(compare_with = "crate::shapes::eq_f64")
+  )]
+  pub(crate) angle: f64,
+}
+
+impl<T> RotatedRectangle<T>
+where
+  T: Scalar,
+{
+  #[inline]
+  pub const fn new(center: Point2<T>, half_size: (T, T), angle: f64) -> Self {
+    Self {
+      center,
+      half_size,
+      // Adding zero turns `-0.0` into `0.0`, which would not be equal by bits.
+      angle: angle + 0.0,
+    }
+  }
+}
+
+impl<T> RotatedRectangle<T>
+where
+  T: FloatMath,
+{
+  /// Converts the point into the coordinate system of the rectangle,
+  /// where the rectangle is centered at the origin and axis-aligned.
+  pub(crate) fn local_point(&self, point: &Point2<T>) -> Point2<f64> {
+    to_local(&self.center, self.angle, point)
+  }
+
+  /// Returns the corners of the rectangle, counter-clockwise.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Point2, RotatedRectangle};
+  ///
+  /// let rectangle = RotatedRectangle::new(Point2::new(10.0, 10.0), (2.0, 1.0), 0.0);
+  ///
+  /// assert_eq!(rectangle.corners(), [
+  ///   Point2::new(8.0, 9.0),
+  ///   Point2::new(12.0, 9.0),
+  ///   Point2::new(12.0, 11.0),
+  ///   Point2::new(8.0, 11.0),
+  /// ]);
+  /// ```
+  pub fn corners(&self) -> [Point2<f64>; 4] {
+    let (sin, cos) = self.angle.sin_cos();
+    let center = self.center.map(Into::<f64>::into);
+    let hw: f64 = self.half_size.0.into();
+    let hh: f64 = self.half_size.1.into();
+
+    [(-hw, -hh), (hw, -hh), (hw, hh), (-hw, hh)]
+      .map(|(x, y)| Point2::new(center.x + x * cos - y * sin, center.y + x * sin + y * cos))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::FRAC_PI_2;
+
+  use float_cmp::assert_approx_eq;
+
+  use crate::{Point2, RotatedRectangle};
+
+  #[test]
+  fn test_corners_rotated() {
+    let rectangle = RotatedRectangle::<u8>::new(Point2::new(10, 10), (2, 1), FRAC_PI_2);
+    let expected = [
+      Point2::new(11.0, 8.0),
+      Point2::new(11.0, 12.0),
+      Point2::new(9.0, 12.0),
+      Point2::new(9.0, 8.0),
+    ];
+
+    for (corner, expected) in rectangle.corners().iter().zip(expected.iter()) {
+      assert_approx_eq!(f64, corner.x, expected.x, epsilon = 1e-9);
+      assert_approx_eq!(f64, corner.y, expected.y, epsilon = 1e-9);
+    }
+  }
+}
@@ -1,21 +1,30 @@
-use nalgebra::Scalar;
-use num::Unsigned;
+use alloc::vec::Vec;
+
+use nalgebra::{Point2, Scalar};
+use ordered_float::OrderedFloat;
 
 use super::Shape;
+use crate::traits::Distance;
+use crate::FloatMath;
 use derivative::Derivative;
 use getset::Getters;
 
 #[cfg_attr(
   feature = "serde-serialize",
   derive(serde::Serialize, serde::Deserialize),
-  serde(transparent)
+  serde(
+    transparent,
+    bound(
+      deserialize = "T: crate::FloatMath + serde::Deserialize<'de>, U: serde::Deserialize<'de>"
+    )
+  )
 )]
 #[derive(Derivative, Getters)]
 #[derivative(Debug, Default, Hash, Clone, PartialEq, Eq)]
 pub struct ShapeCollection<T, U>
 where
   T: Scalar,
-  U: Scalar + Unsigned,
+  U: Scalar,
 {
   #[getset(get = "pub")]
   #[derivative(Default(value = "Vec::new()"))]
@@ -25,12 +34,153 @@ where
 impl<T, U> ShapeCollection<T, U>
 where
   T: Scalar,
-  U: Scalar + Unsigned,
+  U: Scalar,
 {
-  pub fn new(geometry: Vec<Shape<T, U>>) -> Self {
+  pub const fn new(geometry: Vec<Shape<T, U>>) -> Self {
     Self { shapes: geometry }
   }
 }
 
+impl<T, U> ShapeCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  /// Returns the members with their indices and distances to the point, closest first.
+  ///
+  /// Members at the same distance are ordered by their index.
+  fn by_distance(&self, point: &Point2<T>) -> Vec<(usize, &Shape<T, U>, f64)> {
+    let mut members = self
+      .shapes
+      .iter()
+      .enumerate()
+      .map(|(index, shape)| (index, shape, shape.distance(point)))
+      .collect::<Vec<_>>();
+
+    members.sort_unstable_by_key(|(index, _, distance)| (OrderedFloat(*distance), *index));
+    members
+  }
+
+  /// Returns up to `k` members closest to the point, with their indices and distances.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Circle, Point2, Rectangle, ShapeCollection};
+  ///
+  /// let zones = ShapeCollection::<u8, u8>::new(vec![
+  ///   Rectangle::new(Point2::new(0, 0), Point2::new(10, 10)).into(),
+  ///   Circle::new(Point2::new(30, 5), 5).into(),
+  ///   Circle::new(Point2::new(50, 5), 5).into(),
+  /// ]);
+  ///
+  /// let nearest = zones.k_nearest(&Point2::new(20, 5), 2);
+  /// assert_eq!(nearest.iter().map(|(index, _, distance)| (*index, *distance)).collect::<Vec<_>>(), vec![
+  ///   (1, 5.0),
+  ///   (0, 10.0),
+  /// ]);
+  /// ```
+  pub fn k_nearest(&self, point: &Point2<T>, k: usize) -> Vec<(usize, &Shape<T, U>, f64)> {
+    let mut members = self.by_distance(point);
+    members.truncate(k);
+    members
+  }
+
+  /// Returns the members at most `radius` away from the point, closest first.
+  pub fn within_radius(&self, point: &Point2<T>, radius: f64) -> Vec<(usize, &Shape<T, U>, f64)> {
+    let mut members = self.by_distance(point);
+    members.retain(|(_, _, distance)| *distance <= radius);
+    members
+  }
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+  use alloc::vec::Vec;
+
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::testing::PointView2;
+  use crate::traits::Distance;
+  use crate::{Circle, Point2, Rectangle, Shape, ShapeCollection};
+
+  fn zones() -> ShapeCollection<u8, u8> {
+    ShapeCollection::new(vec![
+      Circle::new(Point2::new(40, 10), 5).into(),
+      Rectangle::new(Point2::new(0, 0), Point2::new(10, 10)).into(),
+      Circle::new(Point2::new(30, 10), 5).into(),
+      Circle::new(Point2::new(30, 30), 5).into(),
+    ])
+  }
+
+  fn summary(members: Vec<(usize, &Shape<u8, u8>, f64)>) -> Vec<(usize, f64)> {
+    members
+      .into_iter()
+      .map(|(index, _, distance)| (index, distance))
+      .collect()
+  }
+
+  #[test]
+  fn shape_collection_k_nearest() {
+    let zones = zones();
+    let point = Point2::new(20, 10);
+
+    assert_eq!(
+      summary(zones.k_nearest(&point, 3)),
+      vec![(2, 5.0), (1, 10.0), (0, 15.0)]
+    );
+    assert_eq!(summary(zones.k_nearest(&point, 0)), vec![]);
+    assert_eq!(zones.k_nearest(&point, 10).len(), 4);
+    assert_eq!(zones.k_nearest(&point, 1)[0].1, &zones.shapes()[2]);
+  }
+
+  #[test]
+  fn shape_collection_k_nearest_ties_by_index() {
+    let zones = zones();
+
+    // Halfway between the circles at x = 30.
+    assert_eq!(
+      summary(zones.k_nearest(&Point2::new(30, 20), 2)),
+      vec![(2, 5.0), (3, 5.0)]
+    );
+  }
+
+  #[test]
+  fn shape_collection_within_radius() {
+    let zones = zones();
+    let point = Point2::new(20, 10);
+
+    assert_eq!(
+      summary(zones.within_radius(&point, 10.0)),
+      vec![(2, 5.0), (1, 10.0)]
+    );
+    assert_eq!(summary(zones.within_radius(&point, 1.0)), vec![]);
+    assert_eq!(
+      summary(ShapeCollection::<u8, u8>::new(vec![]).within_radius(&point, 100.0)),
+      vec![]
+    );
+  }
+
+  #[proptest]
+  fn shape_collection_k_nearest_u8_matches_distance(
+    #[strategy(prop::collection::vec(any::<ShapeView<u8, u8>>(), 1..16))] shapes: Vec<
+      ShapeView<u8, u8>,
+    >,
+    point: PointView2<u8>,
+  ) {
+    let zones = ShapeCollection::new(shapes.into_iter().map(Shape::from).collect());
+    let point = Point2::from(point);
+    let nearest = zones.k_nearest(&point, zones.shapes().len());
+
+    assert_eq!(nearest.len(), zones.shapes().len());
+    assert_eq!(nearest[0].2, zones.distance(&point));
+    for pair in nearest.windows(2) {
+      assert!(pair[0].2 <= pair[1].2);
+    }
+    for (index, shape, distance) in nearest {
+      assert_eq!(shape, &zones.shapes()[index]);
+      assert_eq!(distance, shape.distance(&point));
+    }
+  }
+}
@@ -0,0 +1,167 @@
+use derivative::Derivative;
+use nalgebra::Scalar;
+
+use super::{FixedShapeCollection, Shape, ShapeCollection};
+
+/// Collection of shapes borrowed from a slice, for shape tables in `static` items.
+///
+/// Behaves like a [`ShapeCollection`], but can be created in constant expressions. Every
+/// shape except polygons can be built in a constant, since polygons keep their points in
+/// a `Vec`.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, Rectangle, Shape, SliceShapeCollection, traits::Within};
+///
+/// static ZONES: [Shape<u8, u8>; 2] = [
+///   Shape::Rectangle(Rectangle::<u8>::new_const(Point2::new(0, 0), Point2::new(10, 10))),
+///   Shape::Circle(Circle::new(Point2::new(20, 20), 5)),
+/// ];
+/// static LAYOUT: SliceShapeCollection<u8, u8> = SliceShapeCollection::new(&ZONES);
+///
+/// assert!(LAYOUT.within(&Point2::new(22, 22)));
+/// assert!(!LAYOUT.within(&Point2::new(15, 15)));
+/// ```
+#[derive(Derivative)]
+#[derivative(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct SliceShapeCollection<'a, T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  shapes: &'a [Shape<T, U>],
+}
+
+impl<'a, T, U> SliceShapeCollection<'a, T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  #[inline]
+  pub const fn new(shapes: &'a [Shape<T, U>]) -> Self {
+    Self { shapes }
+  }
+
+  #[inline]
+  pub const fn shapes(&self) -> &'a [Shape<T, U>] {
+    self.shapes
+  }
+}
+
+impl<'a, T, U> From<&'a [Shape<T, U>]> for SliceShapeCollection<'a, T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn from(shapes: &'a [Shape<T, U>]) -> Self {
+    Self::new(shapes)
+  }
+}
+
+impl<'a, T, U> From<&'a ShapeCollection<T, U>> for SliceShapeCollection<'a, T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn from(collection: &'a ShapeCollection<T, U>) -> Self {
+    Self::new(&collection.shapes)
+  }
+}
+
+impl<'a, T, U, const N: usize> From<&'a FixedShapeCollection<T, U, N>>
+  for SliceShapeCollection<'a, T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn from(collection: &'a FixedShapeCollection<T, U, N>) -> Self {
+    Self::new(collection.shapes())
+  }
+}
+
+impl<T, U> From<SliceShapeCollection<'_, T, U>> for ShapeCollection<T, U>
+where
+  T: Scalar,
+  U: Scalar,
+{
+  fn from(collection: SliceShapeCollection<'_, T, U>) -> Self {
+    Self::new(collection.shapes.to_vec())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use proptest::prelude::*;
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::testing::PointView2;
+  use crate::traits::{BoundingBox, Centroid, Distance, PointsInside, Within};
+  use crate::{
+    Circle, Ellipse, FixedShapeCollection, Point2, Rectangle, RotatedEllipse, RotatedRectangle,
+    Shape, ShapeCollection, SliceShapeCollection, Triangle,
+  };
+
+  static TABLE: [Shape<u8, u8>; 6] = [
+    Shape::Rectangle(Rectangle::<u8>::new_const(
+      Point2::new(10, 10),
+      Point2::new(0, 0),
+    )),
+    Shape::RotatedRectangle(RotatedRectangle::new(Point2::new(50, 50), (5, 2), 1.0)),
+    Shape::Circle(Circle::new(Point2::new(20, 20), 5)),
+    Shape::Ellipse(Ellipse::new(Point2::new(80, 20), (6, 3))),
+    Shape::RotatedEllipse(RotatedEllipse::new(Point2::new(80, 80), (6, 3), 0.5)),
+    Shape::Triangle(Triangle::new(
+      Point2::new(0, 100),
+      Point2::new(20, 100),
+      Point2::new(0, 120),
+    )),
+  ];
+
+  static TABLE_COLLECTION: SliceShapeCollection<u8, u8> = SliceShapeCollection::new(&TABLE);
+
+  #[test]
+  fn static_table_matches_shape_collection() {
+    let collection = ShapeCollection::new(TABLE.to_vec());
+
+    for point in [[5, 5], [20, 24], [80, 22], [5, 105], [15, 15], [200, 200]] {
+      let point = Point2::from(point);
+      assert_eq!(TABLE_COLLECTION.within(&point), collection.within(&point));
+      assert_eq!(
+        TABLE_COLLECTION.distance(&point),
+        collection.distance(&point)
+      );
+    }
+    assert_eq!(TABLE_COLLECTION.bbox(), collection.bbox());
+    assert_eq!(TABLE_COLLECTION.centroid(), collection.centroid());
+    assert_eq!(TABLE_COLLECTION.points_inside(), collection.points_inside());
+    assert_eq!(ShapeCollection::from(TABLE_COLLECTION), collection);
+  }
+
+  /// Every collection type goes through the slice, so they all have to agree.
+  #[proptest(ProptestConfig::with_cases(32))]
+  fn shape_collections_u8_agree(
+    #[strategy(prop::collection::vec(any::<ShapeView<u8, u8>>(), 0..=4))] shapes: Vec<
+      ShapeView<u8, u8>,
+    >,
+    point: PointView2<u8>,
+  ) {
+    let collection = ShapeCollection::new(shapes.into_iter().map(Shape::from).collect());
+    let fixed = FixedShapeCollection::<u8, u8, 4>::try_from(collection.clone()).unwrap();
+    let slice = SliceShapeCollection::from(&collection);
+    let point = Point2::from(point);
+
+    assert_eq!(fixed.within(&point), slice.within(&point));
+    assert_eq!(collection.within(&point), slice.within(&point));
+    assert_eq!(fixed.distance(&point), slice.distance(&point));
+    assert_eq!(collection.distance(&point), slice.distance(&point));
+    assert_eq!(fixed.bbox(), slice.bbox());
+    assert_eq!(collection.bbox(), slice.bbox());
+    assert_eq!(fixed.centroid(), slice.centroid());
+    assert_eq!(collection.centroid(), slice.centroid());
+    assert_eq!(fixed.points_inside(), slice.points_inside());
+    assert_eq!(collection.points_inside(), slice.points_inside());
+  }
+}
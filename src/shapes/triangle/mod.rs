@@ -15,7 +15,7 @@ where
   T: Scalar,
 {
   #[inline]
-  pub fn new(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Self {
+  pub const fn new(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Self {
     Self(a, b, c)
   }
 }
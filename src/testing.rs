@@ -1,3 +1,7 @@
+use alloc::vec::Vec;
+
+use crate::{Circle, Ellipse, Rectangle, Shape, ShapeCollection, Triangle};
+
 #[cfg(feature = "proptest-support")]
 pub use crate::proptest::*;
 
@@ -26,3 +30,24 @@ macro_rules! assert_vec_eq_unordered {
     assert_eq!($left.len(), $right.len());
   };
 }
+
+/// Shorthand constructors for the `Shape<i32, u32>` operands of the shape-pair tests.
+pub fn rectangle(min: [i32; 2], max: [i32; 2]) -> Shape<i32, u32> {
+  Rectangle::new(min.into(), max.into()).into()
+}
+
+pub fn circle(center: [i32; 2], radius: u32) -> Shape<i32, u32> {
+  Circle::new(center.into(), radius).into()
+}
+
+pub fn ellipse(center: [i32; 2], radius: (u32, u32)) -> Shape<i32, u32> {
+  Ellipse::new(center.into(), radius).into()
+}
+
+pub fn triangle(a: [i32; 2], b: [i32; 2], c: [i32; 2]) -> Shape<i32, u32> {
+  Triangle::new(a.into(), b.into(), c.into()).into()
+}
+
+pub fn collection(shapes: Vec<Shape<i32, u32>>) -> Shape<i32, u32> {
+  ShapeCollection::new(shapes).into()
+}
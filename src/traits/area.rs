@@ -0,0 +1,167 @@
+use crate::primitive;
+use crate::*;
+
+/// Generic trait to calculate the area covered by a shape.
+///
+/// Overlapping members of collections are only counted once, and holes are left out. Shapes
+/// without any area, like lines, have an area of 0.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Point2, Rectangle, ShapeCollection, traits::Area};
+///
+/// let zone = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(10, 10));
+/// let zones = ShapeCollection::<u8, u8>::new(vec![
+///   zone.into(),
+///   Rectangle::new(Point2::new(5, 0), Point2::new(15, 10)).into(),
+/// ]);
+///
+/// assert_eq!(zone.area(), 100.0);
+/// assert_eq!(zones.area(), 150.0);
+/// ```
+pub trait Area {
+  fn area(&self) -> f64;
+}
+
+impl<U: Area> Area for &U {
+  fn area(&self) -> f64 {
+    U::area(*self)
+  }
+}
+
+macro_rules! impl_area {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<$($generic,)*> Area for $shape
+    where
+      $($generic: FloatMath,)*
+    {
+      fn area(&self) -> f64 {
+        primitive::area(self)
+      }
+    }
+  )*};
+}
+
+impl_area!(
+  Line<T> => [T];
+  Rectangle<T> => [T];
+  RotatedRectangle<T> => [T];
+  Circle<T, R> => [T, R];
+  Ellipse<T, R> => [T, R];
+  RotatedEllipse<T, R> => [T, R];
+  Triangle<T> => [T];
+  Polygon<T> => [T];
+  PolygonWithHoles<T> => [T];
+  ShapeCollection<T, R> => [T, R];
+  Shape<T, R> => [T, R];
+);
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::PI;
+
+  use float_cmp::assert_approx_eq;
+  use proptest::prelude::ProptestConfig;
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use super::Area;
+  use crate::proptest::ShapeView;
+  use crate::testing::{circle, collection, ellipse, rectangle, triangle};
+  use crate::{
+    Circle, FillRule, Line, Point2, Polygon, PolygonWithHoles, RotatedRectangle, Shape,
+    ShapeCollection,
+  };
+
+  #[test_case(rectangle([0, 0], [10, 20]) => 200.0; "rectangle")]
+  #[test_case(rectangle([0, 0], [10, 0]) => 0.0; "flat rectangle")]
+  #[test_case(circle([0, 0], 10) => 314.159265359; "circle")]
+  #[test_case(ellipse([0, 0], (10, 2)) => 62.831853072; "ellipse")]
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]) => 50.0; "triangle")]
+  #[test_case(triangle([0, 0], [0, 10], [10, 0]) => 50.0; "clockwise triangle")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([20, 0], [30, 10])]) => 200.0; "collection apart")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10])]) => 200.0; "collection touching")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([5, 5], [15, 15])]) => 175.0; "collection overlapping")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([0, 0], [10, 10])]) => 100.0; "collection identical")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), triangle([0, 0], [10, 0], [0, 10])]) => 100.0; "collection inside")]
+  #[test_case(collection(vec![circle([0, 0], 10), circle([100, 0], 5)]) => 392.699081699; "collection of circles apart")]
+  #[test_case(collection(vec![]) => 0.0; "empty collection")]
+  fn shape_area(shape: Shape<i32, u32>) -> f64 {
+    (shape.area() * 1e9).round() / 1e9
+  }
+
+  #[test]
+  fn line_area() {
+    assert_eq!(
+      Line::new(Point2::new(0, 0), Point2::new(10, 10)).area(),
+      0.0
+    );
+  }
+
+  #[test]
+  fn rotated_rectangle_area() {
+    let rectangle = RotatedRectangle::new(Point2::new(0.0, 0.0), (2.0, 1.0), 0.3);
+
+    assert_approx_eq!(f64, rectangle.area(), 8.0, epsilon = 1e-9);
+  }
+
+  #[test]
+  fn polygon_area_follows_fill_rule() {
+    // A square with a second, overlapping lap around its upper half.
+    let points = [
+      [0, 0],
+      [10, 0],
+      [10, 10],
+      [0, 10],
+      [0, 5],
+      [10, 5],
+      [10, 10],
+      [0, 10],
+    ];
+    let polygon = Polygon::new(points.iter().copied().map(Point2::from).collect());
+
+    assert_eq!(polygon.area(), 50.0);
+    assert_eq!(polygon.with_fill_rule(FillRule::NonZero).area(), 100.0);
+  }
+
+  #[test]
+  fn polygon_with_holes_area() {
+    let square = |min: i32, max: i32| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let polygon = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+
+    assert_eq!(polygon.area(), 800.0);
+  }
+
+  #[test]
+  fn overlapping_circles_area() {
+    // Two unit circles with their centers one radius apart.
+    let lens = 2.0 * PI / 3.0 - 3.0_f64.sqrt() / 2.0;
+    let circles = ShapeCollection::<f64, f64>::new(vec![
+      Circle::new(Point2::new(0.0, 0.0), 1.0).into(),
+      Circle::new(Point2::new(1.0, 0.0), 1.0).into(),
+    ]);
+
+    assert_approx_eq!(f64, circles.area(), 2.0 * PI - lens, epsilon = 1e-3);
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn collection_area_u8(a: ShapeView<u8, u8>, b: ShapeView<u8, u8>) {
+    let (a, b) = (Shape::from(a), Shape::from(b));
+    let (area_a, area_b) = (a.area(), b.area());
+    let tolerance = 1e-3 * (1.0 + area_a + area_b);
+
+    let union = ShapeCollection::new(vec![a.clone(), b]).area();
+    assert!(union >= area_a.max(area_b) - tolerance, "{union}");
+    assert!(union <= area_a + area_b + tolerance, "{union}");
+
+    let twice = ShapeCollection::new(vec![a.clone(), a]).area();
+    assert_approx_eq!(f64, twice, area_a, epsilon = tolerance);
+  }
+}
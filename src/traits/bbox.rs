@@ -1,6 +1,5 @@
 use crate::*;
-use nalgebra::{Scalar, Vector2};
-use num::Num;
+use nalgebra::Scalar;
 
 pub trait BoundingBox<T: Scalar> {
   fn bbox(&self) -> Rectangle<T>;
@@ -12,90 +11,277 @@ impl<T: Scalar, U: BoundingBox<T>> BoundingBox<T> for &U {
   }
 }
 
-impl BoundingBox<u8> for Shape<u8, u8> {
-  fn bbox(&self) -> Rectangle<u8> {
+#[inline]
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+  if b < a {
+    b
+  } else {
+    a
+  }
+}
+
+#[inline]
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+  if b > a {
+    b
+  } else {
+    a
+  }
+}
+
+impl<T, U> BoundingBox<T> for Shape<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
     match self {
       Self::Ellipse(ellipse) => ellipse.bbox(),
       Self::Circle(circle) => circle.bbox(),
       Self::Rectangle(rectangle) => rectangle.bbox(),
+      Self::RotatedRectangle(rectangle) => rectangle.bbox(),
+      Self::RotatedEllipse(ellipse) => ellipse.bbox(),
       Self::Triangle(triangle) => triangle.bbox(),
+      Self::Polygon(polygon) => polygon.bbox(),
+      Self::PolygonWithHoles(polygon) => polygon.bbox(),
       Self::Collection(collection) => collection.bbox(),
     }
   }
 }
 
-impl BoundingBox<u8> for Circle<u8, u8> {
-  fn bbox(&self) -> Rectangle<u8> {
-    let radius = self.radius as i16;
-    let center = self.center.map(|x| x as i16);
+impl<T, R> BoundingBox<T> for Circle<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    let radius: f64 = self.radius.into();
 
-    let min = center - Vector2::new(radius, radius);
-    let max = center + Vector2::new(radius, radius);
+    centered_bbox(&self.center, radius, radius)
+  }
+}
 
-    Rectangle::new(
-      min.map(|x| x.clamp(0, u8::MAX as i16) as u8),
-      max.map(|x| x.clamp(0, u8::MAX as i16) as u8),
-    )
+impl<T, R> BoundingBox<T> for Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    centered_bbox(&self.center, self.radius.0.into(), self.radius.1.into())
   }
 }
 
-impl BoundingBox<u8> for Ellipse<u8, u8> {
-  fn bbox(&self) -> Rectangle<u8> {
-    let x = self.center.x as f64 - self.radius.0 as f64;
-    let y = self.center.y as f64 - self.radius.1 as f64;
-    let width = self.radius.0 as f64 * 2.0;
-    let height = self.radius.1 as f64 * 2.0;
+impl<T, R> BoundingBox<T> for RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  /// Returns the tightest axis-aligned box around the rotated ellipse.
+  fn bbox(&self) -> Rectangle<T> {
+    let (sin, cos) = snapped_sin_cos(self.angle);
+    let rx: f64 = self.radius.0.into();
+    let ry: f64 = self.radius.1.into();
 
-    Rectangle::new(
-      Point2::new(x, y).map(|c| c as u8),
-      Point2::new(x + width, y + height).map(|c| c as u8),
-    )
+    let half_width = ((rx * cos).powi(2) + (ry * sin).powi(2)).sqrt();
+    let half_height = ((rx * sin).powi(2) + (ry * cos).powi(2)).sqrt();
+
+    centered_bbox(&self.center, half_width, half_height)
   }
 }
 
+impl<T> BoundingBox<T> for RotatedRectangle<T>
+where
+  T: FloatMath,
+{
+  /// Returns the tightest axis-aligned box around the rotated rectangle.
+  fn bbox(&self) -> Rectangle<T> {
+    let (sin, cos) = snapped_sin_cos(self.angle);
+    let hw: f64 = self.half_size.0.into();
+    let hh: f64 = self.half_size.1.into();
+
+    let half_width = hw * cos.abs() + hh * sin.abs();
+    let half_height = hw * sin.abs() + hh * cos.abs();
+
+    centered_bbox(&self.center, half_width, half_height)
+  }
+}
+
+/// Returns the sine and cosine of the angle, snapping values that are almost zero.
+///
+/// Otherwise rounding errors (e.g. `cos(PI / 2)` not being exactly zero) would grow
+/// boxes with integer coordinates by a whole unit.
+fn snapped_sin_cos(angle: f64) -> (f64, f64) {
+  const TOLERANCE: f64 = 1e-12;
+
+  let snap = |value: f64| if value.abs() < TOLERANCE { 0.0 } else { value };
+  let (sin, cos) = angle.sin_cos();
+
+  (snap(sin), snap(cos))
+}
+
+/// Builds a box around the center, rounding outwards for integer coordinates.
+fn centered_bbox<T: FloatMath>(
+  center: &Point2<T>,
+  half_width: f64,
+  half_height: f64,
+) -> Rectangle<T> {
+  let center = center.map(Into::<f64>::into);
+
+  Rectangle::new(
+    Point2::new(
+      T::floor_from_f64(center.x - half_width),
+      T::floor_from_f64(center.y - half_height),
+    ),
+    Point2::new(
+      T::ceil_from_f64(center.x + half_width),
+      T::ceil_from_f64(center.y + half_height),
+    ),
+  )
+}
+
 impl<T> BoundingBox<T> for Rectangle<T>
 where
-  T: Scalar + Num,
+  T: Scalar,
 {
   fn bbox(&self) -> Rectangle<T> {
     self.clone()
   }
 }
 
-impl BoundingBox<u8> for Triangle<u8> {
-  fn bbox(&self) -> Rectangle<u8> {
-    let min_x = self.0.x.min(self.1.x).min(self.2.x);
-    let min_y = self.0.y.min(self.1.y).min(self.2.y);
-    let max_x = self.0.x.max(self.1.x).max(self.2.x);
-    let max_y = self.0.y.max(self.1.y).max(self.2.y);
+impl<T> BoundingBox<T> for Triangle<T>
+where
+  T: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    let min_x = min(min(self.0.x, self.1.x), self.2.x);
+    let min_y = min(min(self.0.y, self.1.y), self.2.y);
+    let max_x = max(max(self.0.x, self.1.x), self.2.x);
+    let max_y = max(max(self.0.y, self.1.y), self.2.y);
 
     Rectangle::new(Point2::new(min_x, min_y), Point2::new(max_x, max_y))
   }
 }
 
-impl BoundingBox<u8> for ShapeCollection<u8, u8> {
-  fn bbox(&self) -> Rectangle<u8> {
-    let bboxes = self.shapes.iter().map(|x| x.bbox());
+impl<T> BoundingBox<T> for Polygon<T>
+where
+  T: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    let mut min_point = Point2::new(T::max_value(), T::max_value());
+    let mut max_point = Point2::new(T::min_value(), T::min_value());
+
+    for point in &self.points {
+      min_point = Point2::new(min(min_point.x, point.x), min(min_point.y, point.y));
+      max_point = Point2::new(max(max_point.x, point.x), max(max_point.y, point.y));
+    }
+
+    Rectangle::new(min_point, max_point)
+  }
+}
+
+impl<T> BoundingBox<T> for PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    self.exterior.bbox()
+  }
+}
+
+impl<T, U> BoundingBox<T> for ShapeCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    SliceShapeCollection::from(self).bbox()
+  }
+}
+
+impl<T, U, const N: usize> BoundingBox<T> for FixedShapeCollection<T, U, N>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    SliceShapeCollection::from(self).bbox()
+  }
+}
 
-    let mut min = Point2::new(u8::MAX, u8::MAX);
-    let mut max = Point2::new(u8::MIN, u8::MIN);
+impl<T, U> BoundingBox<T> for SliceShapeCollection<'_, T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn bbox(&self) -> Rectangle<T> {
+    let mut min_point = Point2::new(T::max_value(), T::max_value());
+    let mut max_point = Point2::new(T::min_value(), T::min_value());
 
-    for bbox in bboxes {
-      min = Point2::new(min.x.min(bbox.min().x), min.y.min(bbox.min().y));
-      max = Point2::new(max.x.max(bbox.max().x), max.y.max(bbox.max().y));
+    for bbox in self.shapes().iter().map(|x| x.bbox()) {
+      min_point = Point2::new(
+        min(min_point.x, bbox.min().x),
+        min(min_point.y, bbox.min().y),
+      );
+      max_point = Point2::new(
+        max(max_point.x, bbox.max().x),
+        max(max_point.y, bbox.max().y),
+      );
     }
 
-    Rectangle::new(min, max)
+    Rectangle::new(min_point, max_point)
+  }
+}
+
+impl<T, U> BoundingBox<T> for Csg<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  /// Returns a box around the expression, which is not always the tightest one.
+  ///
+  /// Boxes of intersections are intersected, and are [empty](Rectangle::is_empty) if they
+  /// are disjoint. Differences keep the box of the first operand.
+  fn bbox(&self) -> Rectangle<T> {
+    match self {
+      Self::Shape(shape) => shape.bbox(),
+      Self::Union(a, b) | Self::SymmetricDifference(a, b) => {
+        let (a, b) = (a.bbox(), b.bbox());
+        if a.is_empty() {
+          return b;
+        } else if b.is_empty() {
+          return a;
+        }
+
+        Rectangle::new(
+          Point2::new(min(a.min().x, b.min().x), min(a.min().y, b.min().y)),
+          Point2::new(max(a.max().x, b.max().x), max(a.max().y, b.max().y)),
+        )
+      }
+      Self::Intersection(a, b) => {
+        let (a, b) = (a.bbox(), b.bbox());
+
+        Rectangle::new_unchecked(
+          Point2::new(max(a.min().x, b.min().x), max(a.min().y, b.min().y)),
+          Point2::new(min(a.max().x, b.max().x), min(a.max().y, b.max().y)),
+        )
+      }
+      Self::Difference(a, _) => a.bbox(),
+    }
   }
 }
 
 #[cfg(test)]
 mod tests {
+  use alloc::vec::Vec;
+  use core::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, FRAC_PI_4, PI};
+
+  use float_cmp::assert_approx_eq;
   use test_case::test_case;
   use test_strategy::proptest;
 
   use crate::{
-    traits::BoundingBox, Circle, Ellipse, Point2, Rectangle, Shape, ShapeCollection, Triangle,
+    traits::BoundingBox, Circle, Csg, Ellipse, Point2, Polygon, PolygonWithHoles, Rectangle,
+    RotatedEllipse, RotatedRectangle, Shape, ShapeCollection, Triangle,
   };
 
   #[test_case(Circle::new(Point2::new(12,  12 ), 10), Point2::new(2, 2  ), Point2::new(22, 22 ); "normal")]
@@ -115,6 +301,52 @@ mod tests {
     let _out = circle.bbox();
   }
 
+  #[test]
+  fn fractional_radius_bbox_rounds_outwards() {
+    let circle = Circle::<u8, f32>::new(Point2::new(10, 10), 2.5);
+    assert_eq!(
+      circle.bbox(),
+      Rectangle::new(Point2::new(7, 7), Point2::new(13, 13))
+    );
+
+    let ellipse = Ellipse::<u8, f32>::new(Point2::new(10, 10), (1.3, 0.5));
+    assert_eq!(
+      ellipse.bbox(),
+      Rectangle::new(Point2::new(8, 9), Point2::new(12, 11))
+    );
+  }
+
+  #[test_case(Circle::new(Point2::new(0.1, 0.1), 0.2); "small")]
+  #[test_case(Circle::new(Point2::new(0.3, 1.1), 0.7); "mixed")]
+  #[test_case(Circle::new(Point2::new(-12.7, 3.3), 5.9); "negative")]
+  fn circle_bbox_f32_rounds_outwards(circle: Circle<f32, f32>) {
+    let bbox = circle.bbox();
+    let radius = f64::from(circle.radius);
+
+    assert!(f64::from(bbox.min().x) <= f64::from(circle.center.x) - radius);
+    assert!(f64::from(bbox.min().y) <= f64::from(circle.center.y) - radius);
+    assert!(f64::from(bbox.max().x) >= f64::from(circle.center.x) + radius);
+    assert!(f64::from(bbox.max().y) >= f64::from(circle.center.y) + radius);
+  }
+
+  #[test_case(Circle::new(Point2::new(0.5, 0.5), 0.25), Point2::new(0.25, 0.25), Point2::new(0.75, 0.75); "normal")]
+  #[test_case(Circle::new(Point2::new(0.0, 0.0), 0.25), Point2::new(-0.25, -0.25), Point2::new(0.25, 0.25); "negative")]
+  fn circle_bbox_f32(circle: Circle<f32, f32>, min: Point2<f32>, max: Point2<f32>) {
+    let bbox = circle.bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
+  #[test_case(Circle::new(Point2::new(0, 0), 10), Point2::new(-10, -10), Point2::new(10, 10); "negative")]
+  #[test_case(Circle::new(Point2::new(i16::MAX, i16::MIN), 10), Point2::new(i16::MAX - 10, i16::MIN), Point2::new(i16::MAX, i16::MIN + 10); "edge/top+end")]
+  fn circle_bbox_i16(circle: Circle<i16, u16>, min: Point2<i16>, max: Point2<i16>) {
+    let bbox = circle.bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
   #[test_case(Ellipse::new(Point2::new(12, 12), (10, 10)), Point2::new(2, 2), Point2::new(22, 22); "normal")]
   #[test_case(Ellipse::new(Point2::new(0, 0), (10, 10)), Point2::new(0, 0), Point2::new(10, 10); "edge/top+start")]
   #[test_case(Ellipse::new(Point2::new(255, 0), (10, 10)), Point2::new(245, 0), Point2::new(255, 10); "edge/top+end")]
@@ -133,6 +365,66 @@ mod tests {
     let _out = ellipse.bbox();
   }
 
+  #[test_case(Ellipse::new(Point2::new(0.5, 0.5), (0.25, 0.125)), Point2::new(0.25, 0.375), Point2::new(0.75, 0.625); "normal")]
+  fn ellipse_bbox_f64(ellipse: Ellipse<f64, f64>, min: Point2<f64>, max: Point2<f64>) {
+    let bbox = ellipse.bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
+  #[test_case(RotatedEllipse::new(Point2::new(20, 20), (10, 4), 0.0), Point2::new(10, 16), Point2::new(30, 24); "not rotated")]
+  #[test_case(RotatedEllipse::new(Point2::new(20, 20), (10, 4), FRAC_PI_2), Point2::new(16, 10), Point2::new(24, 30); "quarter turn")]
+  #[test_case(RotatedEllipse::new(Point2::new(20, 20), (10, 4), FRAC_PI_4), Point2::new(12, 12), Point2::new(28, 28); "diagonal")]
+  #[test_case(RotatedEllipse::new(Point2::new(2, 250), (10, 4), FRAC_PI_4), Point2::new(0, 242), Point2::new(10, 255); "edge")]
+  fn rotated_ellipse_bbox_u8(ellipse: RotatedEllipse<u8, u8>, min: Point2<u8>, max: Point2<u8>) {
+    let bbox = ellipse.bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
+  #[proptest]
+  fn rotated_ellipse_bbox_u8_fuzz(ellipse: RotatedEllipse<u8, u8>) {
+    let _out = ellipse.bbox();
+  }
+
+  #[test]
+  fn rotated_ellipse_bbox_f64() {
+    let bbox = RotatedEllipse::new(Point2::new(0.0, 0.0), (2.0, 1.0), FRAC_PI_4).bbox();
+    let extent = 2.5_f64.sqrt();
+
+    assert_approx_eq!(f64, bbox.min().x, -extent, epsilon = 1e-9);
+    assert_approx_eq!(f64, bbox.min().y, -extent, epsilon = 1e-9);
+    assert_approx_eq!(f64, bbox.max().x, extent, epsilon = 1e-9);
+    assert_approx_eq!(f64, bbox.max().y, extent, epsilon = 1e-9);
+  }
+
+  #[test_case(RotatedRectangle::new(Point2::new(20, 20), (10, 4), 0.0), Point2::new(10, 16), Point2::new(30, 24); "not rotated")]
+  #[test_case(RotatedRectangle::new(Point2::new(20, 20), (10, 4), FRAC_PI_2), Point2::new(16, 10), Point2::new(24, 30); "quarter turn")]
+  #[test_case(RotatedRectangle::new(Point2::new(20, 20), (10, 4), PI), Point2::new(10, 16), Point2::new(30, 24); "half turn")]
+  #[test_case(RotatedRectangle::new(Point2::new(20, 20), (10, 4), FRAC_PI_4), Point2::new(10, 10), Point2::new(30, 30); "diagonal")]
+  fn rotated_rectangle_bbox_u8(rectangle: RotatedRectangle<u8>, min: Point2<u8>, max: Point2<u8>) {
+    let bbox = rectangle.bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
+  #[proptest]
+  fn rotated_rectangle_bbox_u8_fuzz(rectangle: RotatedRectangle<u8>) {
+    let _out = rectangle.bbox();
+  }
+
+  #[test]
+  fn rotated_rectangle_bbox_f64() {
+    let bbox = RotatedRectangle::new(Point2::new(0.0, 0.0), (2.0, 1.0), FRAC_PI_4).bbox();
+    let extent = 3.0 * FRAC_1_SQRT_2;
+
+    assert_approx_eq!(f64, bbox.min().x, -extent, epsilon = 1e-9);
+    assert_approx_eq!(f64, bbox.max().y, extent, epsilon = 1e-9);
+  }
+
   #[proptest]
   fn rectangle_bbox_u8(rectangle: Rectangle<u8>) {
     assert_eq!(rectangle.bbox(), rectangle);
@@ -151,9 +443,58 @@ mod tests {
     let _bbox = triangle.bbox();
   }
 
+  #[test_case(Triangle::new([0.5, 0.0].into(), [1.0, 0.75].into(), [0.0, 1.0].into()), Point2::new(0.0, 0.0), Point2::new(1.0, 1.0); "normal")]
+  #[test_case(Triangle::new([-1.0, 2.0].into(), [-3.0, 0.5].into(), [0.0, -1.0].into()), Point2::new(-3.0, -1.0), Point2::new(0.0, 2.0); "negative")]
+  fn triangle_bbox_f32(triangle: Triangle<f32>, min: Point2<f32>, max: Point2<f32>) {
+    let bbox = triangle.bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
+  #[test_case(vec![[10, 20].into(), [30, 5].into(), [25, 40].into(), [0, 15].into()], Point2::new(0, 5), Point2::new(30, 40); "normal")]
+  #[test_case(vec![[10, 20].into()], Point2::new(10, 20), Point2::new(10, 20); "single point")]
+  fn polygon_bbox_u8(points: Vec<Point2<u8>>, min: Point2<u8>, max: Point2<u8>) {
+    let bbox = Polygon::new(points).bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
+  #[test_case(vec![[0.5, 0.25].into(), [-0.5, 0.75].into(), [0.25, -0.25].into()], Point2::new(-0.5, -0.25), Point2::new(0.5, 0.75); "normal")]
+  fn polygon_bbox_f32(points: Vec<Point2<f32>>, min: Point2<f32>, max: Point2<f32>) {
+    let bbox = Polygon::new(points).bbox();
+
+    assert_eq!(bbox.min(), &min);
+    assert_eq!(bbox.max(), &max);
+  }
+
+  #[proptest]
+  fn polygon_bbox_u8_fuzz(polygon: Polygon<u8>) {
+    let _bbox = polygon.bbox();
+  }
+
+  #[test]
+  fn polygon_with_holes_bbox_u8() {
+    let polygon = PolygonWithHoles::new(
+      Polygon::new(vec![[10, 20].into(), [30, 5].into(), [25, 40].into()]),
+      vec![Polygon::new(vec![
+        [20, 15].into(),
+        [25, 15].into(),
+        [25, 20].into(),
+      ])],
+    )
+    .unwrap();
+
+    let bbox = polygon.bbox();
+
+    assert_eq!(bbox.min(), &Point2::new(10, 5));
+    assert_eq!(bbox.max(), &Point2::new(30, 40));
+  }
+
   #[test]
   fn shape_collection_bbox_u8() {
-    let collection = ShapeCollection::new(vec![
+    let collection = ShapeCollection::<u8, u8>::new(vec![
       Shape::Rectangle(Rectangle::new(Point2::new(0, 0), Point2::new(2, 2))),
       Shape::Rectangle(Rectangle::new(Point2::new(2, 2), Point2::new(4, 4))),
       Shape::Rectangle(Rectangle::new(Point2::new(4, 4), Point2::new(6, 6))),
@@ -164,4 +505,51 @@ mod tests {
     assert_eq!(bbox.min(), &Point2::new(0, 0));
     assert_eq!(bbox.max(), &Point2::new(6, 6));
   }
+
+  #[test]
+  fn shape_collection_bbox_f32() {
+    let collection = ShapeCollection::<f32, f32>::new(vec![
+      Shape::Rectangle(Rectangle::new(
+        Point2::new(0.25, 0.25),
+        Point2::new(0.5, 0.5),
+      )),
+      Shape::Circle(Circle::new(Point2::new(-0.5, 0.5), 0.25)),
+    ]);
+
+    let bbox = collection.bbox();
+
+    assert_eq!(bbox.min(), &Point2::new(-0.75, 0.25));
+    assert_eq!(bbox.max(), &Point2::new(0.5, 0.75));
+  }
+
+  #[test]
+  fn csg_bbox_u8() {
+    let circle = Circle::<u8, u8>::new(Point2::new(10, 10), 5);
+    let rectangle = Rectangle::new(Point2::new(12, 0), Point2::new(30, 12));
+    let far = Rectangle::new(Point2::new(40, 40), Point2::new(50, 50));
+    let bbox = |csg: Csg<u8, u8>| (*csg.bbox().min(), *csg.bbox().max());
+
+    assert_eq!(
+      bbox(Csg::from(circle).union(rectangle)),
+      (Point2::new(5, 0), Point2::new(30, 15))
+    );
+    assert_eq!(
+      bbox(Csg::from(circle).intersection(rectangle)),
+      (Point2::new(12, 5), Point2::new(15, 12))
+    );
+    assert!(Csg::from(circle).intersection(far).bbox().is_empty());
+    assert_eq!(
+      bbox(Csg::from(circle).intersection(far).union(rectangle)),
+      (Point2::new(12, 0), Point2::new(30, 12))
+    );
+    assert!(Csg::from(circle)
+      .intersection(far)
+      .intersection(rectangle)
+      .bbox()
+      .is_empty());
+    assert_eq!(
+      bbox(Csg::from(circle).difference(rectangle)),
+      (Point2::new(5, 5), Point2::new(15, 15))
+    );
+  }
 }
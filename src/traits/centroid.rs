@@ -1,6 +1,5 @@
 use crate::*;
-use num::Unsigned;
-use std::ops::Div;
+use core::ops::Div;
 
 pub trait Centroid<T: Scalar> {
   fn centroid(&self) -> Point2<T>;
@@ -12,13 +11,21 @@ impl<T: Scalar, U: Centroid<T>> Centroid<T> for &U {
   }
 }
 
-impl Centroid<u8> for Shape<u8, u8> {
-  fn centroid(&self) -> Point2<u8> {
+impl<T, U> Centroid<T> for Shape<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn centroid(&self) -> Point2<T> {
     match self {
       Self::Ellipse(ellipse) => ellipse.centroid(),
       Self::Circle(circle) => circle.centroid(),
       Self::Rectangle(rectangle) => rectangle.centroid(),
+      Self::RotatedRectangle(rectangle) => rectangle.centroid(),
+      Self::RotatedEllipse(ellipse) => ellipse.centroid(),
       Self::Triangle(triangle) => triangle.centroid(),
+      Self::Polygon(polygon) => polygon.centroid(),
+      Self::PolygonWithHoles(polygon) => polygon.centroid(),
       Self::Collection(collection) => collection.centroid(),
     }
   }
@@ -27,7 +34,7 @@ impl Centroid<u8> for Shape<u8, u8> {
 impl<T, R> Centroid<T> for Circle<T, R>
 where
   T: Scalar,
-  R: Scalar + Unsigned,
+  R: Scalar,
 {
   fn centroid(&self) -> Point2<T> {
     self.center.clone()
@@ -37,14 +44,36 @@ where
 impl<T, R> Centroid<T> for Ellipse<T, R>
 where
   T: Scalar,
-  R: Scalar + Unsigned,
+  R: Scalar,
+{
+  fn centroid(&self) -> Point2<T> {
+    self.center.clone()
+  }
+}
+
+impl<T, R> Centroid<T> for RotatedEllipse<T, R>
+where
+  T: Scalar,
+  R: Scalar,
 {
   fn centroid(&self) -> Point2<T> {
     self.center.clone()
   }
 }
 
-impl Centroid<u8> for Rectangle<u8> {
+impl<T> Centroid<T> for RotatedRectangle<T>
+where
+  T: Scalar,
+{
+  fn centroid(&self) -> Point2<T> {
+    self.center.clone()
+  }
+}
+
+impl<T> Centroid<T> for Rectangle<T>
+where
+  T: FloatMath,
+{
   /// Returns the center of the rectangle.
   ///
   /// # Example
@@ -55,31 +84,21 @@ impl Centroid<u8> for Rectangle<u8> {
   /// assert_eq!(rectangle.centroid(), Point2::new(5, 5));
   ///
   /// ```
-  fn centroid(&self) -> Point2<u8> {
-    let min = self.min().map(|x| x as u16);
-    let max = self.max().map(|x| x as u16);
-
-    Point2::new(((min.x + max.x) / 2) as u8, ((min.y + max.y) / 2) as u8)
-  }
-}
-
-// impl<T, U> Centroid<T> for ShapeCollection<T, U>
-//   where
-//     T: Scalar + Eq + From<f64>,
-//     U: Scalar + Unsigned + Eq,
-//     Shape<T, U>: Centroid<T>,
-//     f64: From<T>
-// {
-//   fn centroid(&self) -> Point2<T> {
-//     let mut center = Vector2::new(0., 0.);
-//     for geometry in &self.shapes {
-//       center += geometry.centroid().coords.map(|x| x.into());
-//     }
-//     center.div(self.shapes.len() as f64).map(|x| x.into()).into()
-//   }
-// }
-
-impl Centroid<u8> for Triangle<u8> {
+  fn centroid(&self) -> Point2<T> {
+    let min = self.min().map(Into::<f64>::into);
+    let max = self.max().map(Into::<f64>::into);
+
+    Point2::new(
+      T::from_f64((min.x + max.x) / 2.0),
+      T::from_f64((min.y + max.y) / 2.0),
+    )
+  }
+}
+
+impl<T> Centroid<T> for Triangle<T>
+where
+  T: FloatMath,
+{
   /// Returns the center of the triangle.
   ///
   /// # Example
@@ -89,31 +108,140 @@ impl Centroid<u8> for Triangle<u8> {
   /// let triangle = Triangle::new([0, 0].into(), [10, 0].into(), [0, 10].into());
   /// assert_eq!(triangle.centroid(), [3, 3].into());
   /// ```
-  fn centroid(&self) -> Point2<u8> {
-    let x_sum: u16 = self.0.x as u16 + self.1.x as u16 + self.2.x as u16;
-    let y_sum: u16 = self.0.y as u16 + self.1.y as u16 + self.2.y as u16;
+  fn centroid(&self) -> Point2<T> {
+    let a = self.0.map(Into::<f64>::into);
+    let b = self.1.map(Into::<f64>::into);
+    let c = self.2.map(Into::<f64>::into);
 
     Point2::new(
-      (x_sum as f64 / 3.0).round() as u8,
-      (y_sum as f64 / 3.0).round() as u8,
+      T::round_from_f64((a.x + b.x + c.x) / 3.0),
+      T::round_from_f64((a.y + b.y + c.y) / 3.0),
     )
   }
 }
 
-impl Centroid<u8> for ShapeCollection<u8, u8> {
-  fn centroid(&self) -> Point2<u8> {
+impl<T> Centroid<T> for Polygon<T>
+where
+  T: FloatMath,
+{
+  /// Returns the area-weighted center of the polygon.
+  ///
+  /// Degenerate polygons without an area fall back to the average of their vertices.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Point2, Polygon, traits::Centroid};
+  ///
+  /// let polygon = Polygon::new(vec![[0, 0].into(), [10, 0].into(), [10, 10].into(), [0, 10].into()]);
+  /// assert_eq!(polygon.centroid(), Point2::new(5, 5));
+  /// ```
+  fn centroid(&self) -> Point2<T> {
+    let (area, moment) = polygon_moments(self);
+
+    if area == 0.0 {
+      let mut center = Vector2::new(0., 0.);
+      for point in &self.points {
+        center += point.coords.map(Into::<f64>::into);
+      }
+      return center
+        .div(self.points.len() as f64)
+        .map(T::round_from_f64)
+        .into();
+    }
+
+    moment.div(area).map(T::round_from_f64).into()
+  }
+}
+
+impl<T> Centroid<T> for PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  /// Returns the area-weighted center of the polygon, excluding the holes.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Point2, Polygon, PolygonWithHoles, traits::Centroid};
+  ///
+  /// let polygon = PolygonWithHoles::new(
+  ///   Polygon::new(vec![[0, 0].into(), [20, 0].into(), [20, 10].into(), [0, 10].into()]),
+  ///   vec![Polygon::new(vec![[10, 0].into(), [20, 0].into(), [20, 10].into(), [10, 10].into()])],
+  /// ).unwrap();
+  /// assert_eq!(polygon.centroid(), Point2::new(5, 5));
+  /// ```
+  fn centroid(&self) -> Point2<T> {
+    // Holes are oriented opposite to the exterior, so their signed area and moment
+    // are subtracted from the exterior ones.
+    let (area, moment) = self
+      .rings()
+      .map(polygon_moments)
+      .fold((0.0, Vector2::new(0., 0.)), |(area, moment), (a, m)| {
+        (area + a, moment + m)
+      });
+
+    if area == 0.0 {
+      return self.exterior.centroid();
+    }
+
+    moment.div(area).map(T::round_from_f64).into()
+  }
+}
+
+/// Returns the signed area of the polygon and its first moment of area.
+fn polygon_moments<T: FloatMath>(polygon: &Polygon<T>) -> (f64, Vector2<f64>) {
+  let mut moment = Vector2::new(0., 0.);
+  for edge in polygon.edges() {
+    let start = edge.start.map(Into::<f64>::into);
+    let end = edge.end.map(Into::<f64>::into);
+    let cross = start.x * end.y - end.x * start.y;
+    moment += (start.coords + end.coords) * cross / 6.0;
+  }
+
+  (polygon.signed_area(), moment)
+}
+
+impl<T, U> Centroid<T> for ShapeCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn centroid(&self) -> Point2<T> {
+    SliceShapeCollection::from(self).centroid()
+  }
+}
+
+impl<T, U, const N: usize> Centroid<T> for FixedShapeCollection<T, U, N>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn centroid(&self) -> Point2<T> {
+    SliceShapeCollection::from(self).centroid()
+  }
+}
+
+impl<T, U> Centroid<T> for SliceShapeCollection<'_, T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  fn centroid(&self) -> Point2<T> {
     let mut center = Vector2::new(0., 0.);
-    for geometry in &self.shapes {
-      center += geometry.centroid().coords.map(|x| x as f64);
+    for geometry in self.shapes() {
+      center += geometry.centroid().coords.map(Into::<f64>::into);
     }
-    center.div(self.shapes.len() as f64).map(|x| x as u8).into()
+    center
+      .div(self.shapes().len() as f64)
+      .map(T::from_f64)
+      .into()
   }
 }
 
 #[cfg(test)]
 mod tests {
   use crate::{
-    traits::Centroid, Circle, Ellipse, Point2, Rectangle, Shape, ShapeCollection, Triangle,
+    traits::Centroid, Circle, Ellipse, Point2, Polygon, PolygonWithHoles, Rectangle, Shape,
+    ShapeCollection, Triangle,
   };
   use test_case::test_case;
   use test_strategy::proptest;
@@ -139,6 +267,18 @@ mod tests {
     let _out = rectangle.centroid();
   }
 
+  #[test_case(Rectangle::new(Point2::new(0.0, 0.0), Point2::new(0.5, 0.25)), Point2::new(0.25, 0.125); "normal")]
+  #[test_case(Rectangle::new(Point2::new(-1.0, -1.0), Point2::new(0.5, 0.5)), Point2::new(-0.25, -0.25); "negative")]
+  fn rectangle_centroid_f32(rectangle: Rectangle<f32>, expected_center: Point2<f32>) {
+    assert_eq!(rectangle.centroid(), expected_center);
+  }
+
+  #[test_case(Rectangle::new(Point2::new(-10, -10), Point2::new(10, 10)), Point2::new(0, 0); "normal")]
+  #[test_case(Rectangle::new(Point2::new(-3, -3), Point2::new(0, 0)), Point2::new(-1, -1); "odd")]
+  fn rectangle_centroid_i16(rectangle: Rectangle<i16>, expected_center: Point2<i16>) {
+    assert_eq!(rectangle.centroid(), expected_center);
+  }
+
   #[test_case(Triangle::new([15, 15].into(), [25, 15].into(), [20, 25].into()), [20, 18].into(); "normal")]
   #[test_case(Triangle::new([0, 0].into(), [10, 0].into(), [0, 10].into()), [3, 3].into(); "edge/top+start")]
   fn triangle_centroid_u8(triangle: Triangle<u8>, centroid: Point2<u8>) {
@@ -150,9 +290,62 @@ mod tests {
     let _centroid = triangle.centroid();
   }
 
+  #[test_case(Triangle::new([0.0, 0.0].into(), [1.0, 0.0].into(), [0.5, 1.5].into()), [0.5, 0.5].into(); "normal")]
+  #[test_case(Triangle::new([0.0, 0.0].into(), [1.0, 0.0].into(), [0.0, 1.0].into()), [1.0 / 3.0, 1.0 / 3.0].into(); "fractional")]
+  fn triangle_centroid_f64(triangle: Triangle<f64>, centroid: Point2<f64>) {
+    assert_eq!(triangle.centroid(), centroid);
+  }
+
+  #[test_case(Polygon::new(vec![[0, 0].into(), [10, 0].into(), [10, 10].into(), [0, 10].into()]), [5, 5].into(); "square")]
+  #[test_case(Polygon::new(vec![[0, 10].into(), [10, 10].into(), [10, 0].into(), [0, 0].into()]), [5, 5].into(); "clockwise square")]
+  #[test_case(Polygon::new(vec![[0, 0].into(), [30, 0].into(), [30, 10].into(), [10, 10].into(), [10, 30].into(), [0, 30].into()]), [11, 11].into(); "l-shape")]
+  #[test_case(Polygon::new(vec![[0, 0].into(), [10, 10].into(), [20, 20].into()]), [10, 10].into(); "degenerate")]
+  fn polygon_centroid_u8(polygon: Polygon<u8>, centroid: Point2<u8>) {
+    assert_eq!(polygon.centroid(), centroid);
+  }
+
+  #[test]
+  fn polygon_centroid_f64() {
+    let polygon = Polygon::<f64>::new(vec![
+      [0.0, 0.0].into(),
+      [1.0, 0.0].into(),
+      [1.0, 0.5].into(),
+      [0.0, 0.5].into(),
+    ]);
+
+    assert_eq!(polygon.centroid(), Point2::new(0.5, 0.25));
+  }
+
+  #[proptest]
+  fn polygon_centroid_u8_fuzz(polygon: Polygon<u8>) {
+    let _centroid = polygon.centroid();
+  }
+
+  #[test]
+  fn polygon_with_holes_centroid_f64() {
+    // 3x3 square with the center-right cell cut out
+    let polygon = PolygonWithHoles::new(
+      Polygon::<f64>::new(vec![
+        [0.0, 0.0].into(),
+        [3.0, 0.0].into(),
+        [3.0, 3.0].into(),
+        [0.0, 3.0].into(),
+      ]),
+      vec![Polygon::new(vec![
+        [2.0, 1.0].into(),
+        [3.0, 1.0].into(),
+        [3.0, 2.0].into(),
+        [2.0, 2.0].into(),
+      ])],
+    )
+    .unwrap();
+
+    assert_eq!(polygon.centroid(), Point2::new(1.375, 1.5));
+  }
+
   #[test]
   fn shape_collection_centroid_u8() {
-    let collection = ShapeCollection::new(vec![
+    let collection = ShapeCollection::<u8, u8>::new(vec![
       Shape::Rectangle(Rectangle::new(Point2::new(0, 0), Point2::new(2, 2))),
       Shape::Rectangle(Rectangle::new(Point2::new(2, 2), Point2::new(4, 4))),
       Shape::Rectangle(Rectangle::new(Point2::new(4, 4), Point2::new(6, 6))),
@@ -160,4 +353,14 @@ mod tests {
 
     assert_eq!(collection.centroid(), Point2::new(3, 3));
   }
+
+  #[test]
+  fn shape_collection_centroid_f32() {
+    let collection = ShapeCollection::<f32, f32>::new(vec![
+      Shape::Rectangle(Rectangle::new(Point2::new(0.0, 0.0), Point2::new(0.5, 0.5))),
+      Shape::Circle(Circle::new(Point2::new(0.75, 0.75), 0.25)),
+    ]);
+
+    assert_eq!(collection.centroid(), Point2::new(0.5, 0.5));
+  }
 }
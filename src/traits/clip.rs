@@ -0,0 +1,228 @@
+use alloc::vec::Vec;
+
+use super::Polygonal;
+use crate::primitive::{self, AsPolygon, Operation};
+use crate::*;
+
+/// Generic trait to clip shapes bounded by straight edges against each other.
+///
+/// Unlike [`Csg`](crate::Csg), which is evaluated per point, the result is new geometry: a set of
+/// non-overlapping polygons with counter-clockwise exteriors and clockwise holes. Parts of the
+/// result which only touch in a single vertex are separate polygons, while holes touching the
+/// exterior in a single vertex become part of the exterior ring. Parts without any area, like
+/// shared edges, are dropped.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Point2, Rectangle, traits::Clip};
+///
+/// let zone = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(30, 30));
+/// let obstacle = Rectangle::<u8>::new(Point2::new(10, 10), Point2::new(20, 20));
+///
+/// let remaining = zone.difference(&obstacle);
+/// assert_eq!(remaining.len(), 1);
+/// assert_eq!(remaining[0].holes().len(), 1);
+///
+/// let overlap = zone.intersection(&obstacle);
+/// assert_eq!(overlap[0].exterior().signed_area(), 100.0);
+/// ```
+pub trait Clip<T> {
+  type Result;
+
+  /// Returns the parts inside both shapes.
+  fn intersection(&self, other: T) -> Self::Result;
+
+  /// Returns the parts inside this shape, but not inside the other one.
+  fn difference(&self, other: T) -> Self::Result;
+}
+
+macro_rules! impl_clip {
+  ($($shape: ty),* $(,)?) => {$(
+    impl<T, S> Clip<&S> for $shape
+    where
+      T: FloatMath,
+      S: Polygonal,
+    {
+      type Result = Vec<PolygonWithHoles<f64>>;
+
+      fn intersection(&self, other: &S) -> Self::Result {
+        primitive::clip(&[self.polygon()], &[other.polygon()], Operation::Intersection)
+      }
+
+      fn difference(&self, other: &S) -> Self::Result {
+        primitive::clip(&[self.polygon()], &[other.polygon()], Operation::Difference)
+      }
+    }
+  )*};
+}
+
+impl_clip!(
+  Rectangle<T>,
+  RotatedRectangle<T>,
+  Triangle<T>,
+  Polygon<T>,
+  PolygonWithHoles<T>,
+);
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use float_cmp::assert_approx_eq;
+  use proptest::prelude::ProptestConfig;
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use super::Clip;
+  use crate::traits::{SignedDistance, Within};
+  use crate::{Point2, Polygon, PolygonWithHoles, Rectangle, RotatedRectangle, Shape, Triangle};
+
+  fn rectangle(min: [i32; 2], max: [i32; 2]) -> Rectangle<i32> {
+    Rectangle::new(min.into(), max.into())
+  }
+
+  fn polygon(points: &[[i32; 2]]) -> Polygon<i32> {
+    Polygon::new(points.iter().copied().map(Into::into).collect())
+  }
+
+  /// Returns the number of polygons, the number of holes and the total area.
+  fn summary(polygons: Vec<PolygonWithHoles<f64>>) -> (usize, usize, f64) {
+    let holes = polygons.iter().map(|polygon| polygon.holes().len()).sum();
+    let area = polygons
+      .iter()
+      .flat_map(|polygon| polygon.rings())
+      .map(|ring| ring.signed_area())
+      .sum::<f64>();
+
+    (polygons.len(), holes, (area * 1e6).round() / 1e6)
+  }
+
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([5, 5], [15, 15]) => (1, 0, 25.0); "overlapping")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([5, 0], [15, 10]) => (1, 0, 50.0); "collinear edges")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([0, 0], [10, 10]) => (1, 0, 100.0); "identical")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([2, 2], [8, 8]) => (1, 0, 36.0); "inside")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10]) => (0, 0, 0.0); "touching edges")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([10, 10], [20, 20]) => (0, 0, 0.0); "touching corners")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([20, 20], [30, 30]) => (0, 0, 0.0); "apart")]
+  fn rectangle_intersection(a: Rectangle<i32>, b: Rectangle<i32>) -> (usize, usize, f64) {
+    summary(a.intersection(&b))
+  }
+
+  #[test_case(rectangle([0, 0], [30, 30]), rectangle([10, 10], [20, 20]) => (1, 1, 800.0); "hole")]
+  #[test_case(rectangle([0, 0], [30, 10]), rectangle([10, -5], [20, 15]) => (2, 0, 200.0); "split in two")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([5, 0], [15, 10]) => (1, 0, 50.0); "collinear edges")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([0, 0], [10, 10]) => (0, 0, 0.0); "identical")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10]) => (1, 0, 100.0); "touching edges")]
+  #[test_case(rectangle([0, 0], [30, 30]), rectangle([10, 0], [20, 20]) => (1, 0, 700.0); "notch from an edge")]
+  #[test_case(rectangle([0, 0], [30, 30]), rectangle([10, 10], [30, 20]) => (1, 0, 700.0); "notch from a side")]
+  fn rectangle_difference(a: Rectangle<i32>, b: Rectangle<i32>) -> (usize, usize, f64) {
+    summary(a.difference(&b))
+  }
+
+  #[test]
+  fn difference_touching_in_a_vertex() {
+    // Two squares meeting in the center, cut out of a larger square.
+    let checkerboard = polygon(&[[0, 0], [10, 0], [10, 20], [20, 20], [20, 10], [0, 10]]);
+    let result = rectangle([0, 0], [20, 20]).difference(&checkerboard);
+
+    assert_eq!(summary(result.clone()), (2, 0, 200.0));
+    for polygon in result {
+      assert_eq!(polygon.exterior().points().len(), 4);
+    }
+  }
+
+  #[test]
+  fn difference_of_hole_touching_the_exterior() {
+    let result = rectangle([0, 0], [30, 30]).difference(&polygon(&[[10, 0], [20, 10], [10, 20]]));
+
+    assert_eq!(summary(result), (1, 0, 800.0));
+  }
+
+  #[test]
+  fn triangle_intersection() {
+    let triangle = Triangle::new(Point2::new(0, 0), Point2::new(20, 0), Point2::new(0, 20));
+    let result = triangle.intersection(&rectangle([0, 0], [10, 10]));
+
+    assert_eq!(summary(result.clone()), (1, 0, 100.0));
+    assert_eq!(
+      result[0].exterior().points(),
+      &vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(10.0, 0.0),
+        Point2::new(10.0, 10.0),
+        Point2::new(0.0, 10.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn polygon_with_holes_difference() {
+    let square = |min: i32, max: i32| polygon(&[[min, min], [max, min], [max, max], [min, max]]);
+    let frame = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+
+    assert_eq!(summary(frame.difference(&square(0, 15))), (1, 0, 600.0));
+    assert_eq!(summary(frame.intersection(&square(5, 25))), (1, 1, 300.0));
+    assert_eq!(summary(square(5, 25).difference(&frame)), (1, 0, 100.0));
+  }
+
+  #[test]
+  fn rotated_rectangle_difference() {
+    let diamond = RotatedRectangle::new(
+      Point2::new(10.0, 10.0),
+      (5.0, 5.0),
+      core::f64::consts::FRAC_PI_4,
+    );
+    let (count, holes, area) = summary(rectangle([0, 0], [20, 20]).difference(&diamond));
+
+    assert_eq!((count, holes), (1, 1));
+    assert_approx_eq!(f64, area, 300.0, epsilon = 1e-6);
+  }
+
+  /// Compares the clipped polygons against the operands at the points of a grid.
+  ///
+  /// Points close to the outline of either operand are skipped, as the outlines of the
+  /// operands are where the results are allowed to disagree.
+  fn assert_clipped(
+    a: Shape<u8, u8>,
+    b: Shape<u8, u8>,
+    intersection: Vec<PolygonWithHoles<f64>>,
+    difference: Vec<PolygonWithHoles<f64>>,
+  ) {
+    for x in (0..=255).step_by(3) {
+      for y in (0..=255).step_by(3) {
+        let point = Point2::new(x, y);
+        if a.signed_distance(&point).abs() < 1e-6 || b.signed_distance(&point).abs() < 1e-6 {
+          continue;
+        }
+
+        let (in_a, in_b) = (a.within(&point), b.within(&point));
+        let point = point.map(f64::from);
+        let count = |polygons: &Vec<PolygonWithHoles<f64>>| {
+          polygons
+            .iter()
+            .filter(|polygon| polygon.within(&point))
+            .count()
+        };
+
+        assert_eq!(count(&intersection), usize::from(in_a && in_b), "{point}");
+        assert_eq!(count(&difference), usize::from(in_a && !in_b), "{point}");
+      }
+    }
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn clip_rectangle_triangle_u8(a: Rectangle<u8>, b: Triangle<u8>) {
+    assert_clipped(a.into(), b.into(), a.intersection(&b), a.difference(&b));
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn clip_triangle_rotated_rectangle_u8(a: Triangle<u8>, b: RotatedRectangle<u8>) {
+    assert_clipped(a.into(), b.into(), a.intersection(&b), a.difference(&b));
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn clip_polygon_polygon_u8(a: Polygon<u8>, b: Polygon<u8>) {
+    let (intersection, difference) = (a.intersection(&b), a.difference(&b));
+    assert_clipped(a.into(), b.into(), intersection, difference);
+  }
+}
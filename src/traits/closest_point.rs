@@ -0,0 +1,217 @@
+use crate::primitive;
+use crate::*;
+
+/// Point on the outline of a shape, with the outward unit normal of the outline there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundaryPoint {
+  pub point: Point2<f64>,
+  /// Outward unit normal, or zero for shapes without an area at all, like a single point.
+  ///
+  /// For points off the outline it is the direction from the boundary point towards the
+  /// given point outside the shape, and away from it inside the shape.
+  pub normal: Vector2<f64>,
+}
+
+/// Generic trait to find the closest point on the outline of a shape.
+///
+/// Points inside the shape are snapped to the outline as well. Holes are part of the
+/// outline, and for collections the outline of the closest member is used. Returns `None`
+/// for shapes without any outline, like empty collections.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Point2, Rectangle, Vector2, traits::ClosestPoint};
+///
+/// let zone = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(10, 10));
+/// let boundary = zone.closest_point(&Point2::new(8, 5)).unwrap();
+///
+/// assert_eq!(boundary.point, Point2::new(10.0, 5.0));
+/// assert_eq!(boundary.normal, Vector2::new(1.0, 0.0));
+/// ```
+pub trait ClosestPoint<T> {
+  type Result;
+
+  fn closest_point(&self, point: T) -> Self::Result;
+}
+
+macro_rules! impl_closest_point {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<T, $($generic,)*> ClosestPoint<&Point2<T>> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      type Result = Option<BoundaryPoint>;
+
+      fn closest_point(&self, point: &Point2<T>) -> Self::Result {
+        primitive::boundary_point(self, &point.map(Into::into))
+      }
+    }
+
+    impl<T, $($generic,)*> ClosestPoint<Point2<T>> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      type Result = Option<BoundaryPoint>;
+
+      fn closest_point(&self, point: Point2<T>) -> Self::Result {
+        self.closest_point(&point)
+      }
+    }
+  )*};
+}
+
+impl_closest_point!(
+  Line<T> => [];
+  Rectangle<T> => [];
+  RotatedRectangle<T> => [];
+  Circle<T, R> => [R];
+  Ellipse<T, R> => [R];
+  RotatedEllipse<T, R> => [R];
+  Triangle<T> => [];
+  Polygon<T> => [];
+  PolygonWithHoles<T> => [];
+  ShapeCollection<T, R> => [R];
+  Shape<T, R> => [R];
+);
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::FRAC_1_SQRT_2;
+
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use super::{BoundaryPoint, ClosestPoint};
+  use crate::proptest::ShapeView;
+  use crate::testing::{circle, collection, ellipse, rectangle, triangle, PointView2};
+  use crate::traits::{Distance, Within};
+  use crate::{Line, Point2, Polygon, PolygonWithHoles, RotatedRectangle, Shape, ShapeCollection};
+
+  fn assert_boundary_point(actual: Option<BoundaryPoint>, point: [f64; 2], normal: [f64; 2]) {
+    let actual = actual.expect("shape has an outline");
+
+    assert_approx_eq!(f64, actual.point.x, point[0], epsilon = 1e-9);
+    assert_approx_eq!(f64, actual.point.y, point[1], epsilon = 1e-9);
+    assert_approx_eq!(f64, actual.normal.x, normal[0], epsilon = 1e-9);
+    assert_approx_eq!(f64, actual.normal.y, normal[1], epsilon = 1e-9);
+  }
+
+  #[test_case(rectangle([0, 0], [10, 10]), [15, 5], [10.0, 5.0], [1.0, 0.0]; "rectangle, outside")]
+  #[test_case(rectangle([0, 0], [10, 10]), [2, 5], [0.0, 5.0], [-1.0, 0.0]; "rectangle, inside")]
+  #[test_case(rectangle([0, 0], [10, 10]), [5, 10], [5.0, 10.0], [0.0, 1.0]; "rectangle, on the edge")]
+  #[test_case(rectangle([0, 0], [10, 10]), [10, 10], [10.0, 10.0], [FRAC_1_SQRT_2, FRAC_1_SQRT_2]; "rectangle, on the corner")]
+  #[test_case(rectangle([0, 0], [10, 10]), [13, 14], [10.0, 10.0], [0.6, 0.8]; "rectangle, beyond the corner")]
+  #[test_case(circle([0, 0], 5), [6, 8], [3.0, 4.0], [0.6, 0.8]; "circle, outside")]
+  #[test_case(circle([0, 0], 5), [0, -2], [0.0, -5.0], [0.0, -1.0]; "circle, inside")]
+  #[test_case(ellipse([0, 0], (10, 2)), [0, 5], [0.0, 2.0], [0.0, 1.0]; "ellipse, minor axis")]
+  #[test_case(ellipse([0, 0], (10, 2)), [10, 0], [10.0, 0.0], [1.0, 0.0]; "ellipse, on the tip")]
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), [10, 10], [5.0, 5.0], [FRAC_1_SQRT_2, FRAC_1_SQRT_2]; "triangle, hypotenuse")]
+  #[test_case(triangle([0, 0], [0, 10], [10, 0]), [1, 2], [0.0, 2.0], [-1.0, 0.0]; "clockwise triangle, inside")]
+  #[test_case(collection(vec![circle([0, 0], 5), rectangle([20, 0], [30, 10])]), [17, 5], [20.0, 5.0], [-1.0, 0.0]; "collection, closest member")]
+  fn shape_closest_point(
+    shape: Shape<i32, u32>,
+    point: [i32; 2],
+    expected: [f64; 2],
+    normal: [f64; 2],
+  ) {
+    assert_boundary_point(shape.closest_point(Point2::from(point)), expected, normal);
+  }
+
+  #[test]
+  fn line_closest_point() {
+    let line = Line::new(Point2::new(0, 0), Point2::new(10, 0));
+
+    assert_boundary_point(
+      line.closest_point(Point2::new(5, 3)),
+      [5.0, 0.0],
+      [0.0, 1.0],
+    );
+    assert_boundary_point(
+      line.closest_point(Point2::new(-3, -4)),
+      [0.0, 0.0],
+      [-0.6, -0.8],
+    );
+    assert_eq!(
+      line.closest_point(Point2::new(5, 0)).unwrap().normal.norm(),
+      1.0
+    );
+  }
+
+  #[test]
+  fn polygon_with_holes_closest_point() {
+    let square = |min: i32, max: i32| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let polygon = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+
+    // The outline of the hole is closest, and its normal points into the hole.
+    assert_boundary_point(
+      polygon.closest_point(Point2::new(15, 12)),
+      [15.0, 10.0],
+      [0.0, 1.0],
+    );
+    assert_boundary_point(
+      polygon.closest_point(Point2::new(15, 8)),
+      [15.0, 10.0],
+      [0.0, 1.0],
+    );
+    assert_boundary_point(
+      polygon.closest_point(Point2::new(15, 10)),
+      [15.0, 10.0],
+      [0.0, 1.0],
+    );
+  }
+
+  #[test]
+  fn rotated_rectangle_closest_point() {
+    let rectangle = RotatedRectangle::new(
+      Point2::new(0.0, 0.0),
+      (2.0, 1.0),
+      core::f64::consts::FRAC_PI_2,
+    );
+
+    assert_boundary_point(
+      rectangle.closest_point(Point2::new(0.0, 5.0)),
+      [0.0, 2.0],
+      [0.0, 1.0],
+    );
+  }
+
+  #[test]
+  fn empty_collection_closest_point() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![]);
+
+    assert_eq!(collection.closest_point(Point2::new(1, 1)), None);
+  }
+
+  #[proptest]
+  fn shape_closest_point_u8(shape: ShapeView<u8, u8>, point: PointView2<u8>) {
+    let shape: Shape<u8, u8> = shape.into();
+    let point: Point2<u8> = point.into();
+
+    if let Some(boundary) = shape.closest_point(&point) {
+      let normal = boundary.normal.norm();
+      assert!(normal == 0.0 || (normal - 1.0).abs() < 1e-9);
+
+      if !shape.within(&point) {
+        let distance = crate::distance(&point.map(Into::into), &boundary.point);
+        let expected = shape.distance(&Line::new_unchecked(point, point));
+        assert_approx_eq!(f64, distance, expected, epsilon = 1e-6);
+        assert!(
+          boundary
+            .normal
+            .dot(&(point.map(Into::<f64>::into) - boundary.point))
+            >= 0.0
+        );
+      }
+    }
+  }
+}
@@ -0,0 +1,304 @@
+use alloc::vec::Vec;
+
+use crate::primitive::AsPrimitives;
+use crate::traits::rasterize::MAX_GRID;
+use crate::traits::ScanBounds;
+use crate::*;
+
+/// Generic trait to calculate how much of every grid cell is covered by a shape.
+///
+/// Every integer point is the center of a 1×1 cell. The coverage of a cell is the fraction
+/// of its area inside the shape, estimated with `samples × samples` evenly spaced samples.
+/// Only cells with a coverage above zero are returned, in scanline order. A sample count
+/// of zero is treated as one sample at the center of the cell.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Point2, Rectangle, traits::Coverage};
+///
+/// let rectangle = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(1, 1));
+/// assert_eq!(rectangle.coverage(4), vec![
+///   (Point2::new(0, 0), 0.25),
+///   (Point2::new(1, 0), 0.25),
+///   (Point2::new(0, 1), 0.25),
+///   (Point2::new(1, 1), 0.25),
+/// ]);
+/// ```
+pub trait Coverage<T: Scalar> {
+  fn coverage(&self, samples: usize) -> Vec<(Point2<T>, f32)>;
+}
+
+impl<T: Scalar, U: Coverage<T>> Coverage<T> for &U {
+  #[cfg_attr(test, mutants::skip)]
+  fn coverage(&self, samples: usize) -> Vec<(Point2<T>, f32)> {
+    U::coverage(*self, samples)
+  }
+}
+
+macro_rules! impl_coverage {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<T, $($generic,)*> Coverage<T> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      fn coverage(&self, samples: usize) -> Vec<(Point2<T>, f32)> {
+        coverage(self, samples)
+      }
+    }
+  )*};
+}
+
+impl_coverage!(
+  Rectangle<T> => [];
+  RotatedRectangle<T> => [];
+  Circle<T, R> => [R];
+  Ellipse<T, R> => [R];
+  RotatedEllipse<T, R> => [R];
+  Triangle<T> => [];
+  Polygon<T> => [];
+  PolygonWithHoles<T> => [];
+  ShapeCollection<T, R> => [R];
+  Shape<T, R> => [R];
+);
+
+fn coverage<S, T>(shape: &S, samples: usize) -> Vec<(Point2<T>, f32)>
+where
+  S: AsPrimitives + ScanBounds<T>,
+  T: FloatMath,
+{
+  let Some(bounds) = shape.scan_bounds() else {
+    return Vec::new();
+  };
+
+  let primitives = shape.primitives();
+  let samples = samples.max(1);
+  let offsets = (0..samples)
+    .map(|i| (i as f64 + 0.5) / samples as f64 - 0.5)
+    .collect::<Vec<_>>();
+
+  // Every cell which reaches into the bounds, clamped like the grid of `PointsInside`.
+  // Adding zero turns `-0.0` into `0.0`.
+  let min = bounds
+    .min()
+    .map(|c| (Into::<f64>::into(c) - 0.5).ceil().max(-MAX_GRID) + 0.0);
+  let max = bounds
+    .max()
+    .map(|c| (Into::<f64>::into(c) + 0.5).floor().min(MAX_GRID));
+
+  let mut cells = Vec::new();
+  let mut y = min.y;
+  while y <= max.y {
+    let mut x = min.x;
+    while x <= max.x {
+      let covered = offsets
+        .iter()
+        .flat_map(|dy| offsets.iter().map(move |dx| Point2::new(x + dx, y + dy)))
+        .filter(|sample| primitives.iter().any(|p| p.contains(sample)))
+        .count();
+
+      if covered > 0 {
+        cells.push((
+          Point2::new(T::from_f64(x), T::from_f64(y)),
+          covered as f32 / (samples * samples) as f32,
+        ));
+      }
+      x += 1.0;
+    }
+    y += 1.0;
+  }
+
+  cells
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use float_cmp::assert_approx_eq;
+  use proptest::prelude::ProptestConfig;
+  use test_strategy::proptest;
+
+  use super::Coverage;
+  use crate::traits::{Area, Perimeter, PointsInside};
+  use crate::{Circle, FillRule, Point2, Polygon, Rectangle, Shape, ShapeCollection, Triangle};
+
+  #[test]
+  fn rectangle_coverage_u8() {
+    let rectangle = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(2, 1));
+    let coverage = rectangle.coverage(2);
+
+    assert_eq!(
+      coverage,
+      vec![
+        (Point2::new(0, 0), 0.25),
+        (Point2::new(1, 0), 0.5),
+        (Point2::new(2, 0), 0.25),
+        (Point2::new(0, 1), 0.25),
+        (Point2::new(1, 1), 0.5),
+        (Point2::new(2, 1), 0.25),
+      ]
+    );
+  }
+
+  #[test]
+  fn rectangle_coverage_f32() {
+    let rectangle = Rectangle::<f32>::new(Point2::new(0.0, 0.0), Point2::new(0.5, 1.0));
+    let coverage = rectangle.coverage(4);
+
+    assert_eq!(
+      coverage,
+      vec![(Point2::new(0.0, 0.0), 0.25), (Point2::new(0.0, 1.0), 0.25)]
+    );
+  }
+
+  #[test]
+  fn rectangle_coverage_i16() {
+    let rectangle = Rectangle::<i16>::new(Point2::new(-3, -1), Point2::new(3, 1));
+    let coverage = rectangle.coverage(4);
+
+    assert_eq!(coverage.len(), 21);
+    assert!(coverage.contains(&(Point2::new(0, 0), 1.0)));
+    assert!(coverage.contains(&(Point2::new(-3, 0), 0.5)));
+  }
+
+  #[test]
+  fn triangle_coverage_u8() {
+    let triangle = Triangle::<u8>::new(Point2::new(0, 0), Point2::new(4, 0), Point2::new(0, 4));
+    let coverage = triangle.coverage(8);
+
+    let total: f32 = coverage.iter().map(|(_, value)| value).sum();
+    assert_approx_eq!(f32, total, 8.0, epsilon = 0.25);
+    assert!(coverage.contains(&(Point2::new(1, 1), 1.0)));
+    // The diagonal halves the cells it passes through the centers of.
+    assert!(coverage.contains(&(Point2::new(2, 2), 0.5625)));
+  }
+
+  #[test]
+  fn circle_coverage_u8() {
+    let circle = Circle::<u8, u8>::new(Point2::new(10, 10), 5);
+    let coverage = circle.coverage(8);
+
+    let total: f32 = coverage.iter().map(|(_, value)| value).sum();
+    assert_approx_eq!(f32, total, 78.54, epsilon = 0.5);
+    assert!(coverage.contains(&(Point2::new(10, 10), 1.0)));
+    assert!(coverage
+      .iter()
+      .all(|(point, _)| (5..=15).contains(&point.x) && (5..=15).contains(&point.y)));
+  }
+
+  #[test]
+  fn polygon_coverage_follows_fill_rule() {
+    // A square with a second, overlapping lap around its upper half.
+    let points = [
+      [0, 0],
+      [4, 0],
+      [4, 4],
+      [0, 4],
+      [0, 2],
+      [4, 2],
+      [4, 4],
+      [0, 4],
+    ];
+    let polygon = Polygon::<u8>::new(points.iter().copied().map(Point2::from).collect());
+    let total =
+      |polygon: &Polygon<u8>| -> f32 { polygon.coverage(4).iter().map(|(_, value)| value).sum() };
+
+    assert_eq!(total(&polygon), 8.0);
+    assert_eq!(
+      total(&polygon.clone().with_fill_rule(FillRule::NonZero)),
+      16.0
+    );
+  }
+
+  #[test]
+  fn shape_collection_coverage_counts_overlap_once() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![
+      Rectangle::new(Point2::new(0, 0), Point2::new(2, 2)).into(),
+      Rectangle::new(Point2::new(1, 1), Point2::new(2, 2)).into(),
+    ]);
+
+    assert_eq!(
+      collection.coverage(4),
+      Rectangle::new(Point2::new(0, 0), Point2::new(2, 2)).coverage(4)
+    );
+  }
+
+  #[test]
+  fn shape_collection_coverage_empty() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![]);
+
+    assert_eq!(collection.coverage(4), vec![]);
+  }
+
+  #[test]
+  fn rectangle_coverage_f64_beyond_exact_integers() {
+    let limit = 2f64.powi(53);
+
+    let far = Rectangle::new(
+      Point2::new(limit * 128.0, 0.0),
+      Point2::new(limit * 130.0, 0.0),
+    );
+    assert_eq!(far.coverage(1), Vec::new());
+
+    let edge = Rectangle::new(
+      Point2::new(limit - 3.0, 0.0),
+      Point2::new(limit + 64.0, 0.0),
+    );
+    assert_eq!(
+      edge.coverage(1),
+      vec![
+        (Point2::new(limit - 3.0, 0.0), 1.0),
+        (Point2::new(limit - 2.0, 0.0), 1.0),
+        (Point2::new(limit - 1.0, 0.0), 1.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn single_sample_matches_points_inside() {
+    let shape: Shape<u8, u8> =
+      Triangle::new(Point2::new(0, 0), Point2::new(10, 0), Point2::new(0, 10)).into();
+    let points = shape
+      .coverage(0)
+      .into_iter()
+      .map(|(point, value)| {
+        assert_eq!(value, 1.0);
+        point
+      })
+      .collect::<Vec<_>>();
+
+    assert_eq!(points, shape.points_inside());
+  }
+
+  /// Checks that the coverage adds up to the area, only cells on the outline can be off.
+  fn assert_coverage_approximates_area<S>(shape: S)
+  where
+    S: Coverage<u8> + Area + Perimeter,
+  {
+    let coverage = shape.coverage(2);
+
+    let total: f64 = coverage.iter().map(|(_, value)| *value as f64).sum();
+    let tolerance = 2.0 * shape.perimeter() + 4.0;
+    assert!(
+      (total - shape.area()).abs() <= tolerance,
+      "{total} vs {}",
+      shape.area()
+    );
+    assert!(coverage
+      .iter()
+      .all(|(_, value)| *value > 0.0 && *value <= 1.0));
+  }
+
+  // Shapes with vertices only, which can't reach outside of the coordinate range.
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn triangle_coverage_u8_approximates_area(triangle: Triangle<u8>) {
+    assert_coverage_approximates_area(triangle);
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn polygon_coverage_u8_approximates_area(polygon: Polygon<u8>) {
+    assert_coverage_approximates_area(polygon);
+  }
+}
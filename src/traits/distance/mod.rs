@@ -1,3 +1,16 @@
+/// Generic trait to calculate the minimum distance to a point or another shape.
+///
+/// Shapes which overlap each other are 0 apart.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, Rectangle, traits::Distance};
+///
+/// let zone = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(10, 10));
+///
+/// assert_eq!(Circle::<u8, u8>::new(Point2::new(20, 5), 5).distance(&zone), 5.0);
+/// assert_eq!(Circle::<u8, u8>::new(Point2::new(12, 5), 5).distance(&zone), 0.0);
+/// ```
 pub trait Distance<T> {
   type Result;
 
@@ -6,5 +19,6 @@ pub trait Distance<T> {
 
 mod to_circle;
 mod to_point;
+mod to_shape;
 
 pub use to_point::*;
@@ -1,27 +1,13 @@
-use crate::{
-  traits::{distance, Distance},
-  Circle,
-};
+use crate::{traits::Distance, Circle, FloatMath};
 
-impl Distance<&Circle<u8, u8>> for Circle<u8, u8> {
+impl<T, R> Distance<Circle<T, R>> for Circle<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = f64;
 
-  fn distance(&self, other: &Circle<u8, u8>) -> Self::Result {
-    let centers_distance = distance(&self.center, &other.center);
-    let radius_sum = self.radius as f64 + other.radius as f64;
-
-    if centers_distance > radius_sum {
-      centers_distance - radius_sum
-    } else {
-      0.0
-    }
-  }
-}
-
-impl Distance<Circle<u8, u8>> for Circle<u8, u8> {
-  type Result = f64;
-
-  fn distance(&self, other: Circle<u8, u8>) -> Self::Result {
+  fn distance(&self, other: Circle<T, R>) -> Self::Result {
     self.distance(&other)
   }
 }
@@ -48,4 +34,12 @@ mod tests {
     let out = circle1.distance(&circle2);
     assert_approx_eq!(f64, out, expected, ulps = 2);
   }
+
+  #[test_case(Circle::new([0.25, 0.25].into(), 0.125), Circle::new([0.25, 0.75].into(), 0.125), 0.25)]
+  #[test_case(Circle::new([0.25, 0.25].into(), 0.25), Circle::new([0.25, 0.75].into(), 0.25), 0.0)]
+  #[test_case(Circle::new([0.25, 0.25].into(), 0.5), Circle::new([0.25, 0.75].into(), 0.25), 0.0)]
+  fn circle_distance_f64(circle1: Circle<f64, f64>, circle2: Circle<f64, f64>, expected: f64) {
+    let out = circle1.distance(&circle2);
+    assert_approx_eq!(f64, out, expected, ulps = 2);
+  }
 }
@@ -1,9 +1,5 @@
-use nalgebra::{Point2, Scalar};
-use num::traits::Unsigned;
-
-use crate::{
-  traits::Distance, Circle, Ellipse, FloatMath, Line, Rectangle, Shape, ShapeCollection, Triangle,
-};
+use crate::traits::{Distance, SignedDistance, Within};
+use crate::*;
 
 /// Calculate the squared distance between two points.
 ///
@@ -21,20 +17,25 @@ pub fn distance_squared<T>(a: &Point2<T>, b: &Point2<T>) -> f64
 where
   T: FloatMath,
 {
-  let x_max = if a.x > b.x { a.x } else { b.x };
-  let x_min = if a.x < b.x { a.x } else { b.x };
-  let y_max = if a.y > b.y { a.y } else { b.y };
-  let y_min = if a.y < b.y { a.y } else { b.y };
-
-  let x = x_max - x_min;
-  let y = y_max - y_min;
+  // Subtracting in `T` overflows for signed coordinates of opposite signs.
+  let a = a.map(Into::<f64>::into);
+  let b = b.map(Into::<f64>::into);
 
-  let x = Into::<f64>::into(x);
-  let y = Into::<f64>::into(y);
+  let x = a.x - b.x;
+  let y = a.y - b.y;
 
   x * x + y * y
 }
 
+/// Distance from a point relative to the center to an ellipse with a zero radius, which is
+/// the segment (or the single point) it collapses to.
+fn degenerate_ellipse_distance(local: &Point2<f64>, rx: f64, ry: f64) -> f64 {
+  let dx = (local.x.abs() - rx).max(0.0);
+  let dy = (local.y.abs() - ry).max(0.0);
+
+  (dx * dx + dy * dy).sqrt()
+}
+
 /// Calculate the distance between two points.
 ///
 /// # Example:
@@ -89,7 +90,11 @@ where
   }
 }
 
-impl Distance<&Point2<u8>> for Circle<u8, u8> {
+impl<T, R> Distance<&Point2<T>> for Circle<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = f64;
 
   /// Calculate the distance from the edge of the circle to the point.
@@ -104,7 +109,7 @@ impl Distance<&Point2<u8>> for Circle<u8, u8> {
   ///
   /// assert_eq!(circle.distance(&point), 5.0);
   /// ```
-  fn distance(&self, point: &Point2<u8>) -> Self::Result {
+  fn distance(&self, point: &Point2<T>) -> Self::Result {
     use crate::traits::Within;
 
     if self.within(point) {
@@ -113,21 +118,29 @@ impl Distance<&Point2<u8>> for Circle<u8, u8> {
 
     let distance_to_center = distance(&self.center, point);
 
-    distance_to_center - self.radius as f64
+    distance_to_center - Into::<f64>::into(self.radius)
   }
 }
-impl Distance<Point2<u8>> for Circle<u8, u8> {
+impl<T, R> Distance<Point2<T>> for Circle<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = f64;
 
-  fn distance(&self, point: Point2<u8>) -> Self::Result {
+  fn distance(&self, point: Point2<T>) -> Self::Result {
     self.distance(&point)
   }
 }
 
-impl Distance<&Point2<u8>> for Ellipse<u8, u8> {
+impl<T, R> Distance<&Point2<T>> for Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = f64;
 
-  fn distance(&self, point: &Point2<u8>) -> f64 {
+  fn distance(&self, point: &Point2<T>) -> f64 {
     use crate::traits::Within;
 
     if self.within(point) {
@@ -136,24 +149,38 @@ impl Distance<&Point2<u8>> for Ellipse<u8, u8> {
 
     // optimize if point is on the same axis as the center
     if point.x == self.center.x {
-      return distance(&self.center, point) - *self.height() as f64;
+      return distance(&self.center, point) - Into::<f64>::into(*self.height());
     } else if point.y == self.center.y {
-      return distance(&self.center, point) - *self.width() as f64;
+      return distance(&self.center, point) - Into::<f64>::into(*self.width());
+    }
+
+    let rx: f64 = (*self.width()).into();
+    let ry: f64 = (*self.height()).into();
+    if rx == 0.0 || ry == 0.0 {
+      let local = point.map(Into::<f64>::into) - self.center.map(Into::<f64>::into);
+      return degenerate_ellipse_distance(&local.into(), rx, ry);
     }
 
     let point_on_ellipse = self.point_intersection(point, 10);
-    distance(&point_on_ellipse, &point.map(|c| c as f64))
+    distance(&point_on_ellipse, &point.map(Into::<f64>::into))
   }
 }
-impl Distance<Point2<u8>> for Ellipse<u8, u8> {
+impl<T, R> Distance<Point2<T>> for Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = f64;
 
-  fn distance(&self, point: Point2<u8>) -> f64 {
+  fn distance(&self, point: Point2<T>) -> f64 {
     self.distance(&point)
   }
 }
 
-impl Distance<&Point2<u8>> for Line<u8> {
+impl<T> Distance<&Point2<T>> for Line<T>
+where
+  T: FloatMath,
+{
   type Result = f64;
 
   /// # Example
@@ -168,10 +195,10 @@ impl Distance<&Point2<u8>> for Line<u8> {
   /// assert_eq!(line.distance(&Point2::new(6, 7)), 1.0); // Point is above the line
   /// assert_eq!(line.distance(&Point2::new(4, 7)), 1.0); // Point is below the line
   /// ```
-  fn distance(&self, point: &Point2<u8>) -> f64 {
-    let xy = point.map(|x| x as f64);
-    let xy1 = self.start.map(|x| x as f64);
-    let xy2 = self.end.map(|x| x as f64);
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    let xy = point.map(Into::<f64>::into);
+    let xy1 = self.start.map(Into::<f64>::into);
+    let xy2 = self.end.map(Into::<f64>::into);
 
     let a = xy.x - xy1.x;
     let b = xy.y - xy1.y;
@@ -200,15 +227,21 @@ impl Distance<&Point2<u8>> for Line<u8> {
     distance(&xy, &Point2::new(xx, yy))
   }
 }
-impl Distance<Point2<u8>> for Line<u8> {
+impl<T> Distance<Point2<T>> for Line<T>
+where
+  T: FloatMath,
+{
   type Result = f64;
 
-  fn distance(&self, point: Point2<u8>) -> f64 {
+  fn distance(&self, point: Point2<T>) -> f64 {
     self.distance(&point)
   }
 }
 
-impl Distance<&Point2<u8>> for Rectangle<u8> {
+impl<T> Distance<&Point2<T>> for Rectangle<T>
+where
+  T: FloatMath,
+{
   type Result = f64;
 
   /// Calculate the distance from the edge of the rectangle to the point.
@@ -222,7 +255,7 @@ impl Distance<&Point2<u8>> for Rectangle<u8> {
   /// assert_eq!(rectangle.distance(&Point2::new(5, 5)), 0.0); // Point is inside the rectangle
   /// assert_eq!(rectangle.distance(&Point2::new(20, 10)), 10.0); // Point is to the right of the rectangle
   /// ```
-  fn distance(&self, point: &Point2<u8>) -> f64 {
+  fn distance(&self, point: &Point2<T>) -> f64 {
     use crate::traits::Within;
     if self.within(point) {
       return 0.0;
@@ -243,15 +276,105 @@ impl Distance<&Point2<u8>> for Rectangle<u8> {
     distances.iter().copied().fold(f64::MAX, f64::min)
   }
 }
-impl Distance<Point2<u8>> for Rectangle<u8> {
+impl<T> Distance<Point2<T>> for Rectangle<T>
+where
+  T: FloatMath,
+{
   type Result = f64;
 
-  fn distance(&self, point: Point2<u8>) -> f64 {
+  fn distance(&self, point: Point2<T>) -> f64 {
     self.distance(&point)
   }
 }
 
-impl Distance<&Point2<u8>> for Triangle<u8> {
+impl<T, R> Distance<&Point2<T>> for RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = f64;
+
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    use crate::traits::Within;
+
+    if self.within(point) {
+      return 0.0;
+    }
+
+    let local = self.local_point(point);
+    let rx: f64 = (*self.width()).into();
+    let ry: f64 = (*self.height()).into();
+
+    // optimize if point is on one of the axes of the ellipse
+    if local.x.abs() < f64::EPSILON {
+      return local.y.abs() - ry;
+    } else if local.y.abs() < f64::EPSILON {
+      return local.x.abs() - rx;
+    }
+
+    if rx == 0.0 || ry == 0.0 {
+      return degenerate_ellipse_distance(&local, rx, ry);
+    }
+
+    let axis_aligned = Ellipse::new(Point2::<f64>::origin(), (rx, ry));
+    let point_on_ellipse = axis_aligned.point_intersection(&local, 10);
+    distance(&point_on_ellipse, &local)
+  }
+}
+impl<T, R> Distance<Point2<T>> for RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = f64;
+
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
+  }
+}
+
+impl<T> Distance<&Point2<T>> for RotatedRectangle<T>
+where
+  T: FloatMath,
+{
+  type Result = f64;
+
+  /// Calculate the distance from the edge of the rotated rectangle to the point.
+  ///
+  /// # Example
+  /// ```rust
+  /// use core::f64::consts::FRAC_PI_2;
+  /// use rshapes::{Point2, RotatedRectangle, traits::Distance};
+  ///
+  /// let rectangle = RotatedRectangle::new(Point2::new(0.0, 0.0), (10.0, 2.0), FRAC_PI_2);
+  ///
+  /// assert_eq!(rectangle.distance(&Point2::new(0.0, 5.0)), 0.0); // Point is inside the rectangle
+  /// assert!((rectangle.distance(&Point2::new(5.0, 0.0)) - 3.0).abs() < 1e-9);
+  /// ```
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    let local = self.local_point(point);
+
+    let dx = (local.x.abs() - Into::<f64>::into(self.half_size.0)).max(0.0);
+    let dy = (local.y.abs() - Into::<f64>::into(self.half_size.1)).max(0.0);
+
+    dx.hypot(dy)
+  }
+}
+impl<T> Distance<Point2<T>> for RotatedRectangle<T>
+where
+  T: FloatMath,
+{
+  type Result = f64;
+
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
+  }
+}
+
+impl<T> Distance<&Point2<T>> for Triangle<T>
+where
+  T: FloatMath,
+{
   type Result = f64;
 
   /// Calculate the distance from the edge of the triangle to the point.
@@ -269,7 +392,7 @@ impl Distance<&Point2<u8>> for Triangle<u8> {
   /// assert_eq!(triangle.distance(&Point2::new(5, 5)), 0.0); // Point is inside the triangle
   /// assert_eq!(triangle.distance(&Point2::new(20, 10)).round(), 14.0); // Point is to the right of the triangle
   /// ```
-  fn distance(&self, point: &Point2<u8>) -> f64 {
+  fn distance(&self, point: &Point2<T>) -> f64 {
     // use crate::{Within, PointWithin};
     // if self.within(point) == true {
     //   return 0.0;
@@ -284,10 +407,91 @@ impl Distance<&Point2<u8>> for Triangle<u8> {
     distances.iter().copied().fold(f64::MAX, f64::min)
   }
 }
-impl Distance<Point2<u8>> for Triangle<u8> {
+impl<T> Distance<Point2<T>> for Triangle<T>
+where
+  T: FloatMath,
+{
+  type Result = f64;
+
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
+  }
+}
+
+impl<T> Distance<&Point2<T>> for Polygon<T>
+where
+  T: FloatMath,
+{
+  type Result = f64;
+
+  /// Calculate the distance from the outline of the polygon to the point.
+  /// If the point is inside the polygon, return 0.
+  ///
+  /// # Example
+  /// ```rust
+  /// use rshapes::{Point2, Polygon, traits::Distance};
+  ///
+  /// let polygon = Polygon::new(vec![
+  ///   Point2::new(0, 0),
+  ///   Point2::new(10, 0),
+  ///   Point2::new(10, 10),
+  ///   Point2::new(0, 10),
+  /// ]);
+  ///
+  /// assert_eq!(polygon.distance(&Point2::new(5, 5)), 0.0); // Point is inside the polygon
+  /// assert_eq!(polygon.distance(&Point2::new(20, 10)), 10.0); // Point is to the right of the polygon
+  /// ```
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    use crate::traits::Within;
+    if self.within(point) {
+      return 0.0;
+    }
+
+    self
+      .edges()
+      .map(|edge| edge.distance(point))
+      .fold(f64::MAX, f64::min)
+  }
+}
+impl<T> Distance<Point2<T>> for Polygon<T>
+where
+  T: FloatMath,
+{
   type Result = f64;
 
-  fn distance(&self, point: Point2<u8>) -> f64 {
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
+  }
+}
+
+impl<T> Distance<&Point2<T>> for PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  type Result = f64;
+
+  /// Calculate the distance from the closest ring of the polygon to the point.
+  /// If the point is inside the polygon, return 0.
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    use crate::traits::Within;
+    if self.within(point) {
+      return 0.0;
+    }
+
+    self
+      .rings()
+      .flat_map(|ring| ring.edges())
+      .map(|edge| edge.distance(point))
+      .fold(f64::MAX, f64::min)
+  }
+}
+impl<T> Distance<Point2<T>> for PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  type Result = f64;
+
+  fn distance(&self, point: Point2<T>) -> f64 {
     self.distance(&point)
   }
 }
@@ -295,31 +499,107 @@ impl Distance<Point2<u8>> for Triangle<u8> {
 impl<T, R> Distance<&Point2<T>> for ShapeCollection<T, R>
 where
   T: Scalar,
-  R: Scalar + Unsigned,
+  R: Scalar,
   Shape<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
 {
   type Result = f64;
 
   fn distance(&self, point: &Point2<T>) -> f64 {
-    let mut distances = Vec::new();
+    SliceShapeCollection::from(self).distance(point)
+  }
+}
 
-    for shape in self.shapes.iter() {
-      let distance = shape.distance(point);
-      distances.push(distance);
-    }
+impl<T, R> Distance<Point2<T>> for ShapeCollection<T, R>
+where
+  T: Scalar,
+  R: Scalar,
+  Shape<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+{
+  type Result = f64;
 
-    distances.iter().copied().fold(f64::MAX, f64::min)
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
   }
 }
 
-impl<T, R> Distance<Point2<T>> for ShapeCollection<T, R>
+impl<T, R, const N: usize> Distance<&Point2<T>> for FixedShapeCollection<T, R, N>
+where
+  T: Scalar,
+  R: Scalar,
+  Shape<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+{
+  type Result = f64;
+
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    SliceShapeCollection::from(self).distance(point)
+  }
+}
+
+impl<T, R, const N: usize> Distance<Point2<T>> for FixedShapeCollection<T, R, N>
+where
+  T: Scalar,
+  R: Scalar,
+  Shape<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+{
+  type Result = f64;
+
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
+  }
+}
+
+impl<T, R> Distance<&Point2<T>> for SliceShapeCollection<'_, T, R>
 where
   T: Scalar,
-  R: Scalar + Unsigned,
+  R: Scalar,
   Shape<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
 {
   type Result = f64;
 
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    self
+      .shapes()
+      .iter()
+      .map(|shape| shape.distance(point))
+      .fold(f64::MAX, f64::min)
+  }
+}
+
+impl<T, R> Distance<Point2<T>> for SliceShapeCollection<'_, T, R>
+where
+  T: Scalar,
+  R: Scalar,
+  Shape<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+{
+  type Result = f64;
+
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
+  }
+}
+
+impl<T, R> Distance<&Point2<T>> for IndexedCollection<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = f64;
+
+  /// Calculate the distance to the closest shape, like for [`ShapeCollection`].
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    self
+      .nearest(point)
+      .map_or(f64::MAX, |(_, distance)| distance)
+  }
+}
+
+impl<T, R> Distance<Point2<T>> for IndexedCollection<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = f64;
+
   fn distance(&self, point: Point2<T>) -> f64 {
     self.distance(&point)
   }
@@ -328,11 +608,15 @@ where
 impl<T, R> Distance<&Point2<T>> for Shape<T, R>
 where
   T: Scalar,
-  R: Scalar + Unsigned,
+  R: Scalar,
   Ellipse<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
   Circle<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
   Rectangle<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  RotatedEllipse<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  RotatedRectangle<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
   Triangle<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  Polygon<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  PolygonWithHoles<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
 {
   type Result = f64;
 
@@ -341,7 +625,11 @@ where
       Self::Ellipse(ellipse) => ellipse.distance(point),
       Self::Circle(circle) => circle.distance(point),
       Self::Rectangle(rectangle) => rectangle.distance(point),
+      Self::RotatedRectangle(rectangle) => rectangle.distance(point),
+      Self::RotatedEllipse(ellipse) => ellipse.distance(point),
       Self::Triangle(triangle) => triangle.distance(point),
+      Self::Polygon(polygon) => polygon.distance(point),
+      Self::PolygonWithHoles(polygon) => polygon.distance(point),
       Self::Collection(collection) => collection.distance(point),
     }
   }
@@ -349,11 +637,15 @@ where
 impl<T, R> Distance<Point2<T>> for Shape<T, R>
 where
   T: Scalar,
-  R: Scalar + Unsigned,
+  R: Scalar,
   Ellipse<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
   Circle<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
   Rectangle<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  RotatedEllipse<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  RotatedRectangle<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
   Triangle<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  Polygon<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
+  PolygonWithHoles<T>: for<'a> Distance<&'a Point2<T>, Result = f64>,
   ShapeCollection<T, R>: for<'a> Distance<&'a Point2<T>, Result = f64>,
 {
   type Result = f64;
@@ -363,13 +655,47 @@ where
   }
 }
 
+impl<T, R> Distance<&Point2<T>> for Csg<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = f64;
+
+  /// Returns 0 inside the expression, and the signed distance outside of it.
+  ///
+  /// This is exact for shapes and unions. For intersections, differences and symmetric
+  /// differences it is only a lower bound: the closest point of the expression can be
+  /// further away, e.g. past the corner where two operands of an intersection meet.
+  fn distance(&self, point: &Point2<T>) -> f64 {
+    if self.within(point) {
+      0.0
+    } else {
+      self.signed_distance(point).max(0.0)
+    }
+  }
+}
+
+impl<T, R> Distance<Point2<T>> for Csg<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = f64;
+
+  fn distance(&self, point: Point2<T>) -> f64 {
+    self.distance(&point)
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use crate::testing::PointView;
   use crate::{
-    distance, distance_squared, traits::Distance, Circle, Ellipse, Line, Point2, Rectangle, Shape,
-    Triangle,
+    distance, distance_squared, traits::Distance, Circle, Ellipse, Line, Point2, Polygon,
+    PolygonWithHoles, Rectangle, RotatedEllipse, RotatedRectangle, Shape, Triangle,
   };
+  use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, SQRT_2};
   use float_cmp::assert_approx_eq;
   use test_case::test_case;
   use test_strategy::proptest;
@@ -384,6 +710,18 @@ mod tests {
     distance_squared(&a, &b) as u32
   }
 
+  #[test_case(Point2::new(-30000, 0), Point2::new(30000, 0) => 3.6e9; "opposite signs")]
+  #[test_case(Point2::new(i16::MIN, i16::MIN), Point2::new(i16::MAX, i16::MAX) => 2.0 * 65535.0 * 65535.0; "extremes")]
+  fn distance_squared_i16(a: Point2<i16>, b: Point2<i16>) -> f64 {
+    distance_squared(&a, &b)
+  }
+
+  #[test_case(Point2::new(i32::MIN, 0), Point2::new(i32::MAX, 0) => 4294967295.0 * 4294967295.0; "extremes")]
+  #[test_case(Point2::new(0, i32::MAX), Point2::new(0, i32::MIN) => 4294967295.0 * 4294967295.0; "extremes reversed")]
+  fn distance_squared_i32(a: Point2<i32>, b: Point2<i32>) -> f64 {
+    distance_squared(&a, &b)
+  }
+
   #[proptest]
   fn distance_squared_u8_fuzz(a: PointView<u8, 2>, b: PointView<u8, 2>) {
     let _out = distance_squared(&a.into(), &b.into());
@@ -422,19 +760,60 @@ mod tests {
     let _out = circle.distance(point.0);
   }
 
+  #[test_case(Circle::<f32, f32>::new([0.5, 0.5].into(), 0.25), Point2::new(0.5, 0.5), 0.0f64; "point in the circle")]
+  #[test_case(Circle::<f32, f32>::new([0.5, 0.5].into(), 0.25), Point2::new(0.5, 0.75), 0.0f64; "point on the edge")]
+  #[test_case(Circle::<f32, f32>::new([0.5, 0.5].into(), 0.25), Point2::new(1.0, 0.5), 0.25f64; "point on side center")]
+  #[test_case(Circle::<f32, f32>::new([0.5, 0.5].into(), 0.25), Point2::new(1.0, 1.0), 0.457f64; "point outside")]
+  fn circle_distance_f32(circle: Circle<f32, f32>, point: Point2<f32>, expected: f64) {
+    assert_approx_eq!(f64, circle.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[test_case(Circle::<i16, u16>::new([-5, -5].into(), 10), Point2::new(-5, 5), 0.0f64; "point on the edge")]
+  #[test_case(Circle::<i16, u16>::new([-5, -5].into(), 10), Point2::new(-20, -5), 5.0f64; "point on side center")]
+  fn circle_distance_i16(circle: Circle<i16, u16>, point: Point2<i16>, expected: f64) {
+    assert_approx_eq!(f64, circle.distance(point), expected, epsilon = 0.001);
+  }
+
   #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (10, 5)), Point2::new(5, 5), 0.0f64; "point in the ellipse")]
   #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (10, 5)), Point2::new(5, 10), 0.0f64; "point on the edge / center - x axis")]
   #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (10, 5)), Point2::new(15, 5), 0.0f64; "point on the edge / center - y axis")]
   #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (10, 5)), Point2::new(5, 15), 5.0f64; "point to the right of the ellipse center")]
   #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (10, 5)), Point2::new(20, 5), 5.0f64; "point to the bottom of the ellipse center")]
   #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (10, 5)), Point2::new(15, 10), 2.788f64; "point on the bbox corner ")]
+  #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (0, 0)), Point2::new(8, 9), 5.0f64; "zero radius")]
+  #[test_case(Ellipse::<u8, u8>::new([5, 5].into(), (3, 0)), Point2::new(9, 8), 3.162f64; "zero height")]
   fn ellipse_distance_u8(ellipse: Ellipse<u8, u8>, point: Point2<u8>, expected: f64) {
     assert_approx_eq!(f64, ellipse.distance(point), expected, epsilon = 0.001);
   }
 
   #[proptest]
   fn ellipse_distance_u8_fuzz(ellipse: Ellipse<u8, u8>, point: PointView<u8, 2>) {
-    let _out = ellipse.distance(&point.into());
+    let _out = ellipse.distance(&Point2::from(point));
+  }
+
+  #[test_case(Ellipse::<f64, f64>::new([0.5, 0.5].into(), (1.0, 0.5)), Point2::new(0.5, 0.5), 0.0f64; "point in the ellipse")]
+  #[test_case(Ellipse::<f64, f64>::new([0.5, 0.5].into(), (1.0, 0.5)), Point2::new(0.5, 1.5), 0.5f64; "point to the right of the ellipse center")]
+  #[test_case(Ellipse::<f64, f64>::new([0.5, 0.5].into(), (1.0, 0.5)), Point2::new(2.0, 0.5), 0.5f64; "point to the bottom of the ellipse center")]
+  #[test_case(Ellipse::<f64, f64>::new([0.5, 0.5].into(), (1.0, 0.5)), Point2::new(1.5, 1.0), 0.2788f64; "point on the bbox corner ")]
+  fn ellipse_distance_f64(ellipse: Ellipse<f64, f64>, point: Point2<f64>, expected: f64) {
+    assert_approx_eq!(f64, ellipse.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[test_case(Point2::new(0.9, 0.8); "off axis")]
+  #[test_case(Point2::new(0.2, 0.3); "off axis, opposite")]
+  #[test_case(Point2::new(0.75, 0.45); "close to the outline")]
+  fn ellipse_distance_f64_matches_closest_point(point: Point2<f64>) {
+    use crate::traits::ClosestPoint;
+
+    let ellipse = Ellipse::<f64, f64>::new([0.5, 0.5].into(), (0.2, 0.1));
+    let closest = ellipse.closest_point(&point).unwrap().point;
+
+    assert_approx_eq!(
+      f64,
+      ellipse.distance(point),
+      crate::distance(&closest, &point),
+      epsilon = 1e-9
+    );
   }
 
   #[test_case(Line::new(Point2::new(5, 5), Point2::new(5, 10)), Point2::new(5, 7), 0.0f64; "point on the straight line")]
@@ -449,7 +828,20 @@ mod tests {
 
   #[proptest]
   fn line_distance_u8_fuzz(line: Line<u8>, point: PointView<u8, 2>) {
-    let _out = line.distance(&point.into());
+    let _out = line.distance(&Point2::from(point));
+  }
+
+  #[test_case(Line::new(Point2::new(0.5, 0.5), Point2::new(0.5, 1.0)), Point2::new(0.5, 0.75), 0.0f64; "point on the straight line")]
+  #[test_case(Line::new(Point2::new(0.5, 0.5), Point2::new(0.5, 1.0)), Point2::new(0.5, 0.25), 0.25f64; "point to the left of the straight line")]
+  #[test_case(Line::new(Point2::new(0.5, 0.5), Point2::new(0.5, 1.0)), Point2::new(0.75, 0.75), 0.25f64; "point above the straight line")]
+  #[test_case(Line::new(Point2::new(-5.0, -5.0), Point2::new(5.0, 5.0)), Point2::new(5.0, -5.0), 7.071f64; "point off the diagonal line")]
+  fn line_distance_f32(line: Line<f32>, point: Point2<f32>, expected: f64) {
+    assert_approx_eq!(f64, line.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[proptest]
+  fn line_distance_f64_fuzz(line: Line<f64>, point: PointView<f64, 2>) {
+    let _out = line.distance(&Point2::from(point));
   }
 
   #[test_case(Rectangle::new(Point2::new(0, 0), Point2::new(10, 10)), Point2::new(5, 5), 0.0f64; "point in the rectangle")]
@@ -460,7 +852,50 @@ mod tests {
 
   #[proptest]
   fn rectangle_distance_u8_fuzz(rectangle: Rectangle<u8>, point: PointView<u8, 2>) {
-    let _out = rectangle.distance(&point.into());
+    let _out = rectangle.distance(&Point2::from(point));
+  }
+
+  #[test_case(Rectangle::new(Point2::new(0.0, 0.0), Point2::new(0.5, 0.5)), Point2::new(0.25, 0.25), 0.0f64; "point in the rectangle")]
+  #[test_case(Rectangle::new(Point2::new(0.0, 0.0), Point2::new(0.5, 0.5)), Point2::new(1.0, 0.5), 0.5f64; "point to the right of the rectangle")]
+  #[test_case(Rectangle::new(Point2::new(0.0, 0.0), Point2::new(0.5, 0.5)), Point2::new(0.8, 0.9), 0.5f64; "point to the bottom-right of the rectangle")]
+  fn rectangle_distance_f64(rectangle: Rectangle<f64>, point: Point2<f64>, expected: f64) {
+    assert_approx_eq!(f64, rectangle.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[test_case(RotatedEllipse::new([20, 20].into(), (10, 5), 0.0), Point2::new(30, 25), 2.788f64; "not rotated, bbox corner")]
+  #[test_case(RotatedEllipse::new([20, 20].into(), (10, 5), FRAC_PI_2), Point2::new(25, 30), 2.788f64; "quarter turn, bbox corner")]
+  #[test_case(RotatedEllipse::new([20, 20].into(), (10, 5), FRAC_PI_2), Point2::new(20, 35), 5.0f64; "quarter turn, on the major axis")]
+  #[test_case(RotatedEllipse::new([20, 20].into(), (10, 5), FRAC_PI_2), Point2::new(20, 28), 0.0f64; "quarter turn, inside")]
+  #[test_case(RotatedEllipse::new([0, 0].into(), (0, 0), 0.0), Point2::new(1, 1), SQRT_2; "zero radius")]
+  #[test_case(RotatedEllipse::new([20, 20].into(), (0, 5), FRAC_PI_2), Point2::new(28, 21), 3.162f64; "zero width, quarter turn")]
+  fn rotated_ellipse_distance_u8(
+    ellipse: RotatedEllipse<u8, u8>,
+    point: Point2<u8>,
+    expected: f64,
+  ) {
+    assert_approx_eq!(f64, ellipse.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[proptest]
+  fn rotated_ellipse_distance_u8_fuzz(ellipse: RotatedEllipse<u8, u8>, point: PointView<u8, 2>) {
+    let _out = ellipse.distance(&Point2::from(point));
+  }
+
+  #[test_case(RotatedRectangle::new([20, 20].into(), (10, 2), 0.0), Point2::new(35, 20), 5.0f64; "not rotated, right")]
+  #[test_case(RotatedRectangle::new([20, 20].into(), (10, 2), FRAC_PI_2), Point2::new(26, 20), 4.0f64; "quarter turn, right")]
+  #[test_case(RotatedRectangle::new([20, 20].into(), (10, 2), FRAC_PI_2), Point2::new(25, 34), 5.0f64; "quarter turn, corner")]
+  #[test_case(RotatedRectangle::new([20, 20].into(), (10, 2), FRAC_PI_4), Point2::new(25, 25), 0.0f64; "diagonal, inside")]
+  fn rotated_rectangle_distance_u8(
+    rectangle: RotatedRectangle<u8>,
+    point: Point2<u8>,
+    expected: f64,
+  ) {
+    assert_approx_eq!(f64, rectangle.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[proptest]
+  fn rotated_rectangle_distance_u8_fuzz(rectangle: RotatedRectangle<u8>, point: PointView<u8, 2>) {
+    let _out = rectangle.distance(&Point2::from(point));
   }
 
   #[test_case(Triangle::new(Point2::new(0, 0), Point2::new(10, 0), Point2::new(0, 10)), Point2::new(5, 5), 0.0f64; "point in the triangle")]
@@ -471,7 +906,62 @@ mod tests {
 
   #[proptest]
   fn triangle_distance_u8_fuzz(triangle: Triangle<u8>, point: PointView<u8, 2>) {
-    let _out = triangle.distance(&point.into());
+    let _out = triangle.distance(&Point2::from(point));
+  }
+
+  #[test_case(Triangle::new(Point2::new(-10, -10), Point2::new(10, -10), Point2::new(0, 10)), Point2::new(0, -10), 0.0f64; "point on the triangle edge")]
+  #[test_case(Triangle::new(Point2::new(-10, -10), Point2::new(10, -10), Point2::new(0, 10)), Point2::new(0, -15), 5.0f64; "point below the triangle")]
+  fn triangle_distance_i32(triangle: Triangle<i32>, point: Point2<i32>, expected: f64) {
+    assert_approx_eq!(f64, triangle.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[test_case(Point2::new(5, 5), 0.0f64; "point in the polygon")]
+  #[test_case(Point2::new(15, 20), 5.0f64; "point in the notch")]
+  #[test_case(Point2::new(15, 12), 2.0f64; "point in the notch, close to the bottom")]
+  #[test_case(Point2::new(40, 30), 10.0f64; "point to the right of the polygon")]
+  #[test_case(Point2::new(33, 34), 5.0f64; "point outside the corner")]
+  fn polygon_distance_u8(point: Point2<u8>, expected: f64) {
+    let polygon = Polygon::new(vec![
+      Point2::new(0, 0),
+      Point2::new(30, 0),
+      Point2::new(30, 30),
+      Point2::new(20, 30),
+      Point2::new(20, 10),
+      Point2::new(10, 10),
+      Point2::new(10, 30),
+      Point2::new(0, 30),
+    ]);
+
+    assert_approx_eq!(f64, polygon.distance(point), expected, epsilon = 0.001);
+  }
+
+  #[proptest]
+  fn polygon_distance_u8_fuzz(polygon: Polygon<u8>, point: PointView<u8, 2>) {
+    let _out = polygon.distance(&Point2::from(point));
+  }
+
+  #[test_case(Point2::new(5, 5), 0.0f64; "point in the polygon")]
+  #[test_case(Point2::new(10, 10), 0.0f64; "point on the hole edge")]
+  #[test_case(Point2::new(15, 13), 2.0f64; "point in the hole")]
+  #[test_case(Point2::new(30, 15), 5.0f64; "point to the right of the polygon")]
+  fn polygon_with_holes_distance_u8(point: Point2<u8>, expected: f64) {
+    let polygon = PolygonWithHoles::new(
+      Polygon::new(vec![
+        Point2::new(0, 0),
+        Point2::new(25, 0),
+        Point2::new(25, 25),
+        Point2::new(0, 25),
+      ]),
+      vec![Polygon::new(vec![
+        Point2::new(10, 10),
+        Point2::new(20, 10),
+        Point2::new(20, 15),
+        Point2::new(10, 15),
+      ])],
+    )
+    .unwrap();
+
+    assert_approx_eq!(f64, polygon.distance(point), expected, epsilon = 0.001);
   }
 
   #[test]
@@ -499,4 +989,36 @@ mod tests {
       epsilon = 0.001
     );
   }
+
+  #[test]
+  fn shape_collection_distance_f32() {
+    let collection = crate::ShapeCollection::new(vec![
+      Shape::Circle(Circle::<f32, f32>::new([0.25, 0.25].into(), 0.25)),
+      Shape::Rectangle(Rectangle::new(Point2::new(0.5, 0.5), Point2::new(1.0, 1.0))),
+    ]);
+
+    assert_approx_eq!(f64, collection.distance(&Point2::new(0.25, 0.25)), 0.0);
+    assert_approx_eq!(f64, collection.distance(&Point2::new(0.75, 0.75)), 0.0);
+    assert_approx_eq!(f64, collection.distance(&Point2::new(1.25, 0.75)), 0.25);
+  }
+
+  #[test]
+  fn csg_distance_u8() {
+    let csg = crate::Csg::<u8, u8>::from(Circle::new(Point2::new(20, 20), 10))
+      .difference(Circle::new(Point2::new(20, 20), 5));
+
+    assert_eq!(csg.distance(&Point2::new(20, 28)), 0.0);
+    assert_eq!(csg.distance(&Point2::new(20, 25)), 0.0);
+    assert_eq!(csg.distance(&Point2::new(20, 22)), 3.0);
+    assert_eq!(csg.distance(&Point2::new(20, 33)), 3.0);
+  }
+
+  #[test]
+  fn csg_distance_is_a_lower_bound() {
+    let csg = crate::Csg::<u8, u8>::from(Rectangle::new(Point2::new(0, 0), Point2::new(10, 20)))
+      .intersection(Rectangle::new(Point2::new(0, 0), Point2::new(20, 10)));
+
+    // The closest point is the corner at (10, 10), about 7.07 away, but both operands are 5 away.
+    assert_eq!(csg.distance(&Point2::new(15, 15)), 5.0);
+  }
 }
@@ -0,0 +1,116 @@
+use crate::primitive;
+use crate::traits::{Distance, Geometry};
+use crate::*;
+
+// Shape-to-shape distances are the minimum distance between any two points of the shapes,
+// so overlapping shapes, and shapes inside each other, are 0 apart. Empty collections
+// are infinitely far away from everything.
+macro_rules! impl_distance {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<$($generic,)* S> Distance<&S> for $shape
+    where
+      $($generic: FloatMath,)*
+      S: Geometry,
+    {
+      type Result = f64;
+
+      fn distance(&self, other: &S) -> Self::Result {
+        primitive::distance(self, other)
+      }
+    }
+  )*};
+}
+
+impl_distance!(
+  Line<T> => [T];
+  Rectangle<T> => [T];
+  RotatedRectangle<T> => [T];
+  Circle<T, R> => [T, R];
+  Ellipse<T, R> => [T, R];
+  RotatedEllipse<T, R> => [T, R];
+  Triangle<T> => [T];
+  Polygon<T> => [T];
+  PolygonWithHoles<T> => [T];
+  ShapeCollection<T, R> => [T, R];
+  Shape<T, R> => [T, R];
+);
+
+#[cfg(test)]
+mod tests {
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::testing::{circle, collection, ellipse, rectangle, triangle};
+  use crate::traits::{Distance, Intersects};
+  use crate::{Line, Point2, Shape};
+
+  // Rectangle
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([5, 5], [15, 15]) => 0.0; "rectangle/rectangle overlapping")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([13, 0], [20, 10]) => 3.0; "rectangle/rectangle side by side")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([13, 14], [20, 20]) => 5.0; "rectangle/rectangle corner to corner")]
+  #[test_case(rectangle([0, 0], [10, 10]), circle([20, 5], 5) => 5.0; "rectangle/circle")]
+  #[test_case(rectangle([0, 0], [10, 10]), circle([5, 5], 50) => 0.0; "rectangle/circle around")]
+  #[test_case(rectangle([0, 0], [10, 10]), ellipse([5, 20], (10, 4)) => 6.0; "rectangle/ellipse")]
+  #[test_case(rectangle([0, 0], [10, 10]), triangle([12, 0], [20, 0], [20, 10]) => 2.0; "rectangle/triangle")]
+  #[test_case(rectangle([0, 0], [10, 10]), collection(vec![circle([30, 5], 5), circle([15, 5], 1)]) => 4.0; "rectangle/collection")]
+  #[test_case(rectangle([0, 0], [10, 10]), collection(vec![]) => f64::INFINITY; "rectangle/empty collection")]
+  // Circle
+  #[test_case(circle([0, 0], 5), circle([13, 0], 5) => 3.0; "circle/circle")]
+  #[test_case(circle([0, 0], 5), circle([1, 1], 1) => 0.0; "circle/circle nested")]
+  #[test_case(circle([0, 0], 5), ellipse([0, 20], (10, 5)) => 10.0; "circle/ellipse")]
+  #[test_case(circle([0, 0], 5), triangle([8, -5], [8, 5], [10, 0]) => 3.0; "circle/triangle")]
+  // Ellipse
+  #[test_case(ellipse([0, 0], (10, 2)), ellipse([0, 10], (10, 2)) => 6.0; "ellipse/ellipse")]
+  #[test_case(ellipse([0, 0], (10, 2)), ellipse([0, 0], (2, 10)) => 0.0; "ellipse/ellipse crossing")]
+  #[test_case(ellipse([0, 0], (10, 2)), triangle([-5, 5], [5, 5], [0, 10]) => 3.0; "ellipse/triangle")]
+  // Triangle
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), triangle([13, 0], [20, 0], [20, 10]) => 3.0; "triangle/triangle")]
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), triangle([5, 5], [10, 5], [5, 10]) => 0.0; "triangle/triangle touching")]
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), collection(vec![rectangle([-5, 2], [-3, 6])]) => 3.0; "triangle/collection")]
+  // Collection
+  #[test_case(collection(vec![circle([0, 0], 1), circle([10, 0], 1)]), collection(vec![rectangle([20, -1], [30, 1]), circle([10, 5], 2)]) => 2.0; "collection/collection")]
+  fn shape_distance(a: Shape<i32, u32>, b: Shape<i32, u32>) -> f64 {
+    let distance = a.distance(&b);
+    assert_approx_eq!(f64, distance, b.distance(&a), epsilon = 1e-9);
+
+    (distance * 1e6).round() / 1e6
+  }
+
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), rectangle([0, 3], [10, 10]) => 3.0; "rectangle")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), circle([5, 5], 2) => 3.0; "circle")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), ellipse([20, 0], (5, 1)) => 5.0; "ellipse")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 10)), rectangle([1, 0], [10, 1]) => 0.0; "crossing rectangle")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), collection(vec![circle([5, 10], 2), triangle([0, 4], [10, 4], [5, 10])]) => 4.0; "collection")]
+  fn line_distance(line: Line<i32>, shape: Shape<i32, u32>) -> f64 {
+    let distance = line.distance(&shape);
+    assert_approx_eq!(f64, distance, shape.distance(&line), epsilon = 1e-9);
+
+    (distance * 1e6).round() / 1e6
+  }
+
+  #[test]
+  fn line_distance_line() {
+    let line = Line::new(Point2::new(0, 0), Point2::new(10, 0));
+
+    assert_eq!(
+      line.distance(&Line::new(Point2::new(0, 3), Point2::new(10, 13))),
+      3.0
+    );
+    assert_eq!(
+      line.distance(&Line::new(Point2::new(5, -5), Point2::new(5, 5))),
+      0.0
+    );
+  }
+
+  #[proptest]
+  fn shape_distance_u8_fuzz(a: ShapeView<u8, u8>, b: ShapeView<u8, u8>) {
+    let a: Shape<u8, u8> = a.into();
+    let b: Shape<u8, u8> = b.into();
+    let distance = a.distance(&b);
+
+    assert!(distance >= 0.0);
+    assert_eq!(distance == 0.0, a.intersects(&b));
+  }
+}
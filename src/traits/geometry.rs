@@ -0,0 +1,30 @@
+use crate::primitive::{AsPolygon, AsPrimitives};
+
+/// Any shape of this crate, the operand type of the shape-versus-shape queries.
+///
+/// Bounds [`Intersects`](crate::traits::Intersects), [`Within`](crate::traits::Within) and
+/// [`Distance`](crate::traits::Distance) between two shapes, so generic code can accept any
+/// shape the queries support. The trait is sealed: it is implemented for every shape and
+/// references to them, and can't be implemented outside of the crate.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, Rectangle, traits::{Geometry, Intersects}};
+///
+/// fn touches_zone<S: Geometry>(shape: &S) -> bool {
+///   Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(10, 10)).intersects(shape)
+/// }
+///
+/// assert!(touches_zone(&Circle::<u8, u8>::new(Point2::new(15, 5), 5)));
+/// ```
+pub trait Geometry: AsPrimitives {}
+
+impl<S: AsPrimitives> Geometry for S {}
+
+/// Shapes bounded by straight edges, the operand type of [`Clip`](crate::traits::Clip).
+///
+/// Sealed like [`Geometry`], and implemented for rectangles, rotated rectangles, triangles,
+/// polygons and polygons with holes.
+pub trait Polygonal: AsPolygon {}
+
+impl<S: AsPolygon> Polygonal for S {}
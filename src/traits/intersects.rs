@@ -0,0 +1,188 @@
+use super::Geometry;
+use crate::primitive;
+use crate::*;
+
+/// Generic trait to determine if two shapes overlap.
+///
+/// Shapes which only touch each other intersect, following the boundary-inclusive
+/// convention of [`Within`](crate::traits::Within).
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, Rectangle, traits::Intersects};
+///
+/// let zone = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(10, 10));
+///
+/// assert!(Circle::<u8, u8>::new(Point2::new(15, 5), 5).intersects(&zone));
+/// assert!(!Circle::<u8, u8>::new(Point2::new(14, 14), 5).intersects(&zone));
+/// ```
+pub trait Intersects<T> {
+  type Result;
+
+  fn intersects(&self, other: T) -> Self::Result;
+}
+
+macro_rules! impl_intersects {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<$($generic,)* S> Intersects<&S> for $shape
+    where
+      $($generic: FloatMath,)*
+      S: Geometry,
+    {
+      type Result = bool;
+
+      fn intersects(&self, other: &S) -> Self::Result {
+        primitive::intersects(self, other)
+      }
+    }
+  )*};
+}
+
+impl_intersects!(
+  Line<T> => [T];
+  Rectangle<T> => [T];
+  RotatedRectangle<T> => [T];
+  Circle<T, R> => [T, R];
+  Ellipse<T, R> => [T, R];
+  RotatedEllipse<T, R> => [T, R];
+  Triangle<T> => [T];
+  Polygon<T> => [T];
+  PolygonWithHoles<T> => [T];
+  ShapeCollection<T, R> => [T, R];
+  Shape<T, R> => [T, R];
+);
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::FRAC_PI_4;
+
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::testing::{circle, collection, ellipse, rectangle, triangle};
+  use crate::traits::{Intersects, Within};
+  use crate::{
+    Circle, Line, Point2, Polygon, PolygonWithHoles, RotatedEllipse, RotatedRectangle, Shape,
+  };
+
+  // Rectangle
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([5, 5], [15, 15]) => true; "rectangle/rectangle overlapping")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10]) => true; "rectangle/rectangle sharing an edge")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([2, 2], [4, 4]) => true; "rectangle/rectangle nested")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([11, 0], [20, 10]) => false; "rectangle/rectangle apart")]
+  #[test_case(rectangle([0, 0], [10, 10]), circle([15, 5], 5) => true; "rectangle/circle touching")]
+  #[test_case(rectangle([0, 0], [10, 10]), circle([14, 14], 5) => false; "rectangle/circle near the corner")]
+  #[test_case(rectangle([0, 0], [10, 10]), circle([5, 5], 50) => true; "rectangle/circle around")]
+  #[test_case(rectangle([0, 0], [10, 10]), ellipse([20, 5], (10, 2)) => true; "rectangle/ellipse touching")]
+  #[test_case(rectangle([0, 0], [10, 10]), ellipse([5, 20], (10, 9)) => false; "rectangle/ellipse apart")]
+  #[test_case(rectangle([0, 0], [10, 10]), triangle([10, 10], [20, 10], [20, 20]) => true; "rectangle/triangle sharing a corner")]
+  #[test_case(rectangle([0, 0], [10, 10]), triangle([11, 0], [20, 0], [20, 20]) => false; "rectangle/triangle apart")]
+  #[test_case(rectangle([0, 0], [10, 10]), collection(vec![circle([30, 30], 2), circle([5, 5], 1)]) => true; "rectangle/collection")]
+  #[test_case(rectangle([0, 0], [10, 10]), collection(vec![]) => false; "rectangle/empty collection")]
+  // Circle
+  #[test_case(circle([0, 0], 5), circle([10, 0], 5) => true; "circle/circle touching")]
+  #[test_case(circle([0, 0], 5), circle([8, 8], 5) => false; "circle/circle apart")]
+  #[test_case(circle([0, 0], 5), circle([1, 1], 1) => true; "circle/circle nested")]
+  #[test_case(circle([0, 0], 5), ellipse([15, 0], (10, 1)) => true; "circle/ellipse touching")]
+  #[test_case(circle([0, 0], 5), ellipse([9, 9], (3, 6)) => false; "circle/ellipse apart")]
+  #[test_case(circle([0, 0], 5), triangle([5, -5], [5, 5], [10, 0]) => true; "circle/triangle touching")]
+  #[test_case(circle([0, 0], 5), triangle([4, 4], [10, 4], [10, 10]) => false; "circle/triangle near the vertex")]
+  #[test_case(circle([0, 0], 1), triangle([-10, -10], [10, -10], [0, 10]) => true; "circle/triangle inside")]
+  #[test_case(circle([0, 0], 5), collection(vec![rectangle([6, 6], [8, 8])]) => false; "circle/collection")]
+  // Ellipse
+  #[test_case(ellipse([0, 0], (10, 2)), ellipse([0, 4], (10, 2)) => true; "ellipse/ellipse touching")]
+  #[test_case(ellipse([0, 0], (10, 2)), ellipse([0, 0], (2, 10)) => true; "ellipse/ellipse crossing")]
+  #[test_case(ellipse([0, 0], (10, 2)), ellipse([9, 9], (10, 2)) => false; "ellipse/ellipse apart")]
+  #[test_case(ellipse([0, 0], (10, 2)), triangle([0, 3], [10, 3], [5, 10]) => false; "ellipse/triangle apart")]
+  #[test_case(ellipse([0, 0], (10, 2)), triangle([0, 2], [10, 3], [5, 10]) => true; "ellipse/triangle touching")]
+  #[test_case(ellipse([0, 0], (10, 0)), rectangle([-1, -1], [1, 1]) => true; "degenerate ellipse/rectangle")]
+  #[test_case(ellipse([0, 0], (10, 2)), collection(vec![collection(vec![circle([0, 0], 1)])]) => true; "ellipse/nested collection")]
+  // Triangle
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), triangle([5, 5], [10, 5], [5, 10]) => true; "triangle/triangle touching")]
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), triangle([6, 6], [10, 6], [6, 10]) => false; "triangle/triangle apart")]
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), triangle([-5, -5], [30, -5], [-5, 30]) => true; "triangle/triangle nested")]
+  #[test_case(triangle([0, 0], [10, 0], [0, 10]), collection(vec![triangle([6, 6], [10, 6], [6, 10])]) => false; "triangle/collection")]
+  // Collection
+  #[test_case(collection(vec![circle([0, 0], 1), circle([10, 10], 1)]), collection(vec![rectangle([9, 10], [9, 10])]) => true; "collection/collection")]
+  fn shape_intersects(a: Shape<i32, u32>, b: Shape<i32, u32>) -> bool {
+    let result = a.intersects(&b);
+    assert_eq!(result, b.intersects(&a), "intersection must be symmetric");
+    result
+  }
+
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 10)), rectangle([4, 0], [10, 4]) => true; "rectangle")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 10)), rectangle([6, 0], [10, 4]) => false; "rectangle apart")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 10)), circle([10, 0], 7) => false; "circle apart")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 10)), circle([10, 0], 8) => true; "circle")]
+  #[test_case(Line::new(Point2::new(1, 1), Point2::new(2, 2)), circle([0, 0], 8) => true; "inside circle")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 10)), ellipse([0, 10], (5, 5)) => false; "ellipse apart")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), triangle([5, 0], [10, 5], [5, 5]) => true; "triangle")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), collection(vec![circle([20, 0], 9), circle([20, 0], 10)]) => true; "collection")]
+  fn line_intersects(line: Line<i32>, shape: Shape<i32, u32>) -> bool {
+    let result = line.intersects(&shape);
+    assert_eq!(
+      result,
+      shape.intersects(&line),
+      "intersection must be symmetric"
+    );
+    result
+  }
+
+  #[test]
+  fn line_intersects_line() {
+    let line = Line::new(Point2::new(0, 0), Point2::new(10, 10));
+
+    assert!(line.intersects(&Line::new(Point2::new(0, 10), Point2::new(10, 0))));
+    assert!(!line.intersects(&Line::new(Point2::new(1, 0), Point2::new(11, 10))));
+  }
+
+  #[test]
+  fn rotated_shapes_intersect() {
+    let rectangle = RotatedRectangle::new(Point2::new(0.0, 0.0), (10.0, 1.0), FRAC_PI_4);
+    let ellipse = RotatedEllipse::<f64, f64>::new(Point2::new(0.0, 0.0), (10.0, 1.0), -FRAC_PI_4);
+
+    assert!(rectangle.intersects(&ellipse));
+    assert!(rectangle.intersects(&Circle::<f64, f64>::new(Point2::new(7.0, 7.0), 0.5)));
+    assert!(!rectangle.intersects(&Circle::<f64, f64>::new(Point2::new(7.0, -7.0), 0.5)));
+    assert!(!ellipse.intersects(&Circle::<f64, f64>::new(Point2::new(7.0, 7.0), 0.5)));
+  }
+
+  #[test]
+  fn polygon_with_holes_intersects() {
+    let square = |min: i32, max: i32| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let polygon = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+
+    assert!(!polygon.intersects(&Circle::<i32, u32>::new(Point2::new(15, 15), 4)));
+    assert!(polygon.intersects(&Circle::<i32, u32>::new(Point2::new(15, 15), 5)));
+    assert!(polygon.intersects(&square(12, 40)));
+    assert!(polygon.intersects(&square(-10, 40)));
+  }
+
+  #[proptest]
+  fn shape_intersects_u8_fuzz(a: ShapeView<u8, u8>, b: ShapeView<u8, u8>) {
+    let a: Shape<u8, u8> = a.into();
+    let b: Shape<u8, u8> = b.into();
+
+    assert_eq!(a.intersects(&b), b.intersects(&a));
+  }
+
+  #[proptest]
+  fn shape_intersects_point_within_u8(a: ShapeView<u8, u8>, point: crate::testing::PointView2<u8>) {
+    let a: Shape<u8, u8> = a.into();
+    let point: Point2<u8> = point.into();
+
+    // A point is a degenerate line, so it intersects the shape exactly when it is within.
+    let line = Line::new(point, point);
+    if a.within(&point) {
+      assert!(line.intersects(&a));
+    }
+  }
+}
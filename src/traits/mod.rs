@@ -1,11 +1,31 @@
+mod area;
 mod bbox;
 mod centroid;
+mod clip;
+mod closest_point;
+mod coverage;
 mod distance;
+mod geometry;
+mod intersects;
+mod perimeter;
 mod points_inside;
+mod rasterize;
+mod signed_distance;
+mod transform;
 mod within;
 
+pub use area::*;
 pub use bbox::*;
 pub use centroid::*;
+pub use clip::*;
+pub use closest_point::*;
+pub use coverage::*;
 pub use distance::*;
+pub use geometry::*;
+pub use intersects::*;
+pub use perimeter::*;
 pub use points_inside::*;
+pub use rasterize::*;
+pub use signed_distance::*;
+pub use transform::*;
 pub use within::*;
@@ -0,0 +1,135 @@
+use crate::primitive;
+use crate::*;
+
+/// Generic trait to calculate the length of the outline of a shape.
+///
+/// The outlines of holes are included. For collections only the outline of the covered area
+/// counts, the parts of the members' outlines inside other members are left out. Lines have
+/// their length as perimeter.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Point2, Rectangle, ShapeCollection, traits::Perimeter};
+///
+/// let zone = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(10, 10));
+/// let zones = ShapeCollection::<u8, u8>::new(vec![
+///   zone.into(),
+///   Rectangle::new(Point2::new(10, 0), Point2::new(20, 10)).into(),
+/// ]);
+///
+/// assert_eq!(zone.perimeter(), 40.0);
+/// assert_eq!(zones.perimeter(), 60.0);
+/// ```
+pub trait Perimeter {
+  fn perimeter(&self) -> f64;
+}
+
+impl<U: Perimeter> Perimeter for &U {
+  fn perimeter(&self) -> f64 {
+    U::perimeter(*self)
+  }
+}
+
+impl<T> Perimeter for Line<T>
+where
+  T: FloatMath,
+{
+  fn perimeter(&self) -> f64 {
+    crate::distance(&self.start, &self.end)
+  }
+}
+
+macro_rules! impl_perimeter {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<$($generic,)*> Perimeter for $shape
+    where
+      $($generic: FloatMath,)*
+    {
+      fn perimeter(&self) -> f64 {
+        primitive::perimeter(self)
+      }
+    }
+  )*};
+}
+
+impl_perimeter!(
+  Rectangle<T> => [T];
+  RotatedRectangle<T> => [T];
+  Circle<T, R> => [T, R];
+  Ellipse<T, R> => [T, R];
+  RotatedEllipse<T, R> => [T, R];
+  Triangle<T> => [T];
+  Polygon<T> => [T];
+  PolygonWithHoles<T> => [T];
+  ShapeCollection<T, R> => [T, R];
+  Shape<T, R> => [T, R];
+);
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::PI;
+
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+
+  use super::Perimeter;
+  use crate::testing::{circle, collection, ellipse, rectangle, triangle};
+  use crate::{
+    Circle, Line, Point2, Polygon, PolygonWithHoles, RotatedEllipse, Shape, ShapeCollection,
+  };
+
+  #[test_case(rectangle([0, 0], [10, 20]) => 60.0; "rectangle")]
+  #[test_case(circle([0, 0], 10) => 62.831853072; "circle")]
+  #[test_case(ellipse([0, 0], (2, 1)) => 9.688448221; "ellipse")]
+  #[test_case(ellipse([0, 0], (5, 0)) => 20.0; "flat ellipse")]
+  #[test_case(triangle([0, 0], [3, 0], [0, 4]) => 12.0; "triangle")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([20, 0], [30, 10])]) => 80.0; "collection apart")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10])]) => 60.0; "collection touching")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([5, 5], [15, 15])]) => 60.0; "collection overlapping")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), triangle([0, 0], [10, 0], [0, 10])]) => 40.0; "collection inside")]
+  #[test_case(collection(vec![]) => 0.0; "empty collection")]
+  fn shape_perimeter(shape: Shape<i32, u32>) -> f64 {
+    (shape.perimeter() * 1e9).round() / 1e9
+  }
+
+  #[test]
+  fn line_perimeter() {
+    assert_eq!(
+      Line::new(Point2::new(0, 0), Point2::new(3, 4)).perimeter(),
+      5.0
+    );
+  }
+
+  #[test]
+  fn rotated_ellipse_perimeter() {
+    let ellipse = RotatedEllipse::new(Point2::new(0.0, 0.0), (2.0, 1.0), 1.0);
+
+    assert_approx_eq!(f64, ellipse.perimeter(), 9.688448220547675, epsilon = 1e-9);
+  }
+
+  #[test]
+  fn polygon_with_holes_perimeter() {
+    let square = |min: i32, max: i32| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let polygon = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+
+    assert_eq!(polygon.perimeter(), 160.0);
+  }
+
+  #[test]
+  fn overlapping_circles_perimeter() {
+    // Two unit circles with their centers one radius apart, each loses a third of its outline.
+    let circles = ShapeCollection::<f64, f64>::new(vec![
+      Circle::new(Point2::new(0.0, 0.0), 1.0).into(),
+      Circle::new(Point2::new(1.0, 0.0), 1.0).into(),
+    ]);
+
+    assert_approx_eq!(f64, circles.perimeter(), 8.0 * PI / 3.0, epsilon = 1e-3);
+  }
+}
@@ -1,119 +1,203 @@
-use crate::traits::{BoundingBox, Within};
-use crate::{Circle, Ellipse, Point2, Rectangle, Scalar, Shape, ShapeCollection, Triangle};
-use std::collections::HashSet;
-
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::traits::rasterize::next_span;
+use crate::traits::{Rasterize, RowSpans, SpansIter};
+use crate::{
+  Circle, Csg, Ellipse, FixedShapeCollection, FloatMath, Point2, Polygon, PolygonWithHoles,
+  Rectangle, RotatedEllipse, RotatedRectangle, Scalar, Shape, ShapeCollection,
+  SliceShapeCollection, Triangle,
+};
+
+/// Generic trait to list the integer points inside a shape.
+///
+/// The points are taken from the [`Rasterize`] spans of the shape.
+/// Points are visited in scanline order: row by row from the smallest to the largest `y`,
+/// and from the smallest to the largest `x` within a row. Every point is visited once, even
+/// if it is inside several members of a collection.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Point2, Rectangle, traits::PointsInside};
+///
+/// let rectangle = Rectangle::new(Point2::new(0, 0), Point2::new(2, 1));
+/// assert_eq!(rectangle.points_inside(), vec![
+///   Point2::new(0, 0),
+///   Point2::new(1, 0),
+///   Point2::new(2, 0),
+///   Point2::new(0, 1),
+///   Point2::new(1, 1),
+///   Point2::new(2, 1),
+/// ]);
+///
+/// let mut points = rectangle.points_inside_iter();
+/// assert_eq!(points.nth(4), Some(Point2::new(1, 1)));
+/// ```
 pub trait PointsInside<T: Scalar> {
-  fn points_inside(&self) -> Vec<Point2<T>>;
+  type Iter<'a>: Iterator<Item = Point2<T>>
+  where
+    Self: 'a;
+
+  /// Returns an iterator yielding the points lazily, without allocating.
+  fn points_inside_iter(&self) -> Self::Iter<'_>;
+
+  /// Collects all points into a vector.
+  fn points_inside(&self) -> Vec<Point2<T>> {
+    self.points_inside_iter().collect()
+  }
 }
 
 impl<T: Scalar, U: PointsInside<T>> PointsInside<T> for &U {
+  type Iter<'a>
+    = U::Iter<'a>
+  where
+    Self: 'a;
+
   #[cfg_attr(test, mutants::skip)]
-  fn points_inside(&self) -> Vec<Point2<T>> {
-    U::points_inside(*self)
+  fn points_inside_iter(&self) -> Self::Iter<'_> {
+    U::points_inside_iter(self)
   }
 }
 
-impl PointsInside<u8> for Shape<u8, u8> {
-  fn points_inside(&self) -> Vec<Point2<u8>> {
-    match self {
-      Shape::Ellipse(ellipse) => ellipse.points_inside(),
-      Shape::Circle(circle) => circle.points_inside(),
-      Shape::Rectangle(rectangle) => rectangle.points_inside(),
-      Shape::Triangle(triangle) => triangle.points_inside(),
-      Shape::Collection(collection) => collection.points_inside(),
+/// Lazy iterator over the integer points inside any of a slice of shapes, in scanline order.
+///
+/// Created by [`PointsInside::points_inside_iter`].
+#[derive(Debug, Clone)]
+pub struct PointsInsideIter<'a, S, T> {
+  spans: SpansIter<'a, S, T>,
+  /// Next point to yield and the end of its span, the span is done once `x` is past `end`.
+  x: f64,
+  y: f64,
+  end: f64,
+}
+
+impl<'a, S, T> From<SpansIter<'a, S, T>> for PointsInsideIter<'a, S, T> {
+  fn from(spans: SpansIter<'a, S, T>) -> Self {
+    Self {
+      spans,
+      x: 0.0,
+      y: 0.0,
+      end: -1.0,
     }
   }
 }
 
-impl PointsInside<u8> for Circle<u8, u8> {
-  fn points_inside(&self) -> Vec<Point2<u8>> {
-    self
-      .bbox()
-      .points_inside()
-      .into_iter()
-      .filter(|point| self.within(point))
-      .collect()
+impl<S, T> Iterator for PointsInsideIter<'_, S, T>
+where
+  S: RowSpans<T>,
+  T: FloatMath,
+{
+  type Item = Point2<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.x > self.end {
+      (self.y, self.x, self.end) = next_span(&mut self.spans)?;
+    }
+
+    let point = Point2::new(T::from_f64(self.x), T::from_f64(self.y));
+    self.x += 1.0;
+
+    Some(point)
   }
 }
 
-impl PointsInside<u8> for Ellipse<u8, u8> {
-  fn points_inside(&self) -> Vec<Point2<u8>> {
-    self
-      .bbox()
-      .points_inside()
-      .into_iter()
-      .filter(|point| self.within(*point))
-      .collect()
-  }
+impl<S, T> FusedIterator for PointsInsideIter<'_, S, T>
+where
+  S: RowSpans<T>,
+  T: FloatMath,
+{
 }
 
-impl PointsInside<u8> for Rectangle<u8> {
-  /// Returns a vector of all points inside the rectangle.
-  ///
-  /// # Example
-  /// ```rust
-  /// use rshapes::{Point2, Rectangle, traits::PointsInside};
-  ///
-  /// let rectangle = Rectangle::new(Point2::new(0, 0), Point2::new(2, 2));
-  /// assert_eq!(rectangle.points_inside(), vec![
-  ///   Point2::new(0, 0),
-  ///   Point2::new(0, 1),
-  ///   Point2::new(0, 2),
-  ///   Point2::new(1, 0),
-  ///   Point2::new(1, 1),
-  ///   Point2::new(1, 2),
-  ///   Point2::new(2, 0),
-  ///   Point2::new(2, 1),
-  ///   Point2::new(2, 2),
-  /// ]);
-  /// ```
-  #[cfg_attr(test, mutants::skip)] // Prevent vector capacity from being mutated
-  fn points_inside(&self) -> Vec<Point2<u8>> {
-    let mut points = Vec::with_capacity(self.width() as usize * self.height() as usize);
-
-    for x in self.min().x..self.max().x.saturating_add(1) {
-      for y in self.min().y..self.max().y.saturating_add(1) {
-        points.push(Point2::new(x, y));
+macro_rules! impl_points_inside {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<T, $($generic,)*> PointsInside<T> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      type Iter<'a>
+        = PointsInsideIter<'a, Self, T>
+      where
+        Self: 'a;
+
+      fn points_inside_iter(&self) -> Self::Iter<'_> {
+        self.spans().into()
       }
     }
+  )*};
+}
 
-    points
+impl_points_inside!(
+  Rectangle<T> => [];
+  RotatedRectangle<T> => [];
+  Circle<T, R> => [R];
+  Ellipse<T, R> => [R];
+  RotatedEllipse<T, R> => [R];
+  Triangle<T> => [];
+  Polygon<T> => [];
+  PolygonWithHoles<T> => [];
+  Csg<T, R> => [R];
+  Shape<T, R> => [R];
+);
+
+impl<T, U> PointsInside<T> for ShapeCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Iter<'a>
+    = PointsInsideIter<'a, Shape<T, U>, T>
+  where
+    Self: 'a;
+
+  fn points_inside_iter(&self) -> Self::Iter<'_> {
+    SliceShapeCollection::from(self).points_inside_iter()
   }
 }
 
-impl PointsInside<u8> for Triangle<u8> {
-  fn points_inside(&self) -> Vec<Point2<u8>> {
-    self
-      .bbox()
-      .points_inside()
-      .into_iter()
-      .filter(|point| self.within(*point))
-      .collect()
+impl<T, U, const N: usize> PointsInside<T> for FixedShapeCollection<T, U, N>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Iter<'a>
+    = PointsInsideIter<'a, Shape<T, U>, T>
+  where
+    Self: 'a;
+
+  fn points_inside_iter(&self) -> Self::Iter<'_> {
+    SliceShapeCollection::from(self).points_inside_iter()
   }
 }
 
-impl PointsInside<u8> for ShapeCollection<u8, u8> {
-  fn points_inside(&self) -> Vec<Point2<u8>> {
-    let mut points = Vec::new();
-    for geometry in &self.shapes {
-      points.extend(geometry.points_inside());
-    }
-    points
-      .into_iter()
-      .collect::<HashSet<_>>()
-      .into_iter()
-      .collect()
+impl<'a, T, U> PointsInside<T> for SliceShapeCollection<'a, T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Iter<'b>
+    = PointsInsideIter<'a, Shape<T, U>, T>
+  where
+    Self: 'b;
+
+  fn points_inside_iter(&self) -> Self::Iter<'_> {
+    self.spans().into()
   }
 }
 
 #[cfg(test)]
 mod tests {
+  use alloc::vec::Vec;
+
   use crate::proptest::ShapeView;
-  use crate::traits::Within;
+  use crate::traits::{BoundingBox, Within};
   use crate::{
-    assert_vec_eq, assert_vec_eq_unordered, traits::PointsInside, Circle, Ellipse, Point2,
-    Rectangle, Shape, ShapeCollection, Triangle,
+    assert_vec_eq, assert_vec_eq_unordered, traits::PointsInside, Circle, Csg, Ellipse, Point2,
+    Polygon, PolygonWithHoles, Rectangle, RotatedEllipse, RotatedRectangle, Shape, ShapeCollection,
+    Triangle,
   };
+  use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+  use proptest::prelude::ProptestConfig;
   use test_strategy::proptest;
 
   #[test]
@@ -122,19 +206,19 @@ mod tests {
     let points = circle.points_inside();
 
     let expected: Vec<Point2<u8>> = vec![
-      Point2::new(3, 5),
-      Point2::new(4, 4),
-      Point2::new(4, 5),
-      Point2::new(4, 6),
       Point2::new(5, 3),
+      Point2::new(4, 4),
       Point2::new(5, 4),
-      Point2::new(5, 5),
-      Point2::new(5, 6),
-      Point2::new(5, 7),
       Point2::new(6, 4),
+      Point2::new(3, 5),
+      Point2::new(4, 5),
+      Point2::new(5, 5),
       Point2::new(6, 5),
-      Point2::new(6, 6),
       Point2::new(7, 5),
+      Point2::new(4, 6),
+      Point2::new(5, 6),
+      Point2::new(6, 6),
+      Point2::new(5, 7),
     ];
 
     assert_vec_eq!(points, expected);
@@ -142,36 +226,36 @@ mod tests {
 
   #[test]
   fn circle_points_inside_u8_edge() {
-    let circle = Circle::new(Point2::new(0, 0), 5);
+    let circle = Circle::<u8, u8>::new(Point2::new(0, 0), 5);
     let points = circle.points_inside();
 
     let expected = vec![
       Point2::new(0, 0),
-      Point2::new(0, 1),
-      Point2::new(0, 2),
-      Point2::new(0, 3),
-      Point2::new(0, 4),
-      Point2::new(0, 5),
       Point2::new(1, 0),
-      Point2::new(1, 1),
-      Point2::new(1, 2),
-      Point2::new(1, 3),
-      Point2::new(1, 4),
       Point2::new(2, 0),
-      Point2::new(2, 1),
-      Point2::new(2, 2),
-      Point2::new(2, 3),
-      Point2::new(2, 4),
       Point2::new(3, 0),
-      Point2::new(3, 1),
-      Point2::new(3, 2),
-      Point2::new(3, 3),
-      Point2::new(3, 4),
       Point2::new(4, 0),
+      Point2::new(5, 0),
+      Point2::new(0, 1),
+      Point2::new(1, 1),
+      Point2::new(2, 1),
+      Point2::new(3, 1),
       Point2::new(4, 1),
+      Point2::new(0, 2),
+      Point2::new(1, 2),
+      Point2::new(2, 2),
+      Point2::new(3, 2),
       Point2::new(4, 2),
+      Point2::new(0, 3),
+      Point2::new(1, 3),
+      Point2::new(2, 3),
+      Point2::new(3, 3),
       Point2::new(4, 3),
-      Point2::new(5, 0),
+      Point2::new(0, 4),
+      Point2::new(1, 4),
+      Point2::new(2, 4),
+      Point2::new(3, 4),
+      Point2::new(0, 5),
     ];
 
     assert_vec_eq!(points, expected);
@@ -182,19 +266,74 @@ mod tests {
     let _out = circle.points_inside();
   }
 
+  #[test]
+  fn circle_points_inside_f64() {
+    let circle = Circle::<f64, f64>::new(Point2::new(0.5, 0.5), 1.0);
+    let points = circle.points_inside();
+
+    let expected = vec![
+      Point2::new(0.0, 0.0),
+      Point2::new(1.0, 0.0),
+      Point2::new(0.0, 1.0),
+      Point2::new(1.0, 1.0),
+    ];
+
+    assert_vec_eq!(points, expected);
+  }
+
+  #[test]
+  fn rectangle_points_inside_f64_beyond_exact_integers() {
+    let limit = 2f64.powi(53);
+
+    let far = Rectangle::new(
+      Point2::new(limit * 128.0, 0.0),
+      Point2::new(limit * 130.0, 1.0),
+    );
+    assert_eq!(far.points_inside_iter().next(), None);
+
+    let edge = Rectangle::new(
+      Point2::new(limit - 3.0, 0.0),
+      Point2::new(limit + 64.0, 0.0),
+    );
+    assert_vec_eq!(
+      edge.points_inside(),
+      vec![
+        Point2::new(limit - 3.0, 0.0),
+        Point2::new(limit - 2.0, 0.0),
+        Point2::new(limit - 1.0, 0.0),
+      ]
+    );
+  }
+
+  #[test]
+  fn circle_points_inside_i32() {
+    let circle = Circle::<i32, u8>::new(Point2::new(-5, -5), 1);
+    let points = circle.points_inside();
+
+    let expected = vec![
+      Point2::new(-5, -6),
+      Point2::new(-6, -5),
+      Point2::new(-5, -5),
+      Point2::new(-4, -5),
+      Point2::new(-5, -4),
+    ];
+
+    assert_vec_eq!(points, expected);
+  }
+
   #[test]
   fn ellipse_points_inside_u8() {
-    let ellipse = Ellipse::new(Point2::new(5, 5), (2, 1));
+    let ellipse = Ellipse::<u8, u8>::new(Point2::new(5, 5), (2, 1));
     let points = ellipse.points_inside();
 
     let expected = vec![
+      Point2::new(5, 4),
       Point2::new(3, 5),
       Point2::new(4, 5),
-      Point2::new(5, 4),
       Point2::new(5, 5),
-      Point2::new(5, 6),
       Point2::new(6, 5),
       Point2::new(7, 5),
+      Point2::new(5, 6),
     ];
 
     assert_vec_eq!(points, expected);
@@ -205,31 +344,75 @@ mod tests {
     let _out = ellipse.points_inside();
   }
 
+  #[test]
+  fn rotated_ellipse_points_inside_u8() {
+    let ellipse = RotatedEllipse::<u8, u8>::new(Point2::new(5, 5), (2, 1), FRAC_PI_2);
+    let points = ellipse.points_inside();
+
+    let expected = vec![
+      Point2::new(5, 3),
+      Point2::new(5, 4),
+      Point2::new(4, 5),
+      Point2::new(5, 5),
+      Point2::new(6, 5),
+      Point2::new(5, 6),
+      Point2::new(5, 7),
+    ];
+
+    assert_vec_eq!(points, expected);
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn rotated_ellipse_points_inside_u8_fuzz(ellipse: RotatedEllipse<u8, u8>) {
+    let _out = ellipse.points_inside();
+  }
+
+  #[test]
+  fn rotated_rectangle_points_inside_u8() {
+    let rectangle = RotatedRectangle::<u8>::new(Point2::new(5, 5), (1, 1), FRAC_PI_4);
+    let points = rectangle.points_inside();
+
+    let expected = vec![
+      Point2::new(5, 4),
+      Point2::new(4, 5),
+      Point2::new(5, 5),
+      Point2::new(6, 5),
+      Point2::new(5, 6),
+    ];
+
+    assert_vec_eq!(points, expected);
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn rotated_rectangle_points_inside_u8_fuzz(rectangle: RotatedRectangle<u8>) {
+    let _out = rectangle.points_inside();
+  }
+
   #[test]
   fn rectangle_points_inside_u8() {
-    let rectangle = Rectangle::new(Point2::new(10, 10), Point2::new(13, 14));
+    let rectangle = Rectangle::<u8>::new(Point2::new(10, 10), Point2::new(13, 14));
     let points = rectangle.points_inside();
 
     let expected = vec![
       Point2::new(10, 10),
-      Point2::new(10, 11),
-      Point2::new(10, 12),
-      Point2::new(10, 13),
-      Point2::new(10, 14),
       Point2::new(11, 10),
-      Point2::new(11, 11),
-      Point2::new(11, 12),
-      Point2::new(11, 13),
-      Point2::new(11, 14),
       Point2::new(12, 10),
-      Point2::new(12, 11),
-      Point2::new(12, 12),
-      Point2::new(12, 13),
-      Point2::new(12, 14),
       Point2::new(13, 10),
+      Point2::new(10, 11),
+      Point2::new(11, 11),
+      Point2::new(12, 11),
       Point2::new(13, 11),
+      Point2::new(10, 12),
+      Point2::new(11, 12),
+      Point2::new(12, 12),
       Point2::new(13, 12),
+      Point2::new(10, 13),
+      Point2::new(11, 13),
+      Point2::new(12, 13),
       Point2::new(13, 13),
+      Point2::new(10, 14),
+      Point2::new(11, 14),
+      Point2::new(12, 14),
       Point2::new(13, 14),
     ];
 
@@ -241,18 +424,45 @@ mod tests {
     let _out = rectangle.points_inside();
   }
 
+  #[test]
+  fn rectangle_points_inside_f32() {
+    let rectangle = Rectangle::<f32>::new(Point2::new(0.5, 0.5), Point2::new(2.5, 1.5));
+    let points = rectangle.points_inside();
+
+    let expected = vec![Point2::new(1.0, 1.0), Point2::new(2.0, 1.0)];
+
+    assert_vec_eq!(points, expected);
+  }
+
+  #[test]
+  fn rectangle_points_inside_i16() {
+    let rectangle = Rectangle::<i16>::new(Point2::new(-1, -1), Point2::new(0, 1));
+    let points = rectangle.points_inside();
+
+    let expected = vec![
+      Point2::new(-1, -1),
+      Point2::new(0, -1),
+      Point2::new(-1, 0),
+      Point2::new(0, 0),
+      Point2::new(-1, 1),
+      Point2::new(0, 1),
+    ];
+
+    assert_vec_eq!(points, expected);
+  }
+
   #[test]
   fn triangle_points_inside_u8() {
-    let triangle = Triangle::new(Point2::new(0, 0), Point2::new(2, 0), Point2::new(0, 2));
+    let triangle = Triangle::<u8>::new(Point2::new(0, 0), Point2::new(2, 0), Point2::new(0, 2));
     let points = triangle.points_inside();
 
     let expected = vec![
       Point2::new(0, 0),
-      Point2::new(0, 1),
-      Point2::new(0, 2),
       Point2::new(1, 0),
-      Point2::new(1, 1),
       Point2::new(2, 0),
+      Point2::new(0, 1),
+      Point2::new(1, 1),
+      Point2::new(0, 2),
     ];
 
     assert_vec_eq!(points, expected);
@@ -263,9 +473,93 @@ mod tests {
     let _points = triangle.points_inside();
   }
 
+  #[test]
+  fn triangle_points_inside_f32() {
+    let triangle = Triangle::<f32>::new(
+      Point2::new(-0.5, -0.5),
+      Point2::new(2.5, -0.5),
+      Point2::new(-0.5, 2.5),
+    );
+    let points = triangle.points_inside();
+
+    let expected = vec![
+      Point2::new(0.0, 0.0),
+      Point2::new(1.0, 0.0),
+      Point2::new(2.0, 0.0),
+      Point2::new(0.0, 1.0),
+      Point2::new(1.0, 1.0),
+      Point2::new(0.0, 2.0),
+    ];
+
+    assert_vec_eq!(points, expected);
+  }
+
+  #[test]
+  fn polygon_points_inside_u8() {
+    let polygon = Polygon::<u8>::new(vec![
+      Point2::new(0, 0),
+      Point2::new(2, 0),
+      Point2::new(2, 2),
+      Point2::new(1, 1),
+      Point2::new(0, 2),
+    ]);
+    let points = polygon.points_inside();
+
+    let expected = vec![
+      Point2::new(0, 0),
+      Point2::new(1, 0),
+      Point2::new(2, 0),
+      Point2::new(0, 1),
+      Point2::new(1, 1),
+      Point2::new(2, 1),
+      Point2::new(0, 2),
+      Point2::new(2, 2),
+    ];
+
+    assert_vec_eq!(points, expected);
+  }
+
+  #[test]
+  fn polygon_points_inside_empty_u8() {
+    let polygon = Polygon::<u8>::new(vec![]);
+
+    assert!(polygon.points_inside().is_empty());
+  }
+
+  // Random polygons span most of the coordinate space, keep the case count low.
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn polygon_points_inside_u8_fuzz(polygon: Polygon<u8>) {
+    let _points = polygon.points_inside();
+  }
+
+  #[test]
+  fn polygon_with_holes_points_inside_u8() {
+    let polygon = PolygonWithHoles::new(
+      Polygon::<u8>::new(vec![
+        Point2::new(0, 0),
+        Point2::new(4, 0),
+        Point2::new(4, 4),
+        Point2::new(0, 4),
+      ]),
+      vec![Polygon::new(vec![
+        Point2::new(1, 1),
+        Point2::new(3, 1),
+        Point2::new(3, 3),
+        Point2::new(1, 3),
+      ])],
+    )
+    .unwrap();
+
+    let points = polygon.points_inside();
+
+    assert_eq!(points.len(), 24);
+    assert!(!points.contains(&Point2::new(2, 2)));
+    assert!(points.contains(&Point2::new(1, 1)));
+  }
+
   #[test]
   fn shape_collection_points_inside_u8() {
-    let collection = ShapeCollection::new(vec![
+    let collection = ShapeCollection::<u8, u8>::new(vec![
       Shape::Rectangle(Rectangle::new(Point2::new(0, 0), Point2::new(2, 2))),
       Shape::Rectangle(Rectangle::new(Point2::new(2, 2), Point2::new(4, 4))),
     ]);
@@ -274,27 +568,48 @@ mod tests {
 
     let expected = vec![
       Point2::new(0, 0),
-      Point2::new(0, 1),
-      Point2::new(0, 2),
       Point2::new(1, 0),
-      Point2::new(1, 1),
-      Point2::new(1, 2),
       Point2::new(2, 0),
+      Point2::new(0, 1),
+      Point2::new(1, 1),
       Point2::new(2, 1),
+      Point2::new(0, 2),
+      Point2::new(1, 2),
       Point2::new(2, 2),
-      Point2::new(2, 3),
-      Point2::new(2, 4),
       Point2::new(3, 2),
-      Point2::new(3, 3),
-      Point2::new(3, 4),
       Point2::new(4, 2),
+      Point2::new(2, 3),
+      Point2::new(3, 3),
       Point2::new(4, 3),
+      Point2::new(2, 4),
+      Point2::new(3, 4),
       Point2::new(4, 4),
     ];
 
     assert_vec_eq_unordered!(points, expected);
   }
 
+  #[test]
+  fn shape_collection_points_inside_f32() {
+    let collection = ShapeCollection::<f32, f32>::new(vec![
+      Shape::Rectangle(Rectangle::new(Point2::new(0.0, 0.0), Point2::new(1.5, 0.5))),
+      Shape::Rectangle(Rectangle::new(
+        Point2::new(0.5, -0.5),
+        Point2::new(2.0, 0.5),
+      )),
+    ]);
+
+    let points = collection.points_inside();
+
+    let expected = vec![
+      Point2::new(0.0, 0.0),
+      Point2::new(1.0, 0.0),
+      Point2::new(2.0, 0.0),
+    ];
+
+    assert_vec_eq_unordered!(points, expected);
+  }
+
   #[proptest]
   fn shape_points_inside_u8_are_within_fuzz(shape_view: ShapeView<u8, u8>) {
     let shape = Shape::from(shape_view);
@@ -308,4 +623,68 @@ mod tests {
       );
     }
   }
+
+  /// Points inside the bounding box, filtered with [`Within`], in scanline order.
+  fn points_within<S>(shape: &S) -> Vec<Point2<u8>>
+  where
+    S: BoundingBox<u8> + for<'a> Within<&'a Point2<u8>, Result = bool>,
+  {
+    let bbox = shape.bbox();
+    (bbox.min().y..=bbox.max().y)
+      .flat_map(|y| (bbox.min().x..=bbox.max().x).map(move |x| Point2::new(x, y)))
+      .filter(|point| shape.within(point))
+      .collect()
+  }
+
+  #[proptest(ProptestConfig::with_cases(64))]
+  fn shape_points_inside_u8_match_within_fuzz(shape_view: ShapeView<u8, u8>) {
+    let shape = Shape::from(shape_view);
+
+    assert_eq!(shape.points_inside(), points_within(&shape));
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn shape_collection_points_inside_u8_match_within_fuzz(
+    a: ShapeView<u8, u8>,
+    b: ShapeView<u8, u8>,
+  ) {
+    let collection = ShapeCollection::new(vec![Shape::from(a), Shape::from(b)]);
+
+    assert_eq!(collection.points_inside(), points_within(&collection));
+  }
+
+  #[proptest]
+  fn shape_collection_points_inside_iter_u8_is_scanline_ordered_fuzz(
+    a: ShapeView<u8, u8>,
+    b: ShapeView<u8, u8>,
+  ) {
+    let collection = ShapeCollection::new(vec![Shape::from(a), Shape::from(b)]);
+    let points = collection.points_inside_iter().collect::<Vec<_>>();
+
+    for pair in points.windows(2) {
+      assert!(
+        (pair[0].y, pair[0].x) < (pair[1].y, pair[1].x),
+        "points {:?} are not in scanline order",
+        pair
+      );
+    }
+  }
+
+  #[proptest(ProptestConfig::with_cases(16))]
+  fn csg_points_inside_u8_fuzz(a: ShapeView<u8, u8>, b: ShapeView<u8, u8>) {
+    let a = Shape::from(a);
+    let b = Shape::from(b);
+    let sorted = |mut points: Vec<Point2<u8>>| {
+      points.sort_by_key(|point| (point.x, point.y));
+      points
+    };
+    let union = Csg::from(a.clone()).union(b.clone()).points_inside();
+    let difference = Csg::from(a.clone()).difference(b.clone()).points_inside();
+    let collection = ShapeCollection::new(vec![a.clone(), b]).points_inside();
+
+    assert_eq!(sorted(union), sorted(collection));
+    for point in difference {
+      assert!(a.within(&point));
+    }
+  }
 }
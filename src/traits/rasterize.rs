@@ -0,0 +1,790 @@
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::traits::{BoundingBox, Within};
+use crate::*;
+
+/// Horizontal run of integer points inside a shape, both ends included.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Span<T> {
+  pub y: T,
+  pub x_start: T,
+  pub x_end: T,
+}
+
+/// Generic trait to split a shape into horizontal spans of integer points.
+///
+/// Spans are yielded in scanline order, like [`PointsInside`](crate::traits::PointsInside)
+/// yields points. Overlapping and adjacent spans of collection members are merged, so every
+/// point inside the shape is covered by exactly one span. Coordinates beyond ±2^53, where
+/// `f64` can't tell neighbouring integers apart, are skipped.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, traits::{Rasterize, Span}};
+///
+/// let circle = Circle::<u8, u8>::new(Point2::new(5, 5), 1);
+/// assert_eq!(circle.spans().collect::<Vec<_>>(), vec![
+///   Span { y: 4, x_start: 5, x_end: 5 },
+///   Span { y: 5, x_start: 4, x_end: 6 },
+///   Span { y: 6, x_start: 5, x_end: 5 },
+/// ]);
+/// ```
+pub trait Rasterize<T: Scalar> {
+  type Spans<'a>: Iterator<Item = Span<T>>
+  where
+    Self: 'a;
+
+  /// Returns an iterator yielding the spans lazily, without allocating.
+  fn spans(&self) -> Self::Spans<'_>;
+}
+
+impl<T: Scalar, U: Rasterize<T>> Rasterize<T> for &U {
+  type Spans<'a>
+    = U::Spans<'a>
+  where
+    Self: 'a;
+
+  #[cfg_attr(test, mutants::skip)]
+  fn spans(&self) -> Self::Spans<'_> {
+    U::spans(self)
+  }
+}
+
+/// Box around the integer points which have to be checked, or `None` for empty shapes.
+///
+/// Unlike the bounding box of an empty polygon or collection, which spans the whole
+/// coordinate range, this doesn't require scanning anything.
+pub(crate) trait ScanBounds<T: Scalar> {
+  fn scan_bounds(&self) -> Option<Rectangle<T>>;
+}
+
+macro_rules! impl_scan_bounds {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<T, $($generic,)*> ScanBounds<T> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      fn scan_bounds(&self) -> Option<Rectangle<T>> {
+        Some(self.bbox())
+      }
+    }
+  )*};
+}
+
+impl_scan_bounds!(
+  Rectangle<T> => [];
+  RotatedRectangle<T> => [];
+  Circle<T, R> => [R];
+  Ellipse<T, R> => [R];
+  RotatedEllipse<T, R> => [R];
+  Triangle<T> => [];
+);
+
+impl<T: FloatMath> ScanBounds<T> for Polygon<T> {
+  fn scan_bounds(&self) -> Option<Rectangle<T>> {
+    (!self.points.is_empty()).then(|| self.bbox())
+  }
+}
+
+impl<T: FloatMath> ScanBounds<T> for PolygonWithHoles<T> {
+  fn scan_bounds(&self) -> Option<Rectangle<T>> {
+    self.exterior.scan_bounds()
+  }
+}
+
+impl<T: FloatMath, U: FloatMath> ScanBounds<T> for ShapeCollection<T, U> {
+  fn scan_bounds(&self) -> Option<Rectangle<T>> {
+    self
+      .shapes
+      .iter()
+      .filter_map(ScanBounds::scan_bounds)
+      .reduce(|a, b| {
+        let min = |a: T, b: T| if b < a { b } else { a };
+        let max = |a: T, b: T| if b > a { b } else { a };
+
+        Rectangle::new(
+          Point2::new(min(a.min().x, b.min().x), min(a.min().y, b.min().y)),
+          Point2::new(max(a.max().x, b.max().x), max(a.max().y, b.max().y)),
+        )
+      })
+  }
+}
+
+impl<T: FloatMath, U: FloatMath> ScanBounds<T> for Shape<T, U> {
+  fn scan_bounds(&self) -> Option<Rectangle<T>> {
+    match self {
+      Self::Polygon(polygon) => polygon.scan_bounds(),
+      Self::PolygonWithHoles(polygon) => polygon.scan_bounds(),
+      Self::Collection(collection) => collection.scan_bounds(),
+      shape => Some(shape.bbox()),
+    }
+  }
+}
+
+impl<T: FloatMath, U: FloatMath> ScanBounds<T> for Csg<T, U> {
+  fn scan_bounds(&self) -> Option<Rectangle<T>> {
+    match self {
+      Self::Shape(shape) => shape.scan_bounds(),
+      csg => Some(csg.bbox()).filter(|bbox| !bbox.is_empty()),
+    }
+  }
+}
+
+/// Largest integer whose neighbours are still distinct `f64` values, so that stepping
+/// through the grid with `+ 1.0` always ends.
+pub(crate) const MAX_GRID: f64 = 9_007_199_254_740_991.0;
+
+/// Returns the first and last integer point inside the scan bounds of the shape.
+///
+/// The bounds are clamped to [`MAX_GRID`], points further away are never visited.
+#[inline]
+fn grid_bounds<S, T>(shape: &S) -> Option<(Point2<f64>, Point2<f64>)>
+where
+  S: ScanBounds<T>,
+  T: FloatMath,
+{
+  let bounds = shape.scan_bounds()?;
+  // Adding zero turns the `-0.0` produced by `ceil` into `0.0`.
+  let min = bounds
+    .min()
+    .map(|c| Into::<f64>::into(c).ceil().max(-MAX_GRID) + 0.0);
+  let max = bounds
+    .max()
+    .map(|c| Into::<f64>::into(c).floor().min(MAX_GRID));
+
+  (min.x <= max.x && min.y <= max.y).then_some((min, max))
+}
+
+#[inline]
+fn all_grid_bounds<S, T>(shapes: &[S]) -> impl Iterator<Item = (Point2<f64>, Point2<f64>)> + '_
+where
+  S: ScanBounds<T>,
+  T: FloatMath,
+{
+  shapes.iter().filter_map(|shape| grid_bounds(shape))
+}
+
+/// Shapes which can be split into spans one row at a time.
+pub(crate) trait RowSpans<T: Scalar>: ScanBounds<T> {
+  /// Returns the first span on row `y` which ends at or after `x`, starting no earlier than `x`.
+  fn span_from(&self, y: f64, x: f64) -> Option<(f64, f64)>;
+}
+
+/// Shapes whose outline can be intersected with a horizontal line.
+pub(crate) trait Crossings {
+  /// Calls `visit` with the `x` of every point where the outline meets the line at `y`.
+  fn crossings(&self, y: f64, visit: impl FnMut(f64));
+}
+
+impl<T: FloatMath> Crossings for Rectangle<T> {
+  fn crossings(&self, _y: f64, mut visit: impl FnMut(f64)) {
+    visit(self.min().x.into());
+    visit(self.max().x.into());
+  }
+}
+
+impl<T: FloatMath, R: FloatMath> Crossings for Circle<T, R> {
+  fn crossings(&self, y: f64, mut visit: impl FnMut(f64)) {
+    let center = self.center.map(Into::<f64>::into);
+    let radius: f64 = self.radius.into();
+    let half = (radius.powi(2) - (y - center.y).powi(2)).max(0.0).sqrt();
+
+    visit(center.x - half);
+    visit(center.x + half);
+  }
+}
+
+impl<T: FloatMath, R: FloatMath> Crossings for Ellipse<T, R> {
+  fn crossings(&self, y: f64, mut visit: impl FnMut(f64)) {
+    let center = self.center.map(Into::<f64>::into);
+    let rx: f64 = self.radius.0.into();
+    let ry: f64 = self.radius.1.into();
+
+    if rx == 0.0 || ry == 0.0 {
+      return visit(center.x);
+    }
+
+    let half = rx * (1.0 - ((y - center.y) / ry).powi(2)).max(0.0).sqrt();
+
+    visit(center.x - half);
+    visit(center.x + half);
+  }
+}
+
+/// Visits the points where the edge meets the line at `y`, both ends for a horizontal edge.
+#[inline]
+fn edge_crossings<T: FloatMath>(
+  start: &Point2<T>,
+  end: &Point2<T>,
+  y: f64,
+  visit: &mut impl FnMut(f64),
+) {
+  let a = start.map(Into::<f64>::into);
+  let b = end.map(Into::<f64>::into);
+
+  if a.y == b.y {
+    if a.y == y {
+      visit(a.x);
+      visit(b.x);
+    }
+  } else if a.y.min(b.y) <= y && y <= a.y.max(b.y) {
+    visit(a.x + (y - a.y) * (b.x - a.x) / (b.y - a.y));
+  }
+}
+
+impl<T: FloatMath> Crossings for Triangle<T> {
+  fn crossings(&self, y: f64, mut visit: impl FnMut(f64)) {
+    edge_crossings(&self.0, &self.1, y, &mut visit);
+    edge_crossings(&self.1, &self.2, y, &mut visit);
+    edge_crossings(&self.2, &self.0, y, &mut visit);
+  }
+}
+
+impl<T: FloatMath> Crossings for Polygon<T> {
+  fn crossings(&self, y: f64, mut visit: impl FnMut(f64)) {
+    for edge in self.edges() {
+      edge_crossings(&edge.start, &edge.end, y, &mut visit);
+    }
+  }
+}
+
+impl<T: FloatMath> Crossings for PolygonWithHoles<T> {
+  fn crossings(&self, y: f64, mut visit: impl FnMut(f64)) {
+    for ring in self.rings() {
+      ring.crossings(y, &mut visit);
+    }
+  }
+}
+
+/// Finds the first span on the row by walking it in runs of points which are either all
+/// inside or all outside the shape, checking the first point of every run.
+///
+/// `run_end` returns the last point of the run starting at the given point.
+#[inline]
+fn span_from_runs<S, T>(
+  shape: &S,
+  y: f64,
+  x: f64,
+  run_end: impl Fn(f64) -> f64,
+) -> Option<(f64, f64)>
+where
+  S: ScanBounds<T> + for<'b> Within<&'b Point2<T>, Result = bool>,
+  T: FloatMath,
+{
+  let (min, max) = grid_bounds(shape)?;
+  if y < min.y || y > max.y {
+    return None;
+  }
+
+  let inside = |x: f64| shape.within(&Point2::new(T::from_f64(x), T::from_f64(y)));
+  let run_end = |x: f64| run_end(x).min(max.x);
+
+  let mut start = x.max(min.x);
+  loop {
+    if start > max.x {
+      return None;
+    }
+    if inside(start) {
+      break;
+    }
+    start = run_end(start) + 1.0;
+  }
+
+  let mut end = run_end(start);
+  while end < max.x && inside(end + 1.0) {
+    end = run_end(end + 1.0);
+  }
+
+  Some((start, end))
+}
+
+/// Finds the first span on the row, checking the points next to the crossings one by one.
+///
+/// The shape can only change between inside and outside at a crossing, so every point
+/// which isn't next to one has the same result as the previous point. Checking the points
+/// next to crossings with [`Within`] keeps the result exact even if the crossings themselves
+/// are slightly off because of rounding.
+fn span_from_crossings<S, T>(shape: &S, y: f64, x: f64) -> Option<(f64, f64)>
+where
+  S: Crossings + ScanBounds<T> + for<'b> Within<&'b Point2<T>, Result = bool>,
+  T: FloatMath,
+{
+  span_from_runs(shape, y, x, |x| {
+    let mut next = f64::INFINITY;
+    shape.crossings(y, |crossing| {
+      for point in [crossing.floor(), crossing.ceil()] {
+        if point >= x {
+          next = next.min(point);
+        }
+      }
+    });
+
+    if next == x {
+      x
+    } else {
+      next - 1.0
+    }
+  })
+}
+
+/// Finds the first span on the row, checking every point.
+fn span_from_points<S, T>(shape: &S, y: f64, x: f64) -> Option<(f64, f64)>
+where
+  S: ScanBounds<T> + for<'b> Within<&'b Point2<T>, Result = bool>,
+  T: FloatMath,
+{
+  span_from_runs(shape, y, x, |x| x)
+}
+
+macro_rules! impl_row_spans {
+  ($span_from: ident => $($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<T, $($generic,)*> RowSpans<T> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      fn span_from(&self, y: f64, x: f64) -> Option<(f64, f64)> {
+        $span_from(self, y, x)
+      }
+    }
+  )*};
+}
+
+impl_row_spans!(
+  span_from_crossings =>
+  Rectangle<T> => [];
+  Circle<T, R> => [R];
+  Ellipse<T, R> => [R];
+  Triangle<T> => [];
+  Polygon<T> => [];
+  PolygonWithHoles<T> => [];
+);
+
+impl_row_spans!(
+  span_from_points =>
+  RotatedRectangle<T> => [];
+  RotatedEllipse<T, R> => [R];
+);
+
+impl<T: FloatMath, U: FloatMath> RowSpans<T> for Csg<T, U> {
+  fn span_from(&self, y: f64, x: f64) -> Option<(f64, f64)> {
+    match self {
+      Self::Shape(shape) => shape.span_from(y, x),
+      csg => span_from_points(csg, y, x),
+    }
+  }
+}
+
+impl<T: FloatMath, U: FloatMath> RowSpans<T> for Shape<T, U> {
+  fn span_from(&self, y: f64, x: f64) -> Option<(f64, f64)> {
+    match self {
+      Self::Rectangle(rectangle) => rectangle.span_from(y, x),
+      Self::RotatedRectangle(rectangle) => rectangle.span_from(y, x),
+      Self::Circle(circle) => circle.span_from(y, x),
+      Self::Ellipse(ellipse) => ellipse.span_from(y, x),
+      Self::RotatedEllipse(ellipse) => ellipse.span_from(y, x),
+      Self::Triangle(triangle) => triangle.span_from(y, x),
+      Self::Polygon(polygon) => polygon.span_from(y, x),
+      Self::PolygonWithHoles(polygon) => polygon.span_from(y, x),
+      Self::Collection(collection) => collection.span_from(y, x),
+    }
+  }
+}
+
+impl<T: FloatMath, U: FloatMath> RowSpans<T> for ShapeCollection<T, U> {
+  fn span_from(&self, y: f64, x: f64) -> Option<(f64, f64)> {
+    merged_span_from(&self.shapes, y, x)
+  }
+}
+
+/// Returns the first span on the row covered by any of the shapes.
+///
+/// The span is extended as long as the span of another shape overlaps it or starts right
+/// after it, so overlapping shapes yield a single span.
+fn merged_span_from<S, T>(shapes: &[S], y: f64, x: f64) -> Option<(f64, f64)>
+where
+  S: RowSpans<T>,
+  T: FloatMath,
+{
+  let (start, mut end) = shapes
+    .iter()
+    .filter_map(|shape| shape.span_from(y, x))
+    .min_by(|a, b| a.0.total_cmp(&b.0))?;
+
+  while let Some(next) = shapes
+    .iter()
+    .filter_map(|shape| shape.span_from(y, end + 1.0))
+    .filter(|(next_start, _)| *next_start == end + 1.0)
+    .map(|(_, next_end)| next_end)
+    .reduce(f64::max)
+  {
+    end = next;
+  }
+
+  Some((start, end))
+}
+
+/// Lazy iterator over the spans inside any of a slice of shapes, in scanline order.
+///
+/// Only the rows covered by the bounding boxes of the shapes are scanned.
+/// Created by [`Rasterize::spans`].
+#[derive(Debug, Clone)]
+pub struct SpansIter<'a, S, T> {
+  shapes: &'a [S],
+  /// Next point to check, `x` is negative infinity at the start of a row.
+  x: f64,
+  y: f64,
+  coordinate: PhantomData<T>,
+}
+
+/// Starts scanning the shapes at the first row covered by any of them.
+fn scan<S, T>(shapes: &[S]) -> SpansIter<'_, S, T>
+where
+  S: ScanBounds<T>,
+  T: FloatMath,
+{
+  let y = all_grid_bounds(shapes)
+    .map(|(min, _)| min.y)
+    .fold(f64::INFINITY, f64::min);
+
+  SpansIter {
+    shapes,
+    x: f64::NEG_INFINITY,
+    y,
+    coordinate: PhantomData,
+  }
+}
+
+/// Returns the next span as `(y, x_start, x_end)`, before converting into the coordinate type.
+pub(crate) fn next_span<S, T>(spans: &mut SpansIter<'_, S, T>) -> Option<(f64, f64, f64)>
+where
+  S: RowSpans<T>,
+  T: FloatMath,
+{
+  while spans.y.is_finite() {
+    let y = spans.y;
+
+    if let Some((start, end)) = merged_span_from(spans.shapes, y, spans.x) {
+      spans.x = end + 1.0;
+      return Some((y, start, end));
+    }
+
+    // Continue on the next row covered by any shape.
+    spans.y = all_grid_bounds(spans.shapes)
+      .filter(|(_, max)| max.y > y)
+      .map(|(min, _)| min.y.max(y + 1.0))
+      .fold(f64::INFINITY, f64::min);
+    spans.x = f64::NEG_INFINITY;
+  }
+
+  None
+}
+
+impl<S, T> Iterator for SpansIter<'_, S, T>
+where
+  S: RowSpans<T>,
+  T: FloatMath,
+{
+  type Item = Span<T>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    next_span(self).map(|(y, x_start, x_end)| Span {
+      y: T::from_f64(y),
+      x_start: T::from_f64(x_start),
+      x_end: T::from_f64(x_end),
+    })
+  }
+}
+
+impl<S, T> FusedIterator for SpansIter<'_, S, T>
+where
+  S: RowSpans<T>,
+  T: FloatMath,
+{
+}
+
+macro_rules! impl_rasterize {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<T, $($generic,)*> Rasterize<T> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      type Spans<'a>
+        = SpansIter<'a, Self, T>
+      where
+        Self: 'a;
+
+      fn spans(&self) -> Self::Spans<'_> {
+        scan(core::slice::from_ref(self))
+      }
+    }
+  )*};
+}
+
+impl_rasterize!(
+  Rectangle<T> => [];
+  RotatedRectangle<T> => [];
+  Circle<T, R> => [R];
+  Ellipse<T, R> => [R];
+  RotatedEllipse<T, R> => [R];
+  Triangle<T> => [];
+  Polygon<T> => [];
+  PolygonWithHoles<T> => [];
+  Csg<T, R> => [R];
+);
+
+impl<T, U> Rasterize<T> for Shape<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Spans<'a>
+    = SpansIter<'a, Self, T>
+  where
+    Self: 'a;
+
+  fn spans(&self) -> Self::Spans<'_> {
+    match self {
+      Self::Collection(collection) => collection.spans(),
+      shape => scan(core::slice::from_ref(shape)),
+    }
+  }
+}
+
+impl<T, U> Rasterize<T> for ShapeCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Spans<'a>
+    = SpansIter<'a, Shape<T, U>, T>
+  where
+    Self: 'a;
+
+  fn spans(&self) -> Self::Spans<'_> {
+    SliceShapeCollection::from(self).spans()
+  }
+}
+
+impl<T, U, const N: usize> Rasterize<T> for FixedShapeCollection<T, U, N>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Spans<'a>
+    = SpansIter<'a, Shape<T, U>, T>
+  where
+    Self: 'a;
+
+  fn spans(&self) -> Self::Spans<'_> {
+    SliceShapeCollection::from(self).spans()
+  }
+}
+
+impl<'a, T, U> Rasterize<T> for SliceShapeCollection<'a, T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  // Spans borrow the shapes for as long as the slice, not just the collection.
+  type Spans<'b>
+    = SpansIter<'a, Shape<T, U>, T>
+  where
+    Self: 'b;
+
+  fn spans(&self) -> Self::Spans<'_> {
+    scan(self.shapes())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use crate::proptest::ShapeView;
+  use crate::traits::{Rasterize, Span};
+  use crate::{
+    Circle, Ellipse, FillRule, Point2, Polygon, PolygonWithHoles, Rectangle, Shape,
+    ShapeCollection, Triangle,
+  };
+  use test_strategy::proptest;
+
+  fn spans<S: Rasterize<u8>>(shape: S) -> Vec<(u8, u8, u8)> {
+    shape
+      .spans()
+      .map(|span| (span.y, span.x_start, span.x_end))
+      .collect()
+  }
+
+  #[test]
+  fn rectangle_spans_u8() {
+    let rectangle = Rectangle::new(Point2::new(1, 2), Point2::new(3, 3));
+
+    assert_eq!(spans(rectangle), vec![(2, 1, 3), (3, 1, 3)]);
+  }
+
+  #[test]
+  fn rectangle_spans_f32() {
+    let rectangle = Rectangle::<f32>::new(Point2::new(-0.5, 0.5), Point2::new(1.5, 1.5));
+    let spans = rectangle.spans().collect::<Vec<_>>();
+
+    assert_eq!(
+      spans,
+      vec![Span {
+        y: 1.0,
+        x_start: 0.0,
+        x_end: 1.0
+      }]
+    );
+  }
+
+  #[test]
+  fn circle_spans_u8() {
+    let circle = Circle::new(Point2::new(5, 5), 2);
+
+    assert_eq!(
+      spans(circle),
+      vec![(3, 5, 5), (4, 4, 6), (5, 3, 7), (6, 4, 6), (7, 5, 5)]
+    );
+  }
+
+  #[test]
+  fn circle_spans_zero_radius_i16() {
+    let circle = Circle::<i16, u8>::new(Point2::new(-3, 4), 0);
+    let spans = circle.spans().collect::<Vec<_>>();
+
+    assert_eq!(
+      spans,
+      vec![Span {
+        y: 4,
+        x_start: -3,
+        x_end: -3
+      }]
+    );
+  }
+
+  #[test]
+  fn ellipse_spans_u8() {
+    let ellipse = Ellipse::new(Point2::new(5, 5), (2, 1));
+
+    assert_eq!(spans(ellipse), vec![(4, 5, 5), (5, 3, 7), (6, 5, 5)]);
+  }
+
+  #[test]
+  fn triangle_spans_u8() {
+    let triangle = Triangle::new(Point2::new(0, 0), Point2::new(4, 0), Point2::new(0, 4));
+
+    assert_eq!(
+      spans(triangle),
+      vec![(0, 0, 4), (1, 0, 3), (2, 0, 2), (3, 0, 1), (4, 0, 0)]
+    );
+  }
+
+  #[test]
+  fn polygon_spans_concave_u8() {
+    // U-shaped polygon with the opening at the top
+    let polygon = Polygon::new(vec![
+      Point2::new(0, 0),
+      Point2::new(6, 0),
+      Point2::new(6, 3),
+      Point2::new(4, 3),
+      Point2::new(4, 1),
+      Point2::new(2, 1),
+      Point2::new(2, 3),
+      Point2::new(0, 3),
+    ]);
+
+    assert_eq!(
+      spans(polygon),
+      vec![
+        (0, 0, 6),
+        (1, 0, 6),
+        (2, 0, 2),
+        (2, 4, 6),
+        (3, 0, 2),
+        (3, 4, 6)
+      ]
+    );
+  }
+
+  #[test]
+  fn polygon_spans_fill_rules_u8() {
+    let points = vec![
+      Point2::new(50, 90),
+      Point2::new(74, 18),
+      Point2::new(12, 62),
+      Point2::new(88, 62),
+      Point2::new(26, 18),
+    ];
+    let even_odd = Polygon::new(points.clone());
+    let non_zero = Polygon::new(points).with_fill_rule(FillRule::NonZero);
+
+    // The center of the pentagram is only filled with the non-zero rule.
+    let row = |polygon: Polygon<u8>| {
+      spans(polygon)
+        .into_iter()
+        .filter(|(y, _, _)| *y == 50)
+        .count()
+    };
+    assert_eq!(row(even_odd), 2);
+    assert_eq!(row(non_zero), 1);
+  }
+
+  #[test]
+  fn polygon_with_holes_spans_u8() {
+    let polygon = PolygonWithHoles::new(
+      Polygon::new(vec![
+        Point2::new(0, 0),
+        Point2::new(4, 0),
+        Point2::new(4, 4),
+        Point2::new(0, 4),
+      ]),
+      vec![Polygon::new(vec![
+        Point2::new(1, 1),
+        Point2::new(3, 1),
+        Point2::new(3, 3),
+        Point2::new(1, 3),
+      ])],
+    )
+    .unwrap();
+
+    assert_eq!(
+      spans(polygon),
+      vec![
+        (0, 0, 4),
+        (1, 0, 4),
+        (2, 0, 1),
+        (2, 3, 4),
+        (3, 0, 4),
+        (4, 0, 4)
+      ]
+    );
+  }
+
+  #[test]
+  fn shape_collection_spans_merged_u8() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![
+      Shape::Rectangle(Rectangle::new(Point2::new(0, 0), Point2::new(2, 1))),
+      Shape::Rectangle(Rectangle::new(Point2::new(3, 1), Point2::new(5, 1))),
+      Shape::Rectangle(Rectangle::new(Point2::new(1, 0), Point2::new(3, 0))),
+      Shape::Rectangle(Rectangle::new(Point2::new(8, 0), Point2::new(9, 0))),
+    ]);
+
+    assert_eq!(spans(collection), vec![(0, 0, 3), (0, 8, 9), (1, 0, 5)]);
+  }
+
+  #[test]
+  fn shape_collection_spans_empty_u8() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![]);
+
+    assert_eq!(spans(collection), vec![]);
+  }
+
+  #[proptest]
+  fn shape_spans_u8_fuzz(shape: ShapeView<u8, u8>) {
+    let _spans = spans(Shape::from(shape));
+  }
+}
@@ -0,0 +1,186 @@
+use crate::primitive;
+use crate::*;
+
+/// Generic trait to calculate the signed distance from a point to the outline of a shape.
+///
+/// The distance is negative inside the shape, 0 on the outline and positive outside.
+/// Holes count as outside. Collections are the union of their members: the result is
+/// the minimum over the members, which is exact outside and where members don't overlap.
+/// Empty collections are infinitely far away.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, traits::SignedDistance};
+///
+/// let effect = Circle::<u8, u8>::new(Point2::new(10, 10), 5);
+///
+/// assert_eq!(effect.signed_distance(&Point2::new(10, 8)), -3.0);
+/// assert_eq!(effect.signed_distance(&Point2::new(10, 15)), 0.0);
+/// assert_eq!(effect.signed_distance(&Point2::new(10, 18)), 3.0);
+/// ```
+pub trait SignedDistance<T> {
+  type Result;
+
+  fn signed_distance(&self, point: T) -> Self::Result;
+}
+
+macro_rules! impl_signed_distance {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<T, $($generic,)*> SignedDistance<&Point2<T>> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      type Result = f64;
+
+      fn signed_distance(&self, point: &Point2<T>) -> Self::Result {
+        primitive::signed_distance(self, &point.map(Into::into))
+      }
+    }
+
+    impl<T, $($generic,)*> SignedDistance<Point2<T>> for $shape
+    where
+      T: FloatMath,
+      $($generic: FloatMath,)*
+    {
+      type Result = f64;
+
+      fn signed_distance(&self, point: Point2<T>) -> Self::Result {
+        self.signed_distance(&point)
+      }
+    }
+  )*};
+}
+
+impl_signed_distance!(
+  Line<T> => [];
+  Rectangle<T> => [];
+  RotatedRectangle<T> => [];
+  Circle<T, R> => [R];
+  Ellipse<T, R> => [R];
+  RotatedEllipse<T, R> => [R];
+  Triangle<T> => [];
+  Polygon<T> => [];
+  PolygonWithHoles<T> => [];
+  ShapeCollection<T, R> => [R];
+  Shape<T, R> => [R];
+);
+
+impl<T, U> SignedDistance<&Point2<T>> for Csg<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Result = f64;
+
+  /// Combines the signed distances of the operands with minimum and maximum.
+  ///
+  /// The result is exact on the outline, and a lower bound of the distance elsewhere.
+  fn signed_distance(&self, point: &Point2<T>) -> Self::Result {
+    match self {
+      Self::Shape(shape) => shape.signed_distance(point),
+      Self::Union(a, b) => a.signed_distance(point).min(b.signed_distance(point)),
+      Self::Intersection(a, b) => a.signed_distance(point).max(b.signed_distance(point)),
+      Self::Difference(a, b) => a.signed_distance(point).max(-b.signed_distance(point)),
+      Self::SymmetricDifference(a, b) => {
+        let (a, b) = (a.signed_distance(point), b.signed_distance(point));
+        a.min(b).max(-a.max(b))
+      }
+    }
+  }
+}
+
+impl<T, U> SignedDistance<Point2<T>> for Csg<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Result = f64;
+
+  fn signed_distance(&self, point: Point2<T>) -> Self::Result {
+    self.signed_distance(&point)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use float_cmp::assert_approx_eq;
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use super::SignedDistance;
+  use crate::proptest::ShapeView;
+  use crate::testing::{circle, collection, rectangle, PointView2};
+  use crate::traits::{Distance, Within};
+  use crate::{Circle, Csg, Ellipse, Line, Point2, Polygon, PolygonWithHoles, Shape, Triangle};
+
+  #[test_case(rectangle([0, 0], [10, 10]), [5, 5] => -5.0; "rectangle center")]
+  #[test_case(rectangle([0, 0], [10, 10]), [2, 5] => -2.0; "rectangle inside")]
+  #[test_case(rectangle([0, 0], [10, 10]), [10, 5] => 0.0; "rectangle edge")]
+  #[test_case(rectangle([0, 0], [10, 10]), [13, 14] => 5.0; "rectangle outside the corner")]
+  #[test_case(circle([0, 0], 5), [0, 0] => -5.0; "circle center")]
+  #[test_case(circle([0, 0], 5), [6, 8] => 5.0; "circle outside")]
+  #[test_case(Ellipse::new(Point2::new(0, 0), (10, 2)).into(), [0, 1] => -1.0; "ellipse inside")]
+  #[test_case(Triangle::new(Point2::new(0, 0), Point2::new(10, 0), Point2::new(0, 10)).into(), [1, 2] => -1.0; "triangle inside")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10])]), [9, 5] => -1.0; "collection takes the minimum")]
+  #[test_case(collection(vec![circle([0, 0], 5), circle([20, 0], 5)]), [12, 0] => 3.0; "collection outside")]
+  #[test_case(collection(vec![]), [0, 0] => f64::INFINITY; "empty collection")]
+  fn shape_signed_distance(shape: Shape<i32, u32>, point: [i32; 2]) -> f64 {
+    (shape.signed_distance(Point2::from(point)) * 1e9).round() / 1e9
+  }
+
+  #[test]
+  fn line_signed_distance() {
+    let line = Line::new(Point2::new(0, 0), Point2::new(10, 0));
+
+    assert_eq!(line.signed_distance(Point2::new(5, 0)), 0.0);
+    assert_eq!(line.signed_distance(Point2::new(5, 3)), 3.0);
+  }
+
+  #[test]
+  fn polygon_with_holes_signed_distance() {
+    let square = |min: i32, max: i32| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let polygon = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+
+    assert_eq!(polygon.signed_distance(Point2::new(15, 13)), 3.0);
+    assert_eq!(polygon.signed_distance(Point2::new(15, 8)), -2.0);
+    assert_eq!(polygon.signed_distance(Point2::new(15, 35)), 5.0);
+  }
+
+  #[test_case(Csg::from(Circle::new(Point2::new(0, 0), 10)).difference(Circle::new(Point2::new(0, 0), 5)), [0, 7] => -2.0; "ring inside")]
+  #[test_case(Csg::from(Circle::new(Point2::new(0, 0), 10)).difference(Circle::new(Point2::new(0, 0), 5)), [0, 2] => 3.0; "ring in the hole")]
+  #[test_case(Csg::from(Circle::new(Point2::new(0, 0), 10)).intersection(Circle::new(Point2::new(10, 0), 10)), [5, 0] => -5.0; "lens")]
+  #[test_case(Csg::from(Circle::new(Point2::new(0, 0), 10)).union(Circle::new(Point2::new(30, 0), 10)), [15, 0] => 5.0; "union")]
+  #[test_case(Csg::from(Circle::new(Point2::new(0, 0), 10)).symmetric_difference(Circle::new(Point2::new(10, 0), 10)), [5, 0] => 5.0; "symmetric difference, both")]
+  #[test_case(Csg::from(Circle::new(Point2::new(0, 0), 10)).symmetric_difference(Circle::new(Point2::new(10, 0), 10)), [-5, 0] => -5.0; "symmetric difference, one")]
+  fn csg_signed_distance(csg: Csg<i32, u32>, point: [i32; 2]) -> f64 {
+    csg.signed_distance(Point2::from(point))
+  }
+
+  #[proptest]
+  fn shape_signed_distance_u8(shape: ShapeView<u8, u8>, point: PointView2<u8>) {
+    let shape: Shape<u8, u8> = shape.into();
+    let point: Point2<u8> = point.into();
+    let signed_distance = shape.signed_distance(&point);
+
+    if signed_distance < 0.0 {
+      assert!(shape.within(&point));
+    }
+    if signed_distance > 0.0 && signed_distance.is_finite() {
+      assert!(!shape.within(&point));
+      assert_approx_eq!(
+        f64,
+        signed_distance,
+        shape.distance(&Line::new_unchecked(point, point)),
+        epsilon = 1e-6
+      );
+    }
+  }
+}
@@ -0,0 +1,688 @@
+use alloc::vec::Vec;
+
+use nalgebra::{Affine2, Isometry2, Matrix3, Similarity2, Translation2};
+
+use crate::primitive::transform_ellipse;
+use crate::*;
+
+/// Applies an affine transformation to a shape.
+///
+/// Shapes keep their type where the transformation allows it. Otherwise a more general
+/// shape is returned, e.g. a rotated [`Rectangle`] becomes a [`Polygon`] and a
+/// non-uniformly scaled [`Circle`] becomes a [`RotatedEllipse`].
+///
+/// Transformed coordinates are rounded to the nearest value of the coordinate type.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, Translation2, traits::Transform};
+///
+/// let circle = Circle::<u8, u8>::new(Point2::new(10, 10), 5);
+/// let moved = circle.transform(&Translation2::new(5.0, -5.0));
+///
+/// assert_eq!(moved, Circle::new(Point2::new(15, 5), 5));
+/// ```
+pub trait Transform<M> {
+  type Output;
+
+  fn transform(&self, transformation: &M) -> Self::Output;
+}
+
+impl<M, U: Transform<M>> Transform<M> for &U {
+  type Output = U::Output;
+
+  fn transform(&self, transformation: &M) -> Self::Output {
+    U::transform(*self, transformation)
+  }
+}
+
+/// Transformations that preserve angles, and therefore the type of most shapes.
+trait Conformal {
+  fn rotation_angle(&self) -> f64;
+
+  fn scale(&self) -> f64;
+}
+
+impl Conformal for Translation2<f64> {
+  fn rotation_angle(&self) -> f64 {
+    0.0
+  }
+
+  fn scale(&self) -> f64 {
+    1.0
+  }
+}
+
+impl Conformal for Isometry2<f64> {
+  fn rotation_angle(&self) -> f64 {
+    self.rotation.angle()
+  }
+
+  fn scale(&self) -> f64 {
+    1.0
+  }
+}
+
+impl Conformal for Similarity2<f64> {
+  fn rotation_angle(&self) -> f64 {
+    self.isometry.rotation.angle()
+  }
+
+  fn scale(&self) -> f64 {
+    self.scaling().abs()
+  }
+}
+
+#[inline]
+fn transform_point<T: FloatMath>(matrix: &Matrix3<f64>, point: &Point2<T>) -> Point2<T> {
+  transform_point_f64(matrix, &point.map(Into::<f64>::into))
+}
+
+#[inline]
+fn transform_point_f64<T: FloatMath>(matrix: &Matrix3<f64>, point: &Point2<f64>) -> Point2<T> {
+  matrix.transform_point(point).map(T::round_from_f64)
+}
+
+#[inline]
+fn transform_polygon<T: FloatMath>(matrix: &Matrix3<f64>, polygon: &Polygon<T>) -> Polygon<T> {
+  Polygon {
+    points: polygon
+      .points
+      .iter()
+      .map(|point| transform_point(matrix, point))
+      .collect(),
+    fill_rule: polygon.fill_rule,
+  }
+}
+
+impl<T> Transform<Translation2<f64>> for Rectangle<T>
+where
+  T: FloatMath,
+{
+  type Output = Rectangle<T>;
+
+  fn transform(&self, transformation: &Translation2<f64>) -> Self::Output {
+    let matrix = transformation.to_homogeneous();
+
+    Rectangle::new(
+      transform_point(&matrix, self.min()),
+      transform_point(&matrix, self.max()),
+    )
+  }
+}
+
+impl<T, R> Transform<Translation2<f64>> for Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Output = Ellipse<T, R>;
+
+  fn transform(&self, transformation: &Translation2<f64>) -> Self::Output {
+    Ellipse::new(
+      transform_point(&transformation.to_homogeneous(), &self.center),
+      self.radius,
+    )
+  }
+}
+
+macro_rules! impl_transform_rotating {
+  ($($m: ty),*) => {$(
+    impl<T> Transform<$m> for Rectangle<T>
+    where
+      T: FloatMath,
+    {
+      type Output = Polygon<T>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        let (min, max) = (self.min(), self.max());
+        let corners = Polygon::new(vec![
+          *min,
+          Point2::new(max.x, min.y),
+          *max,
+          Point2::new(min.x, max.y),
+        ]);
+
+        transform_polygon(&transformation.to_homogeneous(), &corners)
+      }
+    }
+  )*};
+}
+
+impl_transform_rotating!(Isometry2<f64>, Similarity2<f64>, Affine2<f64>);
+
+macro_rules! impl_transform_conformal {
+  ($($m: ty),*) => {$(
+    impl<T, R> Transform<$m> for Circle<T, R>
+    where
+      T: FloatMath,
+      R: FloatMath,
+    {
+      type Output = Circle<T, R>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        let radius: f64 = self.radius.into();
+
+        Circle::new(
+          transform_point(&transformation.to_homogeneous(), &self.center),
+          R::round_from_f64(radius * transformation.scale()),
+        )
+      }
+    }
+
+    impl<T, R> Transform<$m> for RotatedEllipse<T, R>
+    where
+      T: FloatMath,
+      R: FloatMath,
+    {
+      type Output = RotatedEllipse<T, R>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        let scale = transformation.scale();
+
+        RotatedEllipse::new(
+          transform_point(&transformation.to_homogeneous(), &self.center),
+          (
+            R::round_from_f64(Into::<f64>::into(self.radius.0) * scale),
+            R::round_from_f64(Into::<f64>::into(self.radius.1) * scale),
+          ),
+          self.angle + transformation.rotation_angle(),
+        )
+      }
+    }
+
+    impl<T> Transform<$m> for RotatedRectangle<T>
+    where
+      T: FloatMath,
+    {
+      type Output = RotatedRectangle<T>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        let scale = transformation.scale();
+
+        RotatedRectangle::new(
+          transform_point(&transformation.to_homogeneous(), &self.center),
+          (
+            T::round_from_f64(Into::<f64>::into(self.half_size.0) * scale),
+            T::round_from_f64(Into::<f64>::into(self.half_size.1) * scale),
+          ),
+          self.angle + transformation.rotation_angle(),
+        )
+      }
+    }
+  )*};
+}
+
+impl_transform_conformal!(Translation2<f64>, Isometry2<f64>, Similarity2<f64>);
+
+macro_rules! impl_transform_ellipse_conformal {
+  ($($m: ty),*) => {$(
+    impl<T, R> Transform<$m> for Ellipse<T, R>
+    where
+      T: FloatMath,
+      R: FloatMath,
+    {
+      type Output = RotatedEllipse<T, R>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        RotatedEllipse::from(*self).transform(transformation)
+      }
+    }
+  )*};
+}
+
+impl_transform_ellipse_conformal!(Isometry2<f64>, Similarity2<f64>);
+
+impl<T, R> Transform<Affine2<f64>> for Circle<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Output = RotatedEllipse<T, R>;
+
+  fn transform(&self, transformation: &Affine2<f64>) -> Self::Output {
+    transform_ellipse(
+      &transformation.to_homogeneous(),
+      &self.center,
+      &(self.radius, self.radius),
+      0.0,
+    )
+  }
+}
+
+impl<T, R> Transform<Affine2<f64>> for Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Output = RotatedEllipse<T, R>;
+
+  fn transform(&self, transformation: &Affine2<f64>) -> Self::Output {
+    transform_ellipse(
+      &transformation.to_homogeneous(),
+      &self.center,
+      &self.radius,
+      0.0,
+    )
+  }
+}
+
+impl<T, R> Transform<Affine2<f64>> for RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Output = RotatedEllipse<T, R>;
+
+  fn transform(&self, transformation: &Affine2<f64>) -> Self::Output {
+    transform_ellipse(
+      &transformation.to_homogeneous(),
+      &self.center,
+      &self.radius,
+      self.angle,
+    )
+  }
+}
+
+impl<T> Transform<Affine2<f64>> for RotatedRectangle<T>
+where
+  T: FloatMath,
+{
+  type Output = Polygon<T>;
+
+  fn transform(&self, transformation: &Affine2<f64>) -> Self::Output {
+    let matrix = transformation.to_homogeneous();
+
+    Polygon::new(
+      self
+        .corners()
+        .iter()
+        .map(|corner| transform_point_f64(&matrix, corner))
+        .collect(),
+    )
+  }
+}
+
+macro_rules! impl_transform_pointwise {
+  ($($m: ty),*) => {$(
+    impl<T> Transform<$m> for Line<T>
+    where
+      T: FloatMath,
+    {
+      type Output = Line<T>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        let matrix = transformation.to_homogeneous();
+
+        Line::new(
+          transform_point(&matrix, &self.start),
+          transform_point(&matrix, &self.end),
+        )
+      }
+    }
+
+    impl<T> Transform<$m> for Triangle<T>
+    where
+      T: FloatMath,
+    {
+      type Output = Triangle<T>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        let matrix = transformation.to_homogeneous();
+
+        Triangle::new(
+          transform_point(&matrix, &self.0),
+          transform_point(&matrix, &self.1),
+          transform_point(&matrix, &self.2),
+        )
+      }
+    }
+
+    impl<T> Transform<$m> for Polygon<T>
+    where
+      T: FloatMath,
+    {
+      type Output = Polygon<T>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        transform_polygon(&transformation.to_homogeneous(), self)
+      }
+    }
+
+    impl<T> Transform<$m> for PolygonWithHoles<T>
+    where
+      T: FloatMath,
+    {
+      type Output = PolygonWithHoles<T>;
+
+      /// Transforms all rings, keeping the exterior counter-clockwise and the holes clockwise.
+      ///
+      /// Holes which collapse or stop fitting into the exterior once the coordinates are
+      /// rounded are dropped, so the result passes [`PolygonWithHoles::new`] again. If the
+      /// exterior itself collapses, no hole is kept.
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        let matrix = transformation.to_homogeneous();
+        let mirrored = matrix.fixed_view::<2, 2>(0, 0).determinant() < 0.0;
+
+        let ring = |polygon: &Polygon<T>| {
+          let mut ring = transform_polygon(&matrix, polygon);
+          if mirrored {
+            ring.points.reverse();
+          }
+          ring
+        };
+
+        let exterior = ring(&self.exterior);
+        let mut holes = Vec::with_capacity(self.holes.len());
+        for hole in self.holes.iter().map(ring) {
+          holes.push(hole);
+          if PolygonWithHoles::new(exterior.clone(), holes.clone()).is_err() {
+            holes.pop();
+          }
+        }
+
+        PolygonWithHoles::new_unchecked(exterior, holes)
+      }
+    }
+
+    impl<T, R> Transform<$m> for Shape<T, R>
+    where
+      T: FloatMath,
+      R: FloatMath,
+    {
+      type Output = Shape<T, R>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        match self {
+          Self::Rectangle(rectangle) => rectangle.transform(transformation).into(),
+          Self::RotatedRectangle(rectangle) => rectangle.transform(transformation).into(),
+          Self::Circle(circle) => circle.transform(transformation).into(),
+          Self::Ellipse(ellipse) => ellipse.transform(transformation).into(),
+          Self::RotatedEllipse(ellipse) => ellipse.transform(transformation).into(),
+          Self::Triangle(triangle) => triangle.transform(transformation).into(),
+          Self::Polygon(polygon) => polygon.transform(transformation).into(),
+          Self::PolygonWithHoles(polygon) => polygon.transform(transformation).into(),
+          Self::Collection(collection) => collection.transform(transformation).into(),
+        }
+      }
+    }
+
+    impl<T, R> Transform<$m> for ShapeCollection<T, R>
+    where
+      T: FloatMath,
+      R: FloatMath,
+    {
+      type Output = ShapeCollection<T, R>;
+
+      fn transform(&self, transformation: &$m) -> Self::Output {
+        ShapeCollection::new(
+          self
+            .shapes
+            .iter()
+            .map(|shape| shape.transform(transformation))
+            .collect(),
+        )
+      }
+    }
+  )*};
+}
+
+impl_transform_pointwise!(
+  Translation2<f64>,
+  Isometry2<f64>,
+  Similarity2<f64>,
+  Affine2<f64>
+);
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+  use float_cmp::assert_approx_eq;
+  use nalgebra::{Affine2, Isometry2, Matrix3, Similarity2, Translation2, Vector2};
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::traits::{Centroid, Transform};
+  use crate::{
+    Circle, Ellipse, Line, Point2, Polygon, PolygonWithHoles, Rectangle, RotatedEllipse,
+    RotatedRectangle, Shape, ShapeCollection, Triangle,
+  };
+
+  fn scale(x: f64, y: f64) -> Affine2<f64> {
+    Affine2::from_matrix_unchecked(Matrix3::new(x, 0.0, 0.0, 0.0, y, 0.0, 0.0, 0.0, 1.0))
+  }
+
+  #[test_case(Translation2::new(5.0, -5.0) => Rectangle::new(Point2::new(15, 5), Point2::new(25, 15)); "normal")]
+  #[test_case(Translation2::new(-20.0, 0.0) => Rectangle::new(Point2::new(0, 10), Point2::new(0, 20)); "saturating")]
+  #[test_case(Translation2::new(0.4, 0.6) => Rectangle::new(Point2::new(10, 11), Point2::new(20, 21)); "rounding")]
+  fn rectangle_translate_u8(translation: Translation2<f64>) -> Rectangle<u8> {
+    Rectangle::new(Point2::new(10, 10), Point2::new(20, 20)).transform(&translation)
+  }
+
+  #[test]
+  fn rectangle_rotate_u8() {
+    let rectangle = Rectangle::<u8>::new(Point2::new(10, 10), Point2::new(20, 30));
+    let rotation = Isometry2::rotation_wrt_point(
+      nalgebra::UnitComplex::new(FRAC_PI_2),
+      Point2::new(20.0, 20.0),
+    );
+
+    assert_eq!(
+      rectangle.transform(&rotation),
+      Polygon::new(vec![
+        Point2::new(30, 10),
+        Point2::new(30, 20),
+        Point2::new(10, 20),
+        Point2::new(10, 10),
+      ])
+    );
+  }
+
+  #[test_case(Isometry2::translation(1.0, 2.0) => Circle::new(Point2::new(11, 12), 5); "translation")]
+  #[test_case(Isometry2::new(Vector2::new(0.0, 0.0), FRAC_PI_2) => Circle::new(Point2::new(-10, 10), 5); "rotation")]
+  fn circle_isometry_i16(isometry: Isometry2<f64>) -> Circle<i16, u16> {
+    Circle::new(Point2::new(10, 10), 5).transform(&isometry)
+  }
+
+  #[test_case(2.0 => Circle::new(Point2::new(20, 20), 10); "grow")]
+  #[test_case(0.5 => Circle::new(Point2::new(5, 5), 3); "shrink")]
+  #[test_case(100.0 => Circle::new(Point2::new(255, 255), 255); "saturating")]
+  fn circle_scale_u8(scale: f64) -> Circle<u8, u8> {
+    let similarity = Similarity2::new(Vector2::new(0.0, 0.0), 0.0, scale);
+    Circle::new(Point2::new(10, 10), 5).transform(&similarity)
+  }
+
+  #[test]
+  fn circle_non_uniform_scale_f64() {
+    let circle = Circle::<f64, f64>::new(Point2::new(1.0, 1.0), 1.0);
+    let ellipse = circle.transform(&scale(1.0, 3.0));
+
+    assert_eq!(ellipse.center(), &Point2::new(1.0, 3.0));
+    assert_approx_eq!(f64, ellipse.radius().0, 3.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, ellipse.radius().1, 1.0, epsilon = 1e-9);
+    assert_approx_eq!(f64, ellipse.angle().sin().abs(), 1.0, epsilon = 1e-9);
+  }
+
+  #[test]
+  fn ellipse_transform_u8() {
+    let ellipse = Ellipse::<u8, u8>::new(Point2::new(20, 20), (10, 5));
+
+    assert_eq!(
+      ellipse.transform(&Translation2::new(5.0, 0.0)),
+      Ellipse::new(Point2::new(25, 20), (10, 5))
+    );
+
+    let rotated = ellipse.transform(&Isometry2::new(Vector2::new(0.0, 0.0), FRAC_PI_4));
+    assert_eq!(rotated.center(), &Point2::new(0, 28));
+    assert_eq!(rotated.radius(), &(10, 5));
+    assert_approx_eq!(f64, *rotated.angle(), FRAC_PI_4, epsilon = 1e-9);
+  }
+
+  #[test]
+  fn ellipse_shear_f64() {
+    let ellipse = Ellipse::<f64, f64>::new(Point2::new(0.0, 0.0), (2.0, 1.0));
+    let shear =
+      Affine2::from_matrix_unchecked(Matrix3::new(1.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0));
+    let sheared = ellipse.transform(&shear);
+
+    // The area of an ellipse scales with the determinant, which is 1 for a shear.
+    assert_approx_eq!(
+      f64,
+      sheared.radius().0 * sheared.radius().1,
+      2.0,
+      epsilon = 1e-9
+    );
+  }
+
+  #[test_case(RotatedEllipse::new(Point2::new(0.0, 0.0), (2.0, 1.0), 0.3); "rotated ellipse")]
+  #[test_case(RotatedEllipse::new(Point2::new(1.0, -1.0), (1.0, 1.0), 0.0); "circle")]
+  fn rotated_ellipse_affine_matches_similarity_f64(ellipse: RotatedEllipse<f64, f64>) {
+    let similarity = Similarity2::new(Vector2::new(1.0, 2.0), 0.5, 1.5);
+    let expected = ellipse.transform(&similarity);
+    let actual = ellipse.transform(&nalgebra::convert::<_, Affine2<f64>>(similarity));
+
+    assert_approx_eq!(f64, actual.center().x, expected.center().x, epsilon = 1e-9);
+    assert_approx_eq!(f64, actual.center().y, expected.center().y, epsilon = 1e-9);
+    assert_approx_eq!(f64, actual.radius().0, expected.radius().0, epsilon = 1e-9);
+    assert_approx_eq!(f64, actual.radius().1, expected.radius().1, epsilon = 1e-9);
+    if expected.radius().0 != expected.radius().1 {
+      assert_approx_eq!(
+        f64,
+        (actual.angle() - expected.angle()).sin(),
+        0.0,
+        epsilon = 1e-9
+      );
+    }
+  }
+
+  #[test]
+  fn rotated_rectangle_transform_u8() {
+    let rectangle = RotatedRectangle::<u8>::new(Point2::new(10, 10), (4, 2), 0.0);
+
+    let rotated = rectangle.transform(&Similarity2::new(Vector2::new(0.0, 0.0), FRAC_PI_2, 2.0));
+    assert_eq!(rotated.center(), &Point2::new(0, 20));
+    assert_eq!(rotated.half_size(), &(8, 4));
+    assert_approx_eq!(f64, *rotated.angle(), FRAC_PI_2, epsilon = 1e-9);
+
+    assert_eq!(
+      rectangle.transform(&scale(2.0, 1.0)),
+      Polygon::new(vec![
+        Point2::new(12, 8),
+        Point2::new(28, 8),
+        Point2::new(28, 12),
+        Point2::new(12, 12),
+      ])
+    );
+  }
+
+  #[test]
+  fn line_and_triangle_transform_u8() {
+    let translation = Translation2::new(1.0, 1.0);
+
+    assert_eq!(
+      Line::new(Point2::new(5, 5), Point2::new(0, 0)).transform(&translation),
+      Line::new(Point2::new(1, 1), Point2::new(6, 6))
+    );
+    assert_eq!(
+      Triangle::new([0, 0].into(), [10, 0].into(), [0, 10].into()).transform(&translation),
+      Triangle::new([1, 1].into(), [11, 1].into(), [1, 11].into())
+    );
+  }
+
+  #[test]
+  fn polygon_with_holes_mirror_keeps_orientation() {
+    let square = |min: i32, max: i32| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let polygon = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+    let mirrored = polygon.transform(&scale(-1.0, 1.0));
+
+    assert!(mirrored.exterior().signed_area() > 0.0);
+    assert!(mirrored.holes()[0].signed_area() < 0.0);
+    assert_eq!(mirrored.exterior().points()[0], Point2::new(0, 30));
+  }
+
+  #[test]
+  fn polygon_with_holes_scale_down_drops_collapsed_holes_u8() {
+    let square = |min: u8, max: u8| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let shrink = Similarity2::new(Vector2::new(0.0, 0.0), 0.0, 0.1);
+
+    // The hole would round to the exterior itself.
+    let polygon = PolygonWithHoles::new(square(0, 20), vec![square(4, 16)]).unwrap();
+    let scaled = polygon.transform(&shrink);
+
+    assert_eq!(scaled.exterior(), &square(0, 2));
+    assert!(scaled.holes().is_empty());
+
+    // The second hole collapses into a point, the first one survives.
+    let polygon =
+      PolygonWithHoles::new(square(0, 200), vec![square(10, 20), square(100, 104)]).unwrap();
+    let scaled = polygon.transform(&shrink);
+
+    assert_eq!(scaled.holes().len(), 1);
+    assert_eq!(
+      PolygonWithHoles::new(scaled.exterior().clone(), scaled.holes().clone()),
+      Ok(scaled)
+    );
+  }
+
+  #[test]
+  fn shape_collection_transform_u8() {
+    let collection = ShapeCollection::<u8, u8>::new(vec![
+      Rectangle::new(Point2::new(0, 0), Point2::new(10, 10)).into(),
+      Circle::new(Point2::new(20, 20), 5).into(),
+      Ellipse::new(Point2::new(30, 30), (5, 2)).into(),
+    ]);
+
+    let scaled = collection.transform(&scale(2.0, 1.0));
+
+    assert_eq!(
+      scaled.shapes()[0],
+      Shape::Polygon(Polygon::new(vec![
+        Point2::new(0, 0),
+        Point2::new(20, 0),
+        Point2::new(20, 10),
+        Point2::new(0, 10),
+      ]))
+    );
+    assert!(
+      matches!(&scaled.shapes()[1], Shape::RotatedEllipse(ellipse) if ellipse.center() == &Point2::new(40, 20) && ellipse.radius() == &(10, 5))
+    );
+    assert!(
+      matches!(&scaled.shapes()[2], Shape::RotatedEllipse(ellipse) if ellipse.center() == &Point2::new(60, 30) && ellipse.radius() == &(10, 2))
+    );
+  }
+
+  #[proptest]
+  fn shape_translate_moves_centroid_f64(
+    #[strategy(-1000.0..1000.0)] x: f64,
+    #[strategy(-1000.0..1000.0)] y: f64,
+  ) {
+    let shape: Shape<f64, f64> =
+      Triangle::new([0.0, 0.0].into(), [3.0, 0.0].into(), [0.0, 3.0].into()).into();
+    let moved = shape.transform(&Translation2::new(x, y));
+
+    assert_approx_eq!(f64, moved.centroid().x, 1.0 + x, epsilon = 1e-6);
+    assert_approx_eq!(f64, moved.centroid().y, 1.0 + y, epsilon = 1e-6);
+  }
+
+  #[proptest]
+  fn shape_transform_u8_fuzz(shape: ShapeView<u8, u8>, #[strategy(-4.0..4.0)] angle: f64) {
+    let shape: Shape<u8, u8> = shape.into();
+    let _out = shape.transform(&Similarity2::new(Vector2::new(10.0, -10.0), angle, 1.5));
+  }
+}
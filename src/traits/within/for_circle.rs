@@ -1,22 +1,32 @@
 use super::Within;
 use crate::*;
 
-impl Within<&Point2<u8>> for Circle<u8, u8> {
+impl<T, R> Within<&Point2<T>> for Circle<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = bool;
 
-  fn within(&self, other: &Point2<u8>) -> Self::Result {
-    if self.radius == 0 {
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    let radius: f64 = self.radius.into();
+
+    if radius == 0.0 {
       return other == &self.center;
     }
 
-    crate::distance_squared(&self.center, other) <= (self.radius as f64).powi(2)
+    crate::distance_squared(&self.center, other) <= radius.powi(2)
   }
 }
 
-impl Within<Point2<u8>> for Circle<u8, u8> {
+impl<T, R> Within<Point2<T>> for Circle<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = bool;
 
-  fn within(&self, other: Point2<u8>) -> Self::Result {
+  fn within(&self, other: Point2<T>) -> Self::Result {
     self.within(&other)
   }
 }
@@ -45,6 +55,27 @@ mod tests {
 
   #[proptest]
   fn circle_within_u8_fuzz(circle: Circle<u8, u8>, point: PointView<u8, 2>) {
-    let _out = circle.within(&point.into());
+    let _out = circle.within(&Point2::from(point));
+  }
+
+  #[test_case(Circle::new(Point2::from([0.5, 0.5]), 0.25), Point2::from([0.5, 0.5]) => true; "center")]
+  #[test_case(Circle::new(Point2::from([0.5, 0.5]), 0.25), Point2::from([0.75, 0.5]) => true; "inside edge right")]
+  #[test_case(Circle::new(Point2::from([0.5, 0.5]), 0.25), Point2::from([0.7, 0.7]) => false; "outside edge top-right")]
+  #[test_case(Circle::new(Point2::from([0.5, 0.5]), 0.25), Point2::from([0.76, 0.5]) => false; "outside right")]
+  #[test_case(Circle::new(Point2::from([0.5, 0.5]), 0.0), Point2::from([0.5, 0.5]) => true; "zero radius center")]
+  fn circle_within_f32(circle: Circle<f32, f32>, point: Point2<f32>) -> bool {
+    circle.within(point)
+  }
+
+  #[test_case(Circle::new(Point2::from([-10, -10]), 5), Point2::from([-10, -10]) => true; "center")]
+  #[test_case(Circle::new(Point2::from([-10, -10]), 5), Point2::from([-5, -10]) => true; "inside edge right")]
+  #[test_case(Circle::new(Point2::from([-10, -10]), 5), Point2::from([-16, -10]) => false; "outside left")]
+  fn circle_within_i16(circle: Circle<i16, u16>, point: Point2<i16>) -> bool {
+    circle.within(point)
+  }
+
+  #[proptest]
+  fn circle_within_f64_fuzz(circle: Circle<f64, f64>, point: PointView<f64, 2>) {
+    let _out = circle.within(&Point2::from(point));
   }
 }
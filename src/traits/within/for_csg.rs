@@ -0,0 +1,107 @@
+use super::Within;
+use crate::traits::SignedDistance;
+use crate::*;
+
+/// Checks if the point is inside the expression, and not on its outline.
+fn interior<T, U>(csg: &Csg<T, U>, point: &Point2<T>) -> bool
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  csg.within(point) && csg.signed_distance(point) < 0.0
+}
+
+impl<T, U> Within<&Point2<T>> for Csg<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, point: &Point2<T>) -> Self::Result {
+    match self {
+      Self::Shape(shape) => shape.within(point),
+      Self::Union(a, b) => a.within(point) || b.within(point),
+      Self::Intersection(a, b) => a.within(point) && b.within(point),
+      Self::Difference(a, b) => a.within(point) && !interior(b, point),
+      Self::SymmetricDifference(a, b) => {
+        (a.within(point) && !interior(b, point)) || (b.within(point) && !interior(a, point))
+      }
+    }
+  }
+}
+
+impl<T, U> Within<Point2<T>> for Csg<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, point: Point2<T>) -> Self::Result {
+    self.within(&point)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use crate::proptest::ShapeView;
+  use crate::testing::PointView2;
+  use crate::traits::Within;
+  use crate::{Circle, Csg, Point2, Rectangle, Shape};
+
+  fn circle() -> Csg<u8, u8> {
+    Circle::new(Point2::new(10, 10), 5).into()
+  }
+
+  fn rectangle() -> Rectangle<u8> {
+    Rectangle::new(Point2::new(10, 0), Point2::new(20, 20))
+  }
+
+  #[test_case(circle().union(rectangle()), [6, 10] => true; "union, circle")]
+  #[test_case(circle().union(rectangle()), [18, 2] => true; "union, rectangle")]
+  #[test_case(circle().union(rectangle()), [6, 6] => false; "union, outside")]
+  #[test_case(circle().intersection(rectangle()), [12, 10] => true; "intersection")]
+  #[test_case(circle().intersection(rectangle()), [6, 10] => false; "intersection, circle only")]
+  #[test_case(circle().intersection(rectangle()), [18, 10] => false; "intersection, rectangle only")]
+  #[test_case(circle().difference(rectangle()), [6, 10] => true; "difference")]
+  #[test_case(circle().difference(rectangle()), [10, 10] => true; "difference, shared outline")]
+  #[test_case(circle().difference(rectangle()), [12, 10] => false; "difference, removed")]
+  #[test_case(circle().symmetric_difference(rectangle()), [6, 10] => true; "symmetric difference, circle only")]
+  #[test_case(circle().symmetric_difference(rectangle()), [18, 10] => true; "symmetric difference, rectangle only")]
+  #[test_case(circle().symmetric_difference(rectangle()), [12, 10] => false; "symmetric difference, both")]
+  #[test_case(circle().symmetric_difference(rectangle()), [10, 10] => true; "symmetric difference, shared outline")]
+  fn csg_within_u8(csg: Csg<u8, u8>, point: [u8; 2]) -> bool {
+    csg.within(Point2::from(point))
+  }
+
+  #[proptest]
+  fn csg_within_matches_operands_u8(
+    a: ShapeView<u8, u8>,
+    b: ShapeView<u8, u8>,
+    point: PointView2<u8>,
+  ) {
+    let a: Shape<u8, u8> = a.into();
+    let b: Shape<u8, u8> = b.into();
+    let point: Point2<u8> = point.into();
+    let (in_a, in_b) = (a.within(&point), b.within(&point));
+    let csg = Csg::from(a);
+
+    assert_eq!(csg.clone().union(b.clone()).within(&point), in_a || in_b);
+    assert_eq!(
+      csg.clone().intersection(b.clone()).within(&point),
+      in_a && in_b
+    );
+    if !in_b {
+      assert_eq!(csg.clone().difference(b.clone()).within(&point), in_a);
+    }
+    if in_a && in_b {
+      assert!(csg
+        .difference(Csg::from(b.clone()).difference(b))
+        .within(&point));
+    }
+  }
+}
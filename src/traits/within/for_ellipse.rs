@@ -2,11 +2,18 @@ use super::Within;
 use crate::traits::bbox::*;
 use crate::*;
 
-impl Within<&Point2<u8>> for Ellipse<u8, u8> {
+impl<T, R> Within<&Point2<T>> for Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = bool;
 
-  fn within(&self, other: &Point2<u8>) -> Self::Result {
-    if *self.width() == 0 || *self.height() == 0 {
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    let rx: f64 = (*self.width()).into();
+    let ry: f64 = (*self.height()).into();
+
+    if rx == 0.0 || ry == 0.0 {
       return other == &self.center;
     }
 
@@ -14,25 +21,32 @@ impl Within<&Point2<u8>> for Ellipse<u8, u8> {
       return false;
     }
 
-    let px = (other.x as f64) - (self.center.x as f64);
+    let px = Into::<f64>::into(other.x) - Into::<f64>::into(self.center.x);
     let px2 = px.powi(2);
 
-    let py = (other.y as f64) - (self.center.y as f64);
+    let py = Into::<f64>::into(other.y) - Into::<f64>::into(self.center.y);
     let py2 = py.powi(2);
 
-    let rx2 = (*self.width() as f64).powi(2);
-    let ry2 = (*self.height() as f64).powi(2);
+    let rx2 = rx.powi(2);
+    let ry2 = ry.powi(2);
 
+    // Dividing first keeps points on the outline inside, and agrees with the exact integer
+    // check in `exact` for integer coordinates. Comparing `px2 * ry2 + py2 * rx2` against
+    // `rx2 * ry2` instead rounds some of them outside.
     let dst = px2 / rx2 + py2 / ry2;
 
     dst <= 1.0
   }
 }
 
-impl Within<Point2<u8>> for Ellipse<u8, u8> {
+impl<T, R> Within<Point2<T>> for Ellipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
   type Result = bool;
 
-  fn within(&self, other: Point2<u8>) -> Self::Result {
+  fn within(&self, other: Point2<T>) -> Self::Result {
     self.within(&other)
   }
 }
@@ -47,6 +61,8 @@ mod tests {
   #[test_case(Ellipse::new(Point2::from([0, 0]), (4, 5)), Point2::from([0, 0]) => true; "center")]
   #[test_case(Ellipse::new(Point2::from([0, 0]), (4, 5)), Point2::from([2, 2]) => true; "inside")]
   #[test_case(Ellipse::new(Point2::from([0, 0]), (5, 5)), Point2::from([0, 5]) => true; "edge")]
+  #[test_case(Ellipse::new(Point2::from([100, 100]), (5, 5)), Point2::from([103, 104]) => true; "edge, diagonal")]
+  #[test_case(Ellipse::new(Point2::from([100, 100]), (15, 20)), Point2::from([109, 116]) => true; "edge, non-circular")]
   #[test_case(Ellipse::new(Point2::from([0, 0]), (4, 5)), Point2::from([10, 11]) => false; "outside")]
   #[test_case(Ellipse::new(Point2::from([0, 0]), (4, 5)), Point2::from([255, 255]) => false; "outside max")]
   #[test_case(Ellipse::new(Point2::from([5, 5]), (4, 3)), Point2::from([6, 6]) => true; "non-centered inside")]
@@ -57,6 +73,28 @@ mod tests {
 
   #[proptest]
   fn ellipse_within_u8_fuzz(ellipse: Ellipse<u8, u8>, point: PointView<u8, 2>) {
-    let _out = ellipse.within(&point.into());
+    let _out = ellipse.within(&Point2::from(point));
+  }
+
+  #[test_case(Ellipse::new(Point2::from([0.5, 0.5]), (0.2, 0.1)), Point2::from([0.5, 0.5]) => true; "center")]
+  #[test_case(Ellipse::new(Point2::from([0.5, 0.5]), (0.2, 0.1)), Point2::from([0.65, 0.55]) => true; "inside")]
+  #[test_case(Ellipse::new(Point2::from([0.5, 0.5]), (0.2, 0.1)), Point2::from([0.5, 0.6]) => true; "edge")]
+  #[test_case(Ellipse::new(Point2::from([0.0, 0.5]), (0.85, 0.85)), Point2::from([0.75, 0.1]) => true; "edge, rounded")]
+  #[test_case(Ellipse::new(Point2::from([0.5, 0.5]), (0.2, 0.1)), Point2::from([0.65, 0.6]) => false; "outside, but within bbox")]
+  #[test_case(Ellipse::new(Point2::from([0.5, 0.5]), (0.2, 0.1)), Point2::from([0.9, 0.9]) => false; "outside")]
+  fn ellipse_within_f64(ellipse: Ellipse<f64, f64>, point: Point2<f64>) -> bool {
+    ellipse.within(point)
+  }
+
+  #[test_case(Ellipse::new(Point2::from([-5, 5]), (4, 3)), Point2::from([-4, 6]) => true; "inside")]
+  #[test_case(Ellipse::new(Point2::from([-5, 5]), (4, 3)), Point2::from([-1, 5]) => true; "edge")]
+  #[test_case(Ellipse::new(Point2::from([-5, 5]), (4, 3)), Point2::from([-1, 7]) => false; "outside")]
+  fn ellipse_within_i32(ellipse: Ellipse<i32, u16>, point: Point2<i32>) -> bool {
+    ellipse.within(point)
+  }
+
+  #[proptest]
+  fn ellipse_within_f32_fuzz(ellipse: Ellipse<f32, f32>, point: PointView<f32, 2>) {
+    let _out = ellipse.within(&Point2::from(point));
   }
 }
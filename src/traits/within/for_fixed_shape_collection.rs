@@ -0,0 +1,28 @@
+use super::Within;
+use crate::*;
+
+impl<T, U, const N: usize> Within<&Point2<T>> for FixedShapeCollection<T, U, N>
+where
+  T: Scalar,
+  U: Scalar,
+  Shape<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
+{
+  type Result = bool;
+
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    SliceShapeCollection::from(self).within(other)
+  }
+}
+
+impl<T, U, const N: usize> Within<Point2<T>> for FixedShapeCollection<T, U, N>
+where
+  T: Scalar,
+  U: Scalar,
+  Shape<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
+{
+  type Result = bool;
+
+  fn within(&self, other: Point2<T>) -> Self::Result {
+    self.within(&other)
+  }
+}
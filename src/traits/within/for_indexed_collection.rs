@@ -0,0 +1,26 @@
+use super::Within;
+use crate::*;
+
+impl<T, U> Within<&Point2<T>> for IndexedCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    !self.point_query(other).is_empty()
+  }
+}
+
+impl<T, U> Within<Point2<T>> for IndexedCollection<T, U>
+where
+  T: FloatMath,
+  U: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: Point2<T>) -> Self::Result {
+    self.within(&other)
+  }
+}
@@ -0,0 +1,37 @@
+use super::Within;
+use crate::*;
+
+impl Within<&Point2<u8>> for Mask {
+  type Result = bool;
+
+  #[inline]
+  fn within(&self, other: &Point2<u8>) -> Self::Result {
+    self.contains(other)
+  }
+}
+
+impl Within<Point2<u8>> for Mask {
+  type Result = bool;
+
+  #[inline]
+  fn within(&self, other: Point2<u8>) -> Self::Result {
+    self.contains(&other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use test_case::test_case;
+
+  use crate::{traits::Within, Circle, Mask, Point2};
+
+  #[test_case(Point2::new(10, 10) => true; "center")]
+  #[test_case(Point2::new(15, 10) => true; "edge")]
+  #[test_case(Point2::new(14, 14) => false; "outside")]
+  #[test_case(Point2::new(255, 255) => false; "outside max")]
+  fn mask_within_u8(point: Point2<u8>) -> bool {
+    let mask = Mask::from_shape(&Circle::new(Point2::new(10, 10), 5));
+
+    mask.within(point)
+  }
+}
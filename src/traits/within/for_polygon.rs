@@ -0,0 +1,162 @@
+use super::Within;
+use crate::traits::bbox::*;
+use crate::*;
+
+impl<T> Within<&Point2<T>> for Polygon<T>
+where
+  T: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    if self.points.is_empty() || !self.bbox().within(other) {
+      return false;
+    }
+
+    // Points on the outline are considered inside, like for every other shape.
+    if self.on_outline(other) {
+      return true;
+    }
+
+    let p = other.map(Into::<f64>::into);
+
+    let mut crossings = 0usize;
+    let mut winding = 0isize;
+
+    for edge in self.edges() {
+      let a = edge.start.map(Into::<f64>::into);
+      let b = edge.end.map(Into::<f64>::into);
+
+      // Positive if the point is to the left of the edge, negative if to the right.
+      let cross = (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+
+      // Count the edges crossing a ray cast from the point towards +x.
+      if a.y <= p.y {
+        if b.y > p.y && cross > 0.0 {
+          crossings += 1;
+          winding += 1;
+        }
+      } else if b.y <= p.y && cross < 0.0 {
+        crossings += 1;
+        winding -= 1;
+      }
+    }
+
+    match self.fill_rule {
+      FillRule::EvenOdd => crossings % 2 == 1,
+      FillRule::NonZero => winding != 0,
+    }
+  }
+}
+impl<T> Within<Point2<T>> for Polygon<T>
+where
+  T: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: Point2<T>) -> Self::Result {
+    self.within(&other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use crate::testing::*;
+  use crate::{traits::Within, FillRule, Point2, Polygon};
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  fn concave() -> Polygon<u8> {
+    // U-shaped polygon with the opening at the top
+    Polygon::new(vec![
+      Point2::new(0, 0),
+      Point2::new(30, 0),
+      Point2::new(30, 30),
+      Point2::new(20, 30),
+      Point2::new(20, 10),
+      Point2::new(10, 10),
+      Point2::new(10, 30),
+      Point2::new(0, 30),
+    ])
+  }
+
+  fn pentagram() -> Polygon<u8> {
+    Polygon::new(vec![
+      Point2::new(50, 90),
+      Point2::new(74, 18),
+      Point2::new(12, 62),
+      Point2::new(88, 62),
+      Point2::new(26, 18),
+    ])
+  }
+
+  #[test_case(concave(), Point2::new(5, 5) => true; "inside")]
+  #[test_case(concave(), Point2::new(5, 25) => true; "inside left arm")]
+  #[test_case(concave(), Point2::new(25, 25) => true; "inside right arm")]
+  #[test_case(concave(), Point2::new(15, 20) => false; "inside notch")]
+  #[test_case(concave(), Point2::new(15, 10) => true; "notch bottom edge")]
+  #[test_case(concave(), Point2::new(0, 0) => true; "vertex")]
+  #[test_case(concave(), Point2::new(30, 15) => true; "right edge")]
+  #[test_case(concave(), Point2::new(31, 15) => false; "outside right")]
+  #[test_case(concave(), Point2::new(255, 255) => false; "outside max")]
+  #[test_case(pentagram(), Point2::new(50, 80) => true; "star tip")]
+  #[test_case(pentagram(), Point2::new(50, 50) => false; "star center")]
+  #[test_case(pentagram(), Point2::new(10, 10) => false; "outside star")]
+  fn polygon_within_u8(polygon: Polygon<u8>, point: Point2<u8>) -> bool {
+    polygon.within(point)
+  }
+
+  #[test_case(pentagram(), Point2::new(50, 80) => true; "star tip")]
+  #[test_case(pentagram(), Point2::new(50, 50) => true; "star center")]
+  #[test_case(pentagram(), Point2::new(10, 10) => false; "outside star")]
+  fn polygon_within_nonzero_u8(polygon: Polygon<u8>, point: Point2<u8>) -> bool {
+    polygon.with_fill_rule(FillRule::NonZero).within(point)
+  }
+
+  #[test_case(vec![], Point2::new(0, 0) => false; "empty")]
+  #[test_case(vec![Point2::new(5, 5)], Point2::new(5, 5) => true; "single point")]
+  #[test_case(vec![Point2::new(0, 0), Point2::new(10, 10)], Point2::new(5, 5) => true; "segment")]
+  #[test_case(vec![Point2::new(0, 0), Point2::new(10, 10)], Point2::new(5, 6) => false; "off segment")]
+  fn polygon_within_degenerate_u8(points: Vec<Point2<u8>>, point: Point2<u8>) -> bool {
+    Polygon::new(points).within(point)
+  }
+
+  #[test_case(Point2::new(0.5, 0.25) => true; "inside")]
+  #[test_case(Point2::new(0.5, 0.75) => false; "inside notch")]
+  #[test_case(Point2::new(0.25, 1.0) => true; "top edge")]
+  fn polygon_within_f32(point: Point2<f32>) -> bool {
+    let polygon = Polygon::new(vec![
+      Point2::new(0.0, 0.0),
+      Point2::new(1.0, 0.0),
+      Point2::new(1.0, 1.0),
+      Point2::new(0.75, 1.0),
+      Point2::new(0.5, 0.5),
+      Point2::new(0.25, 1.0),
+      Point2::new(0.0, 1.0),
+    ]);
+
+    polygon.within(point)
+  }
+
+  #[proptest]
+  fn polygon_within_u8_fuzz(polygon: Polygon<u8>, point: PointView2<u8>) {
+    let _out = polygon.within(&Point2::from(point));
+  }
+
+  #[proptest]
+  fn polygon_within_rules_agree_on_triangles_u8(
+    triangle: crate::Triangle<u8>,
+    point: PointView2<u8>,
+  ) {
+    let polygon = Polygon::new(vec![triangle.0, triangle.1, triangle.2]);
+    let point: Point2<_> = point.into();
+
+    assert_eq!(polygon.within(&point), triangle.within(&point));
+    assert_eq!(
+      polygon.with_fill_rule(FillRule::NonZero).within(&point),
+      triangle.within(&point)
+    );
+  }
+}
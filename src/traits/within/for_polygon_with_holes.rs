@@ -0,0 +1,115 @@
+use super::Within;
+use crate::*;
+
+impl<T> Within<&Point2<T>> for PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    if !self.exterior.within(other) {
+      return false;
+    }
+
+    // Hole outlines are part of the shape boundary, so only points strictly inside are excluded.
+    !self
+      .holes
+      .iter()
+      .any(|hole| hole.within(other) && !hole.on_outline(other))
+  }
+}
+impl<T> Within<Point2<T>> for PolygonWithHoles<T>
+where
+  T: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: Point2<T>) -> Self::Result {
+    self.within(&other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::testing::*;
+  use crate::{traits::Within, Point2, Polygon, PolygonWithHoles};
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  fn torso() -> PolygonWithHoles<u8> {
+    PolygonWithHoles::new(
+      Polygon::new(vec![
+        Point2::new(0, 0),
+        Point2::new(40, 0),
+        Point2::new(40, 60),
+        Point2::new(0, 60),
+      ]),
+      vec![
+        Polygon::new(vec![
+          Point2::new(18, 5),
+          Point2::new(22, 5),
+          Point2::new(22, 55),
+          Point2::new(18, 55),
+        ]),
+        Polygon::new(vec![
+          Point2::new(5, 5),
+          Point2::new(10, 5),
+          Point2::new(5, 10),
+        ]),
+      ],
+    )
+    .unwrap()
+  }
+
+  #[test_case(torso(), Point2::new(10, 30) => true; "inside")]
+  #[test_case(torso(), Point2::new(0, 0) => true; "exterior vertex")]
+  #[test_case(torso(), Point2::new(40, 30) => true; "exterior edge")]
+  #[test_case(torso(), Point2::new(20, 30) => false; "inside hole")]
+  #[test_case(torso(), Point2::new(18, 30) => true; "hole edge")]
+  #[test_case(torso(), Point2::new(22, 55) => true; "hole vertex")]
+  #[test_case(torso(), Point2::new(6, 6) => false; "inside triangular hole")]
+  #[test_case(torso(), Point2::new(9, 9) => true; "next to triangular hole")]
+  #[test_case(torso(), Point2::new(41, 30) => false; "outside")]
+  #[test_case(torso(), Point2::new(255, 255) => false; "outside max")]
+  fn polygon_with_holes_within_u8(polygon: PolygonWithHoles<u8>, point: Point2<u8>) -> bool {
+    polygon.within(point)
+  }
+
+  #[test_case(Point2::new(0.25, 0.5) => true; "inside")]
+  #[test_case(Point2::new(0.5, 0.5) => false; "inside hole")]
+  #[test_case(Point2::new(0.75, 0.5) => true; "hole edge")]
+  fn polygon_with_holes_within_f32(point: Point2<f32>) -> bool {
+    let polygon = PolygonWithHoles::new(
+      Polygon::new(vec![
+        Point2::new(0.0, 0.0),
+        Point2::new(1.0, 0.0),
+        Point2::new(1.0, 1.0),
+        Point2::new(0.0, 1.0),
+      ]),
+      vec![Polygon::new(vec![
+        Point2::new(0.25, 0.25),
+        Point2::new(0.75, 0.25),
+        Point2::new(0.75, 0.75),
+        Point2::new(0.25, 0.75),
+      ])],
+    )
+    .unwrap();
+
+    polygon.within(point)
+  }
+
+  #[proptest]
+  fn polygon_with_holes_within_u8_fuzz(point: PointView2<u8>) {
+    let polygon = torso();
+    let point: Point2<u8> = point.into();
+
+    let expected = polygon.exterior().within(&point)
+      && polygon
+        .holes()
+        .iter()
+        .all(|hole| !hole.within(&point) || hole.on_outline(&point));
+
+    assert_eq!(polygon.within(&point), expected);
+  }
+}
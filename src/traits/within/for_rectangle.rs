@@ -51,6 +51,18 @@ mod tests {
 
   #[proptest]
   fn rectangle_within_u8_fuzz(rectangle: Rectangle<u8>, point: PointView2<u8>) {
-    let _out = rectangle.within(&point.into());
+    let _out = rectangle.within(&Point2::from(point));
+  }
+
+  #[test_case(Rectangle::new(Point2::from([0.25, 0.25]), Point2::from([0.75, 0.75])), Point2::from([0.25, 0.25]) => true; "top-left")]
+  #[test_case(Rectangle::new(Point2::from([0.25, 0.25]), Point2::from([0.75, 0.75])), Point2::from([0.5, 0.5]) => true; "center")]
+  #[test_case(Rectangle::new(Point2::from([0.25, 0.25]), Point2::from([0.75, 0.75])), Point2::from([0.75, 0.8]) => false; "outside bottom")]
+  fn rectangle_within_f32(rectangle: Rectangle<f32>, point: Point2<f32>) -> bool {
+    rectangle.within(point)
+  }
+
+  #[proptest]
+  fn rectangle_within_f64_fuzz(rectangle: Rectangle<f64>, point: PointView2<f64>) {
+    let _out = rectangle.within(&Point2::from(point));
   }
 }
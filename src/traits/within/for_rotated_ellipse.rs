@@ -0,0 +1,80 @@
+use super::Within;
+use crate::*;
+
+impl<T, R> Within<&Point2<T>> for RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    let rx: f64 = (*self.width()).into();
+    let ry: f64 = (*self.height()).into();
+
+    if rx == 0.0 || ry == 0.0 {
+      return other == &self.center;
+    }
+
+    let local = self.local_point(other);
+    let dst = (local.x / rx).powi(2) + (local.y / ry).powi(2);
+
+    // Rotating the point introduces rounding errors, which would exclude points on the edge.
+    dst <= 1.0 + f64::EPSILON * 4.0
+  }
+}
+
+impl<T, R> Within<Point2<T>> for RotatedEllipse<T, R>
+where
+  T: FloatMath,
+  R: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: Point2<T>) -> Self::Result {
+    self.within(&other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+  use crate::testing::PointView;
+  use crate::{traits::Within, Ellipse, Point2, RotatedEllipse};
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (10, 3), 0.0), Point2::from([30, 20]) => true; "not rotated, edge")]
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (10, 3), 0.0), Point2::from([20, 24]) => false; "not rotated, outside")]
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (10, 3), FRAC_PI_2), Point2::from([20, 30]) => true; "quarter turn, edge")]
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (10, 3), FRAC_PI_2), Point2::from([30, 20]) => false; "quarter turn, outside")]
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (10, 3), FRAC_PI_4), Point2::from([26, 26]) => true; "diagonal, inside")]
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (10, 3), FRAC_PI_4), Point2::from([26, 14]) => false; "diagonal, outside")]
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (0, 3), FRAC_PI_4), Point2::from([20, 20]) => true; "zero radius, center")]
+  #[test_case(RotatedEllipse::new(Point2::from([20, 20]), (10, 3), FRAC_PI_4), Point2::from([255, 255]) => false; "outside max")]
+  fn rotated_ellipse_within_u8(ellipse: RotatedEllipse<u8, u8>, point: Point2<u8>) -> bool {
+    ellipse.within(point)
+  }
+
+  #[test_case(Point2::from([0.6, 0.6]) => true; "inside")]
+  #[test_case(Point2::from([0.6, 0.4]) => false; "outside")]
+  fn rotated_ellipse_within_f32(point: Point2<f32>) -> bool {
+    RotatedEllipse::new(Point2::from([0.5, 0.5]), (0.3, 0.05), FRAC_PI_4).within(point)
+  }
+
+  #[proptest]
+  fn rotated_ellipse_within_u8_fuzz(ellipse: RotatedEllipse<u8, u8>, point: PointView<u8, 2>) {
+    let _out = ellipse.within(&Point2::from(point));
+  }
+
+  #[proptest]
+  fn rotated_ellipse_within_matches_ellipse_u8(ellipse: Ellipse<u8, u8>, point: PointView<u8, 2>) {
+    let point: Point2<_> = point.into();
+
+    assert_eq!(
+      RotatedEllipse::from(ellipse).within(&point),
+      ellipse.within(&point)
+    );
+  }
+}
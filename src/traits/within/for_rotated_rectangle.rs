@@ -0,0 +1,63 @@
+use super::Within;
+use crate::*;
+
+impl<T> Within<&Point2<T>> for RotatedRectangle<T>
+where
+  T: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    let local = self.local_point(other);
+
+    // Rotating the point introduces rounding errors, which would exclude points on the edge.
+    let tolerance = f64::EPSILON * 4.0 * local.x.abs().max(local.y.abs()).max(1.0);
+
+    local.x.abs() <= Into::<f64>::into(self.half_size.0) + tolerance
+      && local.y.abs() <= Into::<f64>::into(self.half_size.1) + tolerance
+  }
+}
+
+impl<T> Within<Point2<T>> for RotatedRectangle<T>
+where
+  T: FloatMath,
+{
+  type Result = bool;
+
+  fn within(&self, other: Point2<T>) -> Self::Result {
+    self.within(&other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+  use crate::testing::*;
+  use crate::{traits::Within, Point2, RotatedRectangle};
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 2), 0.0), Point2::from([30, 22]) => true; "not rotated, corner")]
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 2), 0.0), Point2::from([20, 23]) => false; "not rotated, outside")]
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 2), FRAC_PI_2), Point2::from([22, 30]) => true; "quarter turn, corner")]
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 2), FRAC_PI_2), Point2::from([30, 20]) => false; "quarter turn, outside")]
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 2), PI), Point2::from([10, 18]) => true; "half turn, corner")]
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 1), FRAC_PI_4), Point2::from([27, 27]) => true; "diagonal stripe, inside")]
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 1), FRAC_PI_4), Point2::from([27, 25]) => false; "diagonal stripe, outside")]
+  #[test_case(RotatedRectangle::new(Point2::from([20, 20]), (10, 1), FRAC_PI_4), Point2::from([28, 28]) => false; "diagonal stripe, past the end")]
+  fn rotated_rectangle_within_u8(rectangle: RotatedRectangle<u8>, point: Point2<u8>) -> bool {
+    rectangle.within(point)
+  }
+
+  #[test_case(Point2::from([0.7, 0.7]) => true; "inside")]
+  #[test_case(Point2::from([0.7, 0.3]) => false; "outside")]
+  fn rotated_rectangle_within_f64(point: Point2<f64>) -> bool {
+    RotatedRectangle::new(Point2::from([0.5, 0.5]), (0.5, 0.1), FRAC_PI_4).within(point)
+  }
+
+  #[proptest]
+  fn rotated_rectangle_within_u8_fuzz(rectangle: RotatedRectangle<u8>, point: PointView2<u8>) {
+    let _out = rectangle.within(&Point2::from(point));
+  }
+}
@@ -0,0 +1,197 @@
+use super::Within;
+use crate::primitive::{self, AsPrimitives};
+use crate::traits::Geometry;
+use crate::*;
+
+// Shape-in-shape containment: `container.within(&shape)` checks if the whole `shape`,
+// including its outline, lies inside `container`. Shared outlines count as inside.
+macro_rules! impl_within_shape {
+  ($($shape: ty => [$($generic: ident),*]);* $(;)?) => {$(
+    impl<$($generic,)* S> Within<&S> for $shape
+    where
+      $($generic: FloatMath,)*
+      S: Geometry,
+    {
+      type Result = bool;
+
+      fn within(&self, other: &S) -> Self::Result {
+        let container = self.primitives();
+
+        other
+          .primitives()
+          .iter()
+          .all(|primitive| primitive::contains(&container, primitive))
+      }
+    }
+  )*};
+}
+
+impl_within_shape!(
+  Line<T> => [T];
+  Rectangle<T> => [T];
+  RotatedRectangle<T> => [T];
+  Circle<T, R> => [T, R];
+  Ellipse<T, R> => [T, R];
+  RotatedEllipse<T, R> => [T, R];
+  Triangle<T> => [T];
+  Polygon<T> => [T];
+  PolygonWithHoles<T> => [T];
+  ShapeCollection<T, R> => [T, R];
+  Shape<T, R> => [T, R];
+);
+
+#[cfg(test)]
+mod tests {
+  use core::f64::consts::FRAC_PI_4;
+
+  use test_case::test_case;
+  use test_strategy::proptest;
+
+  use crate::primitive::AsPrimitives;
+  use crate::proptest::ShapeView;
+  use crate::testing::{circle, collection, ellipse, rectangle, triangle};
+  use crate::traits::{Intersects, Within};
+  use crate::{
+    Circle, Line, Point2, Polygon, PolygonWithHoles, Rectangle, RotatedEllipse, RotatedRectangle,
+    Shape, ShapeCollection,
+  };
+
+  // Rectangle
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([2, 2], [8, 8]) => true; "rectangle/rectangle nested")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([0, 0], [10, 10]) => true; "rectangle/rectangle equal")]
+  #[test_case(rectangle([0, 0], [10, 10]), rectangle([5, 5], [15, 15]) => false; "rectangle/rectangle overlapping")]
+  #[test_case(rectangle([0, 0], [10, 10]), circle([5, 5], 5) => true; "rectangle/circle touching")]
+  #[test_case(rectangle([0, 0], [10, 10]), circle([5, 5], 6) => false; "rectangle/circle too large")]
+  #[test_case(rectangle([0, 0], [20, 10]), ellipse([10, 5], (10, 5)) => true; "rectangle/ellipse inscribed")]
+  #[test_case(rectangle([0, 0], [10, 10]), triangle([0, 0], [10, 0], [10, 10]) => true; "rectangle/triangle sharing edges")]
+  #[test_case(rectangle([0, 0], [10, 10]), triangle([0, 0], [11, 0], [10, 10]) => false; "rectangle/triangle crossing")]
+  #[test_case(rectangle([0, 0], [10, 10]), collection(vec![circle([2, 2], 2), circle([8, 8], 2)]) => true; "rectangle/collection")]
+  #[test_case(rectangle([0, 0], [10, 10]), collection(vec![circle([2, 2], 2), circle([20, 20], 2)]) => false; "rectangle/collection partially outside")]
+  // Circle
+  #[test_case(circle([0, 0], 10), circle([5, 0], 5) => true; "circle/circle touching inside")]
+  #[test_case(circle([0, 0], 10), circle([6, 0], 5) => false; "circle/circle crossing")]
+  #[test_case(circle([0, 0], 5), rectangle([-3, -4], [3, 4]) => true; "circle/rectangle inscribed")]
+  #[test_case(circle([0, 0], 5), rectangle([-4, -4], [4, 4]) => false; "circle/rectangle corners outside")]
+  #[test_case(circle([0, 0], 10), ellipse([0, 0], (10, 5)) => true; "circle/ellipse touching")]
+  #[test_case(circle([0, 0], 10), ellipse([0, 0], (11, 5)) => false; "circle/ellipse too wide")]
+  #[test_case(circle([0, 0], 10), triangle([0, 10], [-6, -8], [6, -8]) => true; "circle/triangle inscribed")]
+  #[test_case(circle([0, 0], 5), collection(vec![]) => true; "circle/empty collection")]
+  // Ellipse
+  #[test_case(ellipse([0, 0], (10, 5)), circle([0, 0], 5) => true; "ellipse/circle touching")]
+  #[test_case(ellipse([0, 0], (10, 5)), circle([5, 0], 5) => false; "ellipse/circle crossing")]
+  #[test_case(ellipse([0, 0], (10, 5)), ellipse([2, 0], (7, 3)) => true; "ellipse/ellipse nested")]
+  #[test_case(ellipse([0, 0], (10, 5)), ellipse([0, 0], (5, 10)) => false; "ellipse/ellipse crossing")]
+  #[test_case(ellipse([0, 0], (10, 5)), triangle([-5, 0], [5, 0], [0, 4]) => true; "ellipse/triangle")]
+  #[test_case(ellipse([0, 0], (10, 5)), rectangle([-8, -3], [8, 3]) => true; "ellipse/rectangle")]
+  #[test_case(ellipse([0, 0], (10, 5)), rectangle([-9, -3], [9, 3]) => false; "ellipse/rectangle corners outside")]
+  // Triangle
+  #[test_case(triangle([0, 0], [20, 0], [0, 20]), triangle([1, 1], [10, 1], [1, 10]) => true; "triangle/triangle nested")]
+  #[test_case(triangle([0, 0], [20, 0], [0, 20]), triangle([0, 0], [20, 0], [0, 20]) => true; "triangle/triangle equal")]
+  #[test_case(triangle([0, 0], [20, 0], [0, 20]), triangle([5, 5], [16, 5], [5, 15]) => false; "triangle/triangle crossing")]
+  #[test_case(triangle([0, 0], [20, 0], [0, 20]), circle([5, 5], 3) => true; "triangle/circle")]
+  #[test_case(triangle([0, 0], [20, 0], [0, 20]), circle([5, 5], 6) => false; "triangle/circle crossing")]
+  #[test_case(triangle([0, 0], [20, 0], [0, 20]), rectangle([0, 0], [10, 10]) => true; "triangle/rectangle touching")]
+  #[test_case(triangle([0, 0], [20, 0], [0, 20]), collection(vec![triangle([0, 0], [5, 0], [0, 5]), circle([5, 5], 1)]) => true; "triangle/collection")]
+  // Collection
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10])]), rectangle([5, 2], [15, 8]) => true; "collection/rectangle across the seam")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([11, 0], [20, 10])]), rectangle([5, 2], [15, 8]) => false; "collection/rectangle across a gap")]
+  #[test_case(collection(vec![triangle([0, 0], [10, 0], [0, 10]), triangle([10, 0], [10, 10], [0, 10])]), rectangle([0, 0], [10, 10]) => true; "collection/rectangle split into triangles")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), circle([10, 5], 5)]), circle([10, 5], 4) => true; "collection/circle inside one member")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), circle([14, 5], 5)]), ellipse([10, 5], (8, 2)) => true; "collection/ellipse across members")]
+  #[test_case(collection(vec![circle([0, 0], 5), circle([6, 0], 5)]), rectangle([-1, -1], [7, 1]) => true; "collection/rectangle across circles")]
+  #[test_case(collection(vec![]), circle([0, 0], 1) => false; "empty collection/circle")]
+  #[test_case(collection(vec![circle([0, 0], 10), circle([30, 0], 10)]), collection(vec![circle([0, 0], 5), circle([30, 0], 5)]) => true; "collection/collection")]
+  #[test_case(collection(vec![circle([0, 0], 10), circle([30, 0], 10)]), collection(vec![circle([0, 0], 5), circle([15, 0], 5)]) => false; "collection/collection between members")]
+  fn shape_within_shape(container: Shape<i32, u32>, shape: Shape<i32, u32>) -> bool {
+    container.within(&shape)
+  }
+
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), Line::new(Point2::new(2, 0), Point2::new(8, 0)) => true; "line/overlapping line")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), Line::new(Point2::new(2, 0), Point2::new(12, 0)) => false; "line/longer line")]
+  #[test_case(Line::new(Point2::new(0, 0), Point2::new(10, 0)), Line::new(Point2::new(2, 0), Point2::new(8, 1)) => false; "line/crossing line")]
+  fn line_within_line(container: Line<i32>, line: Line<i32>) -> bool {
+    container.within(&line)
+  }
+
+  #[test_case(rectangle([0, 0], [10, 10]), Line::new(Point2::new(0, 0), Point2::new(10, 10)) => true; "rectangle/diagonal")]
+  #[test_case(circle([0, 0], 5), Line::new(Point2::new(-3, -4), Point2::new(3, 4)) => true; "circle/chord")]
+  #[test_case(circle([0, 0], 5), Line::new(Point2::new(-3, -4), Point2::new(4, 4)) => false; "circle/secant")]
+  #[test_case(collection(vec![rectangle([0, 0], [10, 10]), rectangle([10, 0], [20, 10])]), Line::new(Point2::new(0, 5), Point2::new(20, 5)) => true; "collection/line across the seam")]
+  fn line_within_shape(container: Shape<i32, u32>, line: Line<i32>) -> bool {
+    container.within(&line)
+  }
+
+  #[test]
+  fn rotated_shapes_within() {
+    let rectangle = RotatedRectangle::new(Point2::new(0.0, 0.0), (10.0, 2.0), FRAC_PI_4);
+    let ellipse = RotatedEllipse::<f64, f64>::new(Point2::new(0.0, 0.0), (10.0, 2.0), FRAC_PI_4);
+
+    assert!(rectangle.within(&ellipse));
+    assert!(!ellipse.within(&rectangle));
+    assert!(ellipse.within(&Circle::<f64, f64>::new(Point2::new(5.0, 5.0), 1.0)));
+    assert!(!rectangle.within(&Circle::<f64, f64>::new(Point2::new(5.0, -5.0), 1.0)));
+  }
+
+  #[test]
+  fn polygon_with_holes_within() {
+    let square = |min: i32, max: i32| {
+      Polygon::new(vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+      ])
+    };
+    let polygon = PolygonWithHoles::new(square(0, 30), vec![square(10, 20)]).unwrap();
+
+    assert!(polygon.within(&Circle::<i32, u32>::new(Point2::new(5, 15), 5)));
+    assert!(!polygon.within(&Circle::<i32, u32>::new(Point2::new(15, 15), 2)));
+    assert!(!polygon.within(&square(5, 25)));
+    assert!(square(-10, 40).within(&polygon));
+  }
+
+  #[test]
+  fn concave_polygon_within() {
+    let polygon = Polygon::new(vec![
+      Point2::new(0, 0),
+      Point2::new(30, 0),
+      Point2::new(30, 30),
+      Point2::new(20, 30),
+      Point2::new(20, 10),
+      Point2::new(10, 10),
+      Point2::new(10, 30),
+      Point2::new(0, 30),
+    ]);
+
+    assert!(polygon.within(&Rectangle::new(Point2::new(0, 0), Point2::new(30, 10))));
+    assert!(polygon.within(&Circle::<i32, u32>::new(Point2::new(5, 20), 5)));
+    assert!(!polygon.within(&Circle::<i32, u32>::new(Point2::new(15, 20), 4)));
+    assert!(!polygon.within(&Rectangle::new(Point2::new(0, 0), Point2::new(30, 30))));
+  }
+
+  #[proptest]
+  fn shape_within_itself_u8(shape: ShapeView<u8, u8>) {
+    let shape: Shape<u8, u8> = shape.into();
+
+    assert!(shape.within(&shape));
+  }
+
+  #[proptest]
+  fn shape_within_collection_with_it_u8(a: ShapeView<u8, u8>, b: ShapeView<u8, u8>) {
+    let a: Shape<u8, u8> = a.into();
+    let b: Shape<u8, u8> = b.into();
+    let collection = ShapeCollection::new(vec![b, a.clone()]);
+
+    assert!(collection.within(&a));
+  }
+
+  #[proptest]
+  fn shape_within_implies_intersects_u8(a: ShapeView<u8, u8>, b: ShapeView<u8, u8>) {
+    let a: Shape<u8, u8> = a.into();
+    let b: Shape<u8, u8> = b.into();
+
+    if a.within(&b) && !b.primitives().is_empty() {
+      assert!(a.intersects(&b));
+    }
+  }
+}
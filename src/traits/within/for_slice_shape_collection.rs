@@ -0,0 +1,28 @@
+use super::Within;
+use crate::*;
+
+impl<T, U> Within<&Point2<T>> for SliceShapeCollection<'_, T, U>
+where
+  T: Scalar,
+  U: Scalar,
+  Shape<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
+{
+  type Result = bool;
+
+  fn within(&self, other: &Point2<T>) -> Self::Result {
+    self.shapes().iter().any(|shape| shape.within(other))
+  }
+}
+
+impl<T, U> Within<Point2<T>> for SliceShapeCollection<'_, T, U>
+where
+  T: Scalar,
+  U: Scalar,
+  Shape<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
+{
+  type Result = bool;
+
+  fn within(&self, other: Point2<T>) -> Self::Result {
+    self.within(&other)
+  }
+}
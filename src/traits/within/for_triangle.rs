@@ -2,41 +2,47 @@ use super::Within;
 use crate::traits::bbox::*;
 use crate::*;
 
-impl Within<&Point2<u8>> for Triangle<u8> {
+impl<T> Within<&Point2<T>> for Triangle<T>
+where
+  T: FloatMath,
+{
   type Result = bool;
 
-  fn within(&self, other: &Point2<u8>) -> Self::Result {
+  fn within(&self, other: &Point2<T>) -> Self::Result {
     if !self.bbox().within(other) {
       return false;
     }
 
-    let a = self.0.map(|x| x as f64);
-    let b = self.1.map(|x| x as f64);
-    let c = self.2.map(|x| x as f64);
+    let a = self.0.map(Into::<f64>::into);
+    let b = self.1.map(Into::<f64>::into);
+    let c = self.2.map(Into::<f64>::into);
 
-    let p = other.map(|x| x as f64);
+    let p = other.map(Into::<f64>::into);
 
-    let area = |a: &Point2<f64>, b: &Point2<f64>, c: &Point2<f64>| {
-      let x1 = a.x - c.x;
-      let y1 = a.y - c.y;
-      let x2 = b.x - c.x;
-      let y2 = b.y - c.y;
-      let area = x1 * y2 - x2 * y1;
-      area.abs()
+    // Signed doubled area of the triangle formed by the edge and the point.
+    // Comparing signs instead of summing areas keeps the check exact for
+    // fractional coordinates.
+    let cross = |a: &Point2<f64>, b: &Point2<f64>, p: &Point2<f64>| {
+      (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
     };
 
-    let area_abc = area(&a, &b, &c);
-    let area_pbc = area(&p, &b, &c);
-    let area_apc = area(&a, &p, &c);
-    let area_abp = area(&a, &b, &p);
+    let d1 = cross(&a, &b, &p);
+    let d2 = cross(&b, &c, &p);
+    let d3 = cross(&c, &a, &p);
 
-    area_abc == area_pbc + area_apc + area_abp
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+
+    !(has_negative && has_positive)
   }
 }
-impl Within<Point2<u8>> for Triangle<u8> {
+impl<T> Within<Point2<T>> for Triangle<T>
+where
+  T: FloatMath,
+{
   type Result = bool;
 
-  fn within(&self, other: Point2<u8>) -> Self::Result {
+  fn within(&self, other: Point2<T>) -> Self::Result {
     self.within(&other)
   }
 }
@@ -57,6 +63,24 @@ mod tests {
 
   #[proptest]
   fn triangle_within_u8_fuzz(triangle: Triangle<u8>, point: PointView2<u8>) {
-    let _out = triangle.within(&point.into());
+    let _out = triangle.within(&Point2::from(point));
+  }
+
+  #[test_case(Triangle::new(Point2::from([0.0, 0.0]), Point2::from([1.0, 0.0]), Point2::from([0.0, 1.0])), Point2::from([0.1, 0.2]) => true; "inside")]
+  #[test_case(Triangle::new(Point2::from([0.0, 0.0]), Point2::from([1.0, 0.0]), Point2::from([0.0, 1.0])), Point2::from([0.25, 0.75]) => true; "on edge")]
+  #[test_case(Triangle::new(Point2::from([0.0, 0.0]), Point2::from([1.0, 0.0]), Point2::from([0.0, 1.0])), Point2::from([0.6, 0.6]) => false; "inside bbox")]
+  fn triangle_within_f32(triangle: Triangle<f32>, point: Point2<f32>) -> bool {
+    triangle.within(point)
+  }
+
+  #[test_case(Triangle::new(Point2::from([-10, -10]), Point2::from([10, -10]), Point2::from([0, 10])), Point2::from([0, 0]) => true; "center")]
+  #[test_case(Triangle::new(Point2::from([-10, -10]), Point2::from([10, -10]), Point2::from([0, 10])), Point2::from([-8, 8]) => false; "inside bbox")]
+  fn triangle_within_i16(triangle: Triangle<i16>, point: Point2<i16>) -> bool {
+    triangle.within(point)
+  }
+
+  #[proptest]
+  fn triangle_within_f64_fuzz(triangle: Triangle<f64>, point: PointView2<f64>) {
+    let _out = triangle.within(&Point2::from(point));
   }
 }
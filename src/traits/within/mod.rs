@@ -1,13 +1,37 @@
 mod for_circle;
+mod for_csg;
 mod for_ellipse;
+mod for_fixed_shape_collection;
+mod for_indexed_collection;
+mod for_mask;
+mod for_polygon;
+mod for_polygon_with_holes;
 mod for_rectangle;
+mod for_rotated_ellipse;
+mod for_rotated_rectangle;
 mod for_shape_collection;
+mod for_shapes;
+mod for_slice_shape_collection;
 mod for_triangle;
 
 use crate::*;
-use num::Unsigned;
 
 /// Generic trait to determine if a shape is within another shape.
+///
+/// `container.within(other)` checks if `other` lies inside `container`. The other value
+/// is either a point or any shape, in which case the whole shape must be covered.
+/// Points on the outline count as inside, so a shape is within itself.
+///
+/// # Example
+/// ```rust
+/// use rshapes::{Circle, Point2, Rectangle, traits::Within};
+///
+/// let chest = Rectangle::<u8>::new(Point2::new(0, 0), Point2::new(10, 10));
+///
+/// assert!(chest.within(&Point2::new(10, 5)));
+/// assert!(chest.within(&Circle::<u8, u8>::new(Point2::new(5, 5), 5)));
+/// assert!(!chest.within(&Circle::<u8, u8>::new(Point2::new(5, 5), 6)));
+/// ```
 pub trait Within<T> {
   type Result;
 
@@ -17,11 +41,15 @@ pub trait Within<T> {
 impl<T, U> Within<&Point2<T>> for Shape<T, U>
 where
   T: Scalar,
-  U: Scalar + Unsigned,
+  U: Scalar,
   Ellipse<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
   Circle<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
   Rectangle<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  RotatedEllipse<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  RotatedRectangle<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
   Triangle<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  Polygon<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  PolygonWithHoles<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
 {
   type Result = bool;
 
@@ -30,7 +58,11 @@ where
       Self::Circle(circle) => circle.within(other),
       Self::Ellipse(ellipse) => ellipse.within(other),
       Self::Rectangle(rectangle) => rectangle.within(other),
+      Self::RotatedRectangle(rectangle) => rectangle.within(other),
+      Self::RotatedEllipse(ellipse) => ellipse.within(other),
       Self::Triangle(triangle) => triangle.within(other),
+      Self::Polygon(polygon) => polygon.within(other),
+      Self::PolygonWithHoles(polygon) => polygon.within(other),
       Self::Collection(collection) => collection.within(other),
     }
   }
@@ -39,11 +71,15 @@ where
 impl<T, U> Within<Point2<T>> for Shape<T, U>
 where
   T: Scalar,
-  U: Scalar + Unsigned,
+  U: Scalar,
   Ellipse<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
   Circle<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
   Rectangle<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  RotatedEllipse<T, U>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  RotatedRectangle<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
   Triangle<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  Polygon<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
+  PolygonWithHoles<T>: for<'a> Within<&'a Point2<T>, Result = bool>,
 {
   type Result = bool;
 
@@ -57,7 +93,7 @@ mod tests {
   use crate::testing::PointView;
 
   use crate::{
-    traits::Within, Circle, Ellipse, Point2, Rectangle, Shape, ShapeCollection, Triangle,
+    traits::Within, Circle, Ellipse, Point2, Polygon, Rectangle, Shape, ShapeCollection, Triangle,
   };
 
   use crate::proptest::ShapeView;
@@ -68,6 +104,7 @@ mod tests {
   #[test_case(Shape::Circle(Circle::new(Point2::from([10, 10]), 5)), Point2::from([10, 10]) => true; "circle center")]
   #[test_case(Shape::Ellipse(Ellipse::new(Point2::from([10, 10]), (5, 5))), Point2::from([10, 10]) => true; "ellipse center")]
   #[test_case(Shape::Triangle(Triangle::new(Point2::from([0, 0]), Point2::from([10, 0]), Point2::from([0, 10]))), Point2::from([5, 5]) => true; "triangle center")]
+  #[test_case(Shape::Polygon(Polygon::new(vec![Point2::from([0, 0]), Point2::from([10, 0]), Point2::from([10, 10]), Point2::from([0, 10])])), Point2::from([5, 5]) => true; "polygon center")]
   #[test_case(Shape::Collection(ShapeCollection::new(vec![
     Shape::Rectangle(Rectangle::new(Point2::from([0, 0]), Point2::from([10, 10]))),
     Shape::Circle(Circle::new(Point2::from([10, 10]), 5)),
@@ -79,6 +116,18 @@ mod tests {
   #[proptest]
   fn shape_within_u8_fuzz(shape: ShapeView<u8, u8>, point: PointView<u8, 2>) {
     let shape: Shape<_, _> = shape.into();
-    let _out = shape.within(&point.into());
+    let _out = shape.within(&Point2::from(point));
+  }
+
+  #[test_case(Shape::Rectangle(Rectangle::new(Point2::from([0.0, 0.0]), Point2::from([0.5, 0.5]))), Point2::from([0.25, 0.25]) => true; "rectangle center")]
+  #[test_case(Shape::Circle(Circle::new(Point2::from([0.5, 0.5]), 0.1)), Point2::from([0.55, 0.55]) => true; "circle inside")]
+  #[test_case(Shape::Ellipse(Ellipse::new(Point2::from([0.5, 0.5]), (0.2, 0.1))), Point2::from([0.5, 0.65]) => false; "ellipse outside")]
+  #[test_case(Shape::Triangle(Triangle::new(Point2::from([0.0, 0.0]), Point2::from([1.0, 0.0]), Point2::from([0.0, 1.0]))), Point2::from([0.5, 0.5]) => true; "triangle edge")]
+  #[test_case(Shape::Collection(ShapeCollection::new(vec![
+    Shape::Rectangle(Rectangle::new(Point2::from([0.0, 0.0]), Point2::from([0.5, 0.5]))),
+    Shape::Circle(Circle::new(Point2::from([0.5, 0.5]), 0.1)),
+  ])), Point2::from([0.58, 0.5]) => true; "collection circle")]
+  fn shape_within_f32(shape: Shape<f32, f32>, point: Point2<f32>) -> bool {
+    shape.within(point)
   }
 }
@@ -1,6 +1,6 @@
 #![cfg(feature = "serde-serialize")]
 
-use rshapes::{Circle, Ellipse, Rectangle, Triangle};
+use rshapes::{Circle, Ellipse, Polygon, Rectangle, RotatedEllipse, RotatedRectangle, Triangle};
 
 use test_strategy::proptest;
 
@@ -20,4 +20,100 @@ test_serde!(
   serde_ellipse, Ellipse<u8, u8>;
   serde_rectangle, Rectangle<u8>;
   serde_triangle, Triangle<u8>;
+  serde_polygon, Polygon<u8>;
 );
+
+#[test]
+fn serde_polygon_with_holes() {
+  use rshapes::{Point2, PolygonWithHoles};
+
+  let polygon = PolygonWithHoles::<u8>::new(
+    Polygon::new(vec![
+      Point2::new(0, 0),
+      Point2::new(30, 0),
+      Point2::new(30, 30),
+      Point2::new(0, 30),
+    ]),
+    vec![Polygon::new(vec![
+      Point2::new(10, 10),
+      Point2::new(20, 10),
+      Point2::new(20, 20),
+    ])],
+  )
+  .unwrap();
+
+  let serialized = serde_json::to_string(&polygon).unwrap();
+  let deserialized: PolygonWithHoles<u8> = serde_json::from_str(&serialized).unwrap();
+  assert_eq!(polygon, deserialized);
+}
+
+#[test]
+fn serde_polygon_with_holes_validates() {
+  use rshapes::{PolygonWithHoles, Shape};
+
+  let outside = r#"{
+    "exterior": {"points": [[0, 0], [30, 0], [30, 30], [0, 30]]},
+    "holes": [{"points": [[40, 40], [50, 40], [50, 50]]}]
+  }"#;
+  let error = serde_json::from_str::<PolygonWithHoles<u8>>(outside).unwrap_err();
+  assert!(error
+    .to_string()
+    .contains("hole 0 is outside of the exterior"));
+
+  let shape = format!(r#"{{"PolygonWithHoles": {}}}"#, outside);
+  assert!(serde_json::from_str::<Shape<u8, u8>>(&shape).is_err());
+}
+
+#[test]
+fn serde_rotated_shapes() {
+  use rshapes::Point2;
+
+  let ellipse = RotatedEllipse::<u8, u8>::new(Point2::new(10, 20), (5, 3), 0.5);
+  let serialized = serde_json::to_string(&ellipse).unwrap();
+  let deserialized: RotatedEllipse<u8, u8> = serde_json::from_str(&serialized).unwrap();
+  assert_eq!(ellipse, deserialized);
+
+  let rectangle = RotatedRectangle::<u8>::new(Point2::new(10, 20), (5, 3), -1.25);
+  let serialized = serde_json::to_string(&rectangle).unwrap();
+  let deserialized: RotatedRectangle<u8> = serde_json::from_str(&serialized).unwrap();
+  assert_eq!(rectangle, deserialized);
+}
+
+#[test]
+fn serde_csg() {
+  use rshapes::{Csg, Point2};
+
+  let csg = Csg::<u8, u8>::from(Circle::new(Point2::new(10, 10), 8))
+    .difference(Rectangle::new(Point2::new(10, 0), Point2::new(20, 20)))
+    .union(Triangle::new(
+      Point2::new(0, 0),
+      Point2::new(5, 0),
+      Point2::new(0, 5),
+    ))
+    .intersection(Ellipse::new(Point2::new(10, 10), (9, 7)))
+    .symmetric_difference(Circle::new(Point2::new(0, 0), 2));
+
+  let serialized = serde_json::to_string(&csg).unwrap();
+  let deserialized: Csg<u8, u8> = serde_json::from_str(&serialized).unwrap();
+  assert_eq!(csg, deserialized);
+}
+
+#[test]
+fn serde_fixed_shape_collection() {
+  use rshapes::{FixedShapeCollection, Point2, Shape, ShapeCollection};
+
+  let shapes: Vec<Shape<u8, u8>> = vec![
+    Circle::new(Point2::new(10, 10), 8).into(),
+    Rectangle::new(Point2::new(0, 0), Point2::new(5, 5)).into(),
+  ];
+  let collection = ShapeCollection::new(shapes);
+  let fixed = FixedShapeCollection::<u8, u8, 2>::try_from(collection.clone()).unwrap();
+
+  // Both collections are stored as a plain list of shapes.
+  let serialized = serde_json::to_string(&fixed).unwrap();
+  assert_eq!(serialized, serde_json::to_string(&collection).unwrap());
+
+  let deserialized: FixedShapeCollection<u8, u8, 2> = serde_json::from_str(&serialized).unwrap();
+  assert_eq!(fixed, deserialized);
+  assert!(serde_json::from_str::<FixedShapeCollection<u8, u8, 1>>(&serialized).is_err());
+}
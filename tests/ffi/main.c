@@ -0,0 +1,124 @@
+// Exercises the C interface against the static library, run with `just ffi-test`.
+
+#include <math.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "rshapes.h"
+
+static int failures = 0;
+
+#define CHECK(condition)                                              \
+  do {                                                                \
+    if (!(condition)) {                                               \
+      fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #condition); \
+      failures++;                                                     \
+    }                                                                 \
+  } while (0)
+
+static RshapesPoint point(uint8_t x, uint8_t y) {
+  RshapesPoint p = {x, y};
+  return p;
+}
+
+static void test_circle(void) {
+  RshapesShape *circle = rshapes_circle_new(point(10, 10), 2);
+
+  CHECK(rshapes_shape_within(circle, point(11, 11)));
+  CHECK(!rshapes_shape_within(circle, point(12, 12)));
+  CHECK(fabs(rshapes_shape_distance(circle, point(10, 20)) - 8.0) < 1e-9);
+
+  RshapesRectangle bbox = rshapes_shape_bbox(circle);
+  CHECK(bbox.min.x == 8 && bbox.min.y == 8 && bbox.max.x == 12 && bbox.max.y == 12);
+
+  RshapesPoint centroid = rshapes_shape_centroid(circle);
+  CHECK(centroid.x == 10 && centroid.y == 10);
+
+  RshapesPoint points[16];
+  size_t count = rshapes_shape_points_inside(circle, points, 16);
+  CHECK(count == 13);
+  CHECK(points[0].x == 10 && points[0].y == 8);
+  CHECK(points[12].x == 10 && points[12].y == 12);
+
+  rshapes_shape_free(circle);
+}
+
+static void test_points_inside_buffer_too_small(void) {
+  RshapesShape *rectangle = rshapes_rectangle_new(point(0, 0), point(3, 3));
+
+  RshapesPoint points[4];
+  CHECK(rshapes_shape_points_inside(rectangle, points, 4) == 16);
+  CHECK(points[3].x == 3 && points[3].y == 0);
+  CHECK(rshapes_shape_points_inside(rectangle, NULL, 0) == 16);
+
+  rshapes_shape_free(rectangle);
+}
+
+static void test_polygon_with_holes(void) {
+  const RshapesPoint points[] = {
+      {0, 0}, {10, 0}, {10, 10}, {0, 10}, {3, 3}, {3, 7}, {7, 7}, {7, 3},
+  };
+  const size_t ring_lengths[] = {4, 4};
+
+  RshapesShape *polygon = rshapes_polygon_with_holes_new(points, ring_lengths, 2);
+  CHECK(polygon != NULL);
+  CHECK(rshapes_shape_within(polygon, point(1, 1)));
+  CHECK(!rshapes_shape_within(polygon, point(5, 5)));
+  rshapes_shape_free(polygon);
+
+  const size_t degenerate[] = {2};
+  CHECK(rshapes_polygon_with_holes_new(points, degenerate, 1) == NULL);
+}
+
+static void test_collection(void) {
+  const RshapesPoint triangle[] = {{20, 20}, {30, 20}, {20, 30}};
+
+  const RshapesShape *shapes[] = {
+      rshapes_rectangle_new(point(0, 0), point(10, 10)),
+      rshapes_polygon_new(triangle, 3, RSHAPES_FILL_RULE_NON_ZERO),
+      rshapes_ellipse_new(point(50, 50), 5, 2),
+      rshapes_rotated_ellipse_new(point(80, 80), 5, 2, M_PI / 2),
+      rshapes_rotated_rectangle_new(point(80, 20), 4, 1, M_PI / 2),
+      rshapes_triangle_new(point(40, 0), point(50, 0), point(40, 10)),
+  };
+  const size_t len = sizeof(shapes) / sizeof(shapes[0]);
+
+  RshapesShape *collection = rshapes_collection_new(shapes, len);
+  // The collection keeps its own copies.
+  for (size_t i = 0; i < len; i++) {
+    rshapes_shape_free((RshapesShape *)shapes[i]);
+  }
+
+  CHECK(rshapes_shape_within(collection, point(5, 5)));
+  CHECK(rshapes_shape_within(collection, point(21, 21)));
+  CHECK(rshapes_shape_within(collection, point(80, 84)));
+  CHECK(!rshapes_shape_within(collection, point(84, 80)));
+  CHECK(rshapes_shape_within(collection, point(80, 24)));
+  CHECK(!rshapes_shape_within(collection, point(15, 15)));
+  CHECK(rshapes_shape_distance(collection, point(0, 15)) == 5.0);
+
+  RshapesRectangle bbox = rshapes_shape_bbox(collection);
+  CHECK(bbox.min.x == 0 && bbox.min.y == 0 && bbox.max.x == 82 && bbox.max.y == 85);
+
+  rshapes_shape_free(collection);
+
+  RshapesShape *empty = rshapes_collection_new(NULL, 0);
+  CHECK(rshapes_shape_points_inside(empty, NULL, 0) == 0);
+  rshapes_shape_free(empty);
+  rshapes_shape_free(NULL);
+}
+
+int main(void) {
+  test_circle();
+  test_points_inside_buffer_too_small();
+  test_polygon_with_holes();
+  test_collection();
+
+  if (failures > 0) {
+    fprintf(stderr, "%d checks failed\n", failures);
+    return EXIT_FAILURE;
+  }
+
+  printf("all checks passed\n");
+  return EXIT_SUCCESS;
+}
@@ -0,0 +1,56 @@
+//! Tests of `rshapes` built without the standard library, run on the host.
+//!
+//! The unit tests of `rshapes` always link the standard library, so the float math only goes
+//! through `libm` here.
+#![cfg(test)]
+
+use core::f64::consts::{FRAC_PI_2, PI};
+
+use float_cmp::assert_approx_eq;
+use rshapes::traits::{Area, BoundingBox, Coverage, Distance, Perimeter, PointsInside, Within};
+use rshapes::{Circle, Ellipse, Point2, Rectangle, RotatedEllipse, RotatedRectangle};
+
+#[test]
+fn circle_measures() {
+  let circle = Circle::<f64, f64>::new(Point2::new(0.0, 0.0), 2.0);
+
+  assert_approx_eq!(f64, circle.area(), 4.0 * PI);
+  assert_approx_eq!(f64, circle.perimeter(), 4.0 * PI);
+  assert_approx_eq!(f64, circle.distance(&Point2::new(3.0, 4.0)), 3.0);
+}
+
+#[test]
+fn ellipse_distance() {
+  let ellipse = Ellipse::<f64, f64>::new(Point2::new(0.0, 0.0), (2.0, 1.0));
+
+  assert_approx_eq!(f64, ellipse.distance(&Point2::new(0.0, 3.0)), 2.0);
+  assert_approx_eq!(f64, ellipse.distance(&Point2::new(5.0, 0.0)), 3.0);
+  assert!(ellipse.within(&Point2::new(1.0, 0.5)));
+  assert!(!ellipse.within(&Point2::new(1.5, 1.0)));
+}
+
+#[test]
+fn rotated_shapes() {
+  let ellipse = RotatedEllipse::<f64, f64>::new(Point2::new(0.0, 0.0), (2.0, 1.0), FRAC_PI_2);
+  assert!(ellipse.within(&Point2::new(0.0, 1.5)));
+  assert!(!ellipse.within(&Point2::new(1.5, 0.0)));
+
+  let rectangle = RotatedRectangle::<i32>::new(Point2::new(0, 0), (2, 1), FRAC_PI_2);
+  assert_eq!(
+    rectangle.bbox(),
+    Rectangle::new(Point2::new(-1, -2), Point2::new(1, 2))
+  );
+}
+
+#[test]
+fn rasterization() {
+  let circle = Circle::<u8, u8>::new(Point2::new(10, 10), 2);
+  assert_eq!(circle.points_inside().len(), 13);
+
+  let coverage = circle
+    .coverage(16)
+    .iter()
+    .map(|(_, c)| f64::from(*c))
+    .sum::<f64>();
+  assert_approx_eq!(f64, coverage, circle.area(), epsilon = 0.05);
+}